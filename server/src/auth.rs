@@ -1 +1,172 @@
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome},
+    Request,
+};
+
 pub const SESSION_COOKIE_NAME: &str = "session";
+
+/// Secret that operators must send to use `/api/admin` routes. If it isn't set, admin routes
+/// are disabled.
+pub const ADMIN_TOKEN_ENV_VAR: &str = "ADMIN_TOKEN";
+
+const ADMIN_TOKEN_HEADER_NAME: &str = "X-Admin-Token";
+
+/// Carries the password when creating or joining a private game. It's a header rather than part of
+/// the URL so that it doesn't end up in access logs.
+const GAME_PASSWORD_HEADER_NAME: &str = "X-Game-Password";
+
+/// Which admin token is accepted, if any. Must be managed by Rocket for the `Admin` guard to work.
+pub struct AdminTokenConfig {
+    admin_token_or: Option<String>,
+}
+
+impl AdminTokenConfig {
+    pub fn new(admin_token_or: Option<String>) -> Self {
+        Self { admin_token_or }
+    }
+
+    /// Reads the admin token from `ADMIN_TOKEN`. An empty token counts as not being set.
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var(ADMIN_TOKEN_ENV_VAR)
+                .ok()
+                .filter(|admin_token| !admin_token.is_empty()),
+        )
+    }
+}
+
+/// Request guard for admin routes. Only succeeds if the request's `X-Admin-Token` header matches
+/// the configured admin token.
+pub struct Admin;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Admin {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let admin_token = match request
+            .rocket()
+            .state::<AdminTokenConfig>()
+            .and_then(|admin_token_config| admin_token_config.admin_token_or.as_ref())
+        {
+            Some(admin_token) => admin_token,
+            // Admin routes don't exist as far as clients can tell until a token is configured.
+            None => return Outcome::Failure((Status::NotFound, ())),
+        };
+        match request.headers().get_one(ADMIN_TOKEN_HEADER_NAME) {
+            Some(token) if token == admin_token => Outcome::Success(Admin),
+            _ => Outcome::Failure((Status::Unauthorized, ())),
+        }
+    }
+}
+
+/// Request guard for the optional `X-Game-Password` header. Always succeeds.
+pub struct GamePassword(pub Option<String>);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for GamePassword {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(GamePassword(
+            request
+                .headers()
+                .get_one(GAME_PASSWORD_HEADER_NAME)
+                .map(String::from),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::PlayerUUID;
+    use crate::game_manager::GameManager;
+    use rocket::http::{Cookie, Header};
+    use rocket::local::blocking::Client;
+    use std::sync::RwLock;
+
+    fn client_with_admin_token(admin_token_or: Option<&str>) -> Client {
+        // Rocket installs its own logger unless one is already set.
+        crate::test_logger::init();
+        let rocket = rocket::build()
+            .manage(RwLock::from(GameManager::new()))
+            .manage(AdminTokenConfig::new(admin_token_or.map(String::from)))
+            .mount("/", routes![crate::admin_overview_handler]);
+        Client::untracked(rocket).unwrap()
+    }
+
+    #[test]
+    fn admin_route_requires_matching_token() {
+        let client = client_with_admin_token(Some("secret"));
+
+        let response = client
+            .get("/api/admin/overview")
+            .header(Header::new(ADMIN_TOKEN_HEADER_NAME, "secret"))
+            .dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let response = client
+            .get("/api/admin/overview")
+            .header(Header::new(ADMIN_TOKEN_HEADER_NAME, "wrong"))
+            .dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+
+        let response = client.get("/api/admin/overview").dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+    }
+
+    #[test]
+    fn game_password_is_read_from_header() {
+        crate::test_logger::init();
+        let mut game_manager = GameManager::new();
+        let owner_uuid = PlayerUUID::new();
+        let player_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(owner_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(
+                owner_uuid,
+                "Game 1".to_string(),
+                Some("hunter2".to_string()),
+            )
+            .unwrap();
+        let rocket = rocket::build()
+            .manage(RwLock::from(game_manager))
+            .mount("/", routes![crate::join_game_handler]);
+        let client = Client::untracked(rocket).unwrap();
+        let session_cookie = Cookie::new(SESSION_COOKIE_NAME, player_uuid.to_string());
+
+        // Passwords in the URL are ignored.
+        let response = client
+            .get(format!(
+                "/api/joinGame/{}?password=hunter2",
+                game_uuid.to_string()
+            ))
+            .cookie(session_cookie.clone())
+            .dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+
+        let response = client
+            .get(format!("/api/joinGame/{}", game_uuid.to_string()))
+            .cookie(session_cookie)
+            .header(Header::new(GAME_PASSWORD_HEADER_NAME, "hunter2"))
+            .dispatch();
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    #[test]
+    fn admin_route_is_disabled_without_configured_token() {
+        let client = client_with_admin_token(None);
+        let response = client
+            .get("/api/admin/overview")
+            .header(Header::new(ADMIN_TOKEN_HEADER_NAME, ""))
+            .dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
@@ -0,0 +1,137 @@
+use rocket::{
+    fairing::{Fairing, Info, Kind},
+    http::{Header, Method, Status},
+    Request, Response,
+};
+use std::io::Cursor;
+
+/// Comma-separated list of origins that may call the API from another site, e.g.
+/// `http://localhost:3000,https://example.com`.
+pub const ALLOWED_ORIGINS_ENV_VAR: &str = "CORS_ALLOWED_ORIGINS";
+
+const API_PATH_PREFIX: &str = "/api/";
+
+/// Adds CORS headers to responses from `/api` routes so that separately-hosted clients can call
+/// the API. Credentials are allowed so that the session cookie is sent along.
+pub struct Cors {
+    allowed_origins: Vec<String>,
+}
+
+impl Cors {
+    pub fn new(allowed_origins: Vec<String>) -> Self {
+        Self { allowed_origins }
+    }
+
+    /// Reads allowed origins from `CORS_ALLOWED_ORIGINS`. If it isn't set, no other origins are
+    /// allowed.
+    pub fn from_env() -> Self {
+        let allowed_origins = match std::env::var(ALLOWED_ORIGINS_ENV_VAR) {
+            Ok(origins) => origins
+                .split(',')
+                .map(|origin| origin.trim().to_string())
+                .filter(|origin| !origin.is_empty())
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        Self::new(allowed_origins)
+    }
+
+    fn is_allowed_origin(&self, origin: &str) -> bool {
+        self.allowed_origins
+            .iter()
+            .any(|allowed_origin| allowed_origin == origin)
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for Cors {
+    fn info(&self) -> Info {
+        Info {
+            name: "CORS",
+            kind: Kind::Response,
+        }
+    }
+
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        if !request.uri().path().starts_with(API_PATH_PREFIX) {
+            return;
+        }
+
+        let origin = match request.headers().get_one("Origin") {
+            Some(origin) if self.is_allowed_origin(origin) => origin.to_string(),
+            _ => return,
+        };
+
+        response.set_header(Header::new("Access-Control-Allow-Origin", origin));
+        response.set_header(Header::new("Access-Control-Allow-Credentials", "true"));
+        response.set_header(Header::new("Vary", "Origin"));
+
+        // Rocket has no `OPTIONS` routes, so preflight requests would otherwise be a 404.
+        if request.method() == Method::Options {
+            response.set_header(Header::new(
+                "Access-Control-Allow-Methods",
+                "GET, POST, OPTIONS",
+            ));
+            response.set_header(Header::new("Access-Control-Allow-Headers", "Content-Type"));
+            response.set_status(Status::NoContent);
+            response.set_sized_body(0, Cursor::new(""));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    fn client_allowing(origin: &str) -> Client {
+        // Rocket installs its own logger unless one is already set.
+        crate::test_logger::init();
+        let rocket = rocket::build().attach(Cors::new(vec![origin.to_string()]));
+        Client::untracked(rocket).unwrap()
+    }
+
+    #[test]
+    fn preflight_to_api_route_returns_cors_headers() {
+        let client = client_allowing("http://localhost:3000");
+        let response = client
+            .req(Method::Options, "/api/listGames")
+            .header(Header::new("Origin", "http://localhost:3000"))
+            .header(Header::new("Access-Control-Request-Method", "GET"))
+            .dispatch();
+
+        assert_eq!(response.status(), Status::NoContent);
+        let headers = response.headers();
+        assert_eq!(
+            headers.get_one("Access-Control-Allow-Origin"),
+            Some("http://localhost:3000")
+        );
+        assert_eq!(
+            headers.get_one("Access-Control-Allow-Credentials"),
+            Some("true")
+        );
+        assert_eq!(
+            headers.get_one("Access-Control-Allow-Methods"),
+            Some("GET, POST, OPTIONS")
+        );
+        assert_eq!(
+            headers.get_one("Access-Control-Allow-Headers"),
+            Some("Content-Type")
+        );
+    }
+
+    #[test]
+    fn preflight_from_unknown_origin_gets_no_cors_headers() {
+        let client = client_allowing("http://localhost:3000");
+        let response = client
+            .req(Method::Options, "/api/listGames")
+            .header(Header::new("Origin", "http://evil.example.com"))
+            .dispatch();
+
+        assert_ne!(response.status(), Status::NoContent);
+        assert_eq!(
+            response.headers().get_one("Access-Control-Allow-Origin"),
+            None
+        );
+    }
+}
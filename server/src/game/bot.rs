@@ -0,0 +1,288 @@
+use super::game_logic::{GameLogic, LegalAction, TurnPhase};
+use super::player_card::{PlayerCard, RootPlayerCard, TargetStyle};
+use super::uuid::PlayerUUID;
+use super::Error;
+
+/// Smart bots only start a gambling round if they hold at least this many cards that can be
+/// used to start or take control of one.
+const MIN_GAMBLING_CARDS_TO_START_ROUND: usize = 2;
+/// Smart bots keep at least this much gold out of gambling rounds that they start or raise.
+const MIN_SPARE_GOLD_TO_GAMBLE: i32 = 3;
+/// Smart bots stop raising once the pot holds this much gold.
+const MAX_POT_AMOUNT_TO_RAISE: i32 = 6;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BotDifficulty {
+    /// Never plays cards on its own turn. Only passes, draws and orders drinks.
+    Basic,
+    /// Starts gambling rounds when its hand and gold allow, raises small pots, and plays
+    /// cheating cards to take back control of a gambling round.
+    Smart,
+}
+
+/// A computer-controlled player that picks one of its legal actions at a time.
+pub struct BotPlayer {
+    player_uuid: PlayerUUID,
+    difficulty: BotDifficulty,
+}
+
+impl BotPlayer {
+    pub fn new(player_uuid: PlayerUUID, difficulty: BotDifficulty) -> Self {
+        Self {
+            player_uuid,
+            difficulty,
+        }
+    }
+
+    /// Performs a single action for the bot. Returns `false` if the bot has no legal actions.
+    pub fn take_action(&self, game_logic: &mut GameLogic) -> Result<bool, Error> {
+        // The bot's action is applied directly rather than through `perform_undoable_action`, so
+        // its starting hand has to be drawn here.
+        game_logic.draw_starting_hand_if_needed(&self.player_uuid);
+        let action = match self.choose_action(game_logic) {
+            Some(action) => action,
+            None => return Ok(false),
+        };
+
+        match action {
+            LegalAction::Pass => game_logic.pass(&self.player_uuid)?,
+            LegalAction::PlayCard {
+                card_index,
+                target_player_uuid,
+            } => game_logic.play_card(&self.player_uuid, &target_player_uuid, card_index)?,
+            LegalAction::DiscardCards => {
+                game_logic.discard_cards_and_draw_to_full(&self.player_uuid, Vec::new())?
+            }
+            LegalAction::DiscardSingleCard => {
+                game_logic.discard_single_card(&self.player_uuid, 0)?
+            }
+            LegalAction::OrderDrink { target_player_uuid } => {
+                game_logic.order_drink(&self.player_uuid, &target_player_uuid)?
+            }
+        };
+
+        Ok(true)
+    }
+
+    fn choose_action(&self, game_logic: &GameLogic) -> Option<LegalAction> {
+        let legal_actions = game_logic.legal_actions(&self.player_uuid);
+
+        let smart_action_or = match self.difficulty {
+            BotDifficulty::Basic => None,
+            BotDifficulty::Smart => self.choose_gambling_action(game_logic, &legal_actions),
+        };
+
+        smart_action_or.or_else(|| choose_basic_action(legal_actions))
+    }
+
+    fn choose_gambling_action(
+        &self,
+        game_logic: &GameLogic,
+        legal_actions: &[LegalAction],
+    ) -> Option<LegalAction> {
+        let player = game_logic.get_player_by_uuid(&self.player_uuid)?;
+        let hand = player.get_hand();
+        let has_spare_gold = player.get_gold() > MIN_SPARE_GOLD_TO_GAMBLE;
+        let gambling_manager = game_logic.get_gambling_manager();
+
+        let find_playable_card = |is_wanted_card: &dyn Fn(&RootPlayerCard) -> bool| {
+            legal_actions
+                .iter()
+                .find(|legal_action| match legal_action {
+                    LegalAction::PlayCard { card_index, .. } => match hand.get(*card_index) {
+                        Some(PlayerCard::RootPlayerCard(root_player_card)) => {
+                            is_wanted_card(root_player_card)
+                        }
+                        _ => false,
+                    },
+                    _ => false,
+                })
+                .cloned()
+        };
+
+        if gambling_manager.round_in_progress() {
+            if !gambling_manager.is_turn(&self.player_uuid) {
+                return None;
+            }
+
+            if gambling_manager.need_cheating_card_to_take_next_control() {
+                return find_playable_card(&|card| card.is_cheating_card());
+            }
+
+            if has_spare_gold && gambling_manager.get_pot_amount() < MAX_POT_AMOUNT_TO_RAISE {
+                if let Some(raise_action) = find_playable_card(&is_raise_card) {
+                    return Some(raise_action);
+                }
+            }
+
+            find_playable_card(&|card| card.is_gambling_card())
+                .or_else(|| find_playable_card(&|card| card.is_cheating_card()))
+        } else if game_logic.get_turn_phase() == TurnPhase::Action && has_spare_gold {
+            let gambling_card_count = hand
+                .iter()
+                .filter(|card| match card {
+                    PlayerCard::RootPlayerCard(root_player_card) => {
+                        root_player_card.is_gambling_card() || root_player_card.is_cheating_card()
+                    }
+                    PlayerCard::InterruptPlayerCard(_) => false,
+                })
+                .count();
+
+            if gambling_card_count >= MIN_GAMBLING_CARDS_TO_START_ROUND {
+                find_playable_card(&|card| card.is_action_card() && card.is_gambling_card())
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+}
+
+fn is_raise_card(card: &RootPlayerCard) -> bool {
+    card.is_gambling_card()
+        && card.get_target_style() == TargetStyle::AllGamblingPlayersIncludingSelf
+}
+
+/// Passes whenever possible, otherwise keeps the game moving with the least eventful action.
+fn choose_basic_action(legal_actions: Vec<LegalAction>) -> Option<LegalAction> {
+    if legal_actions.contains(&LegalAction::Pass) {
+        return Some(LegalAction::Pass);
+    }
+    if legal_actions.contains(&LegalAction::DiscardCards) {
+        return Some(LegalAction::DiscardCards);
+    }
+    legal_actions.into_iter().find(|legal_action| {
+        matches!(
+            legal_action,
+            LegalAction::OrderDrink { .. } | LegalAction::DiscardSingleCard
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::player::Player;
+    use super::super::player_card::{gambling_cheat_card, gambling_im_in_card, winning_hand_card};
+    use super::super::Character;
+    use super::*;
+
+    fn get_gold(game_logic: &GameLogic, player_uuid: &PlayerUUID) -> i32 {
+        game_logic
+            .get_player_by_uuid(player_uuid)
+            .unwrap()
+            .get_gold()
+    }
+
+    // Leaves the bot holding only the cards that a test gives it, so that its choices don't
+    // depend on the shuffle.
+    fn empty_hand(player: &mut Player) {
+        while player.pop_card_from_hand(0).is_some() {}
+    }
+
+    #[test]
+    fn smart_bot_wins_gambling_round_against_pass_only_opponent() {
+        let bot_uuid = PlayerUUID::new();
+        let opponent_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (bot_uuid.clone(), Character::Deirdre),
+            (opponent_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        let bot_player = game_logic.get_player_by_uuid_mut(&bot_uuid).unwrap();
+        empty_hand(bot_player);
+        bot_player.return_card_to_hand(gambling_im_in_card().into(), 0);
+        bot_player.return_card_to_hand(gambling_im_in_card().into(), 1);
+
+        let bot = BotPlayer::new(bot_uuid.clone(), BotDifficulty::Smart);
+        while game_logic.get_turn_phase() != TurnPhase::OrderDrinks {
+            if !bot.take_action(&mut game_logic).unwrap() {
+                game_logic.pass(&opponent_uuid).unwrap();
+            }
+        }
+
+        // Both players anted once and the bot took the pot.
+        assert_eq!(get_gold(&game_logic, &bot_uuid), 9);
+        assert_eq!(get_gold(&game_logic, &opponent_uuid), 7);
+        assert!(!game_logic.get_gambling_manager().round_in_progress());
+        assert_eq!(
+            game_logic.get_turn_info().get_current_player_turn(),
+            &bot_uuid
+        );
+    }
+
+    #[test]
+    fn basic_bot_never_starts_gambling_round() {
+        let bot_uuid = PlayerUUID::new();
+        let opponent_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (bot_uuid.clone(), Character::Deirdre),
+            (opponent_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        let bot_player = game_logic.get_player_by_uuid_mut(&bot_uuid).unwrap();
+        empty_hand(bot_player);
+        bot_player.return_card_to_hand(gambling_im_in_card().into(), 0);
+        bot_player.return_card_to_hand(gambling_im_in_card().into(), 1);
+
+        let bot = BotPlayer::new(bot_uuid.clone(), BotDifficulty::Basic);
+        while game_logic.get_turn_phase() != TurnPhase::OrderDrinks {
+            assert!(bot.take_action(&mut game_logic).unwrap());
+        }
+
+        assert_eq!(get_gold(&game_logic, &bot_uuid), 8);
+        assert_eq!(get_gold(&game_logic, &opponent_uuid), 8);
+        assert!(!game_logic.get_gambling_manager().round_in_progress());
+    }
+
+    #[test]
+    fn smart_bot_plays_cheating_card_to_retake_control() {
+        let player_uuid = PlayerUUID::new();
+        let bot_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player_uuid.clone(), Character::Deirdre),
+            (bot_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        let player = game_logic.get_player_by_uuid_mut(&player_uuid).unwrap();
+        player.return_card_to_hand(gambling_im_in_card().into(), 0);
+        player.return_card_to_hand(winning_hand_card().into(), 1);
+        let bot_player = game_logic.get_player_by_uuid_mut(&bot_uuid).unwrap();
+        empty_hand(bot_player);
+        bot_player.return_card_to_hand(gambling_im_in_card().into(), 0);
+        bot_player.return_card_to_hand(gambling_cheat_card("Cheat").into(), 1);
+
+        let bot = BotPlayer::new(bot_uuid.clone(), BotDifficulty::Smart);
+        game_logic
+            .discard_cards_and_draw_to_full(&player_uuid, Vec::new())
+            .unwrap();
+        game_logic.play_card(&player_uuid, &None, 0).unwrap();
+
+        // The bot declines to interrupt the ante, then takes control with its gambling card.
+        assert!(bot.take_action(&mut game_logic).unwrap());
+        assert!(game_logic.get_gambling_manager().is_turn(&bot_uuid));
+        assert!(bot.take_action(&mut game_logic).unwrap());
+        assert_eq!(
+            game_logic.get_game_log().last().unwrap().card_name,
+            Some("Gambling? I'm in!".to_string())
+        );
+
+        // Only a cheating card can take control back after "Winning Hand!".
+        game_logic.play_card(&player_uuid, &None, 0).unwrap();
+        assert!(game_logic
+            .get_gambling_manager()
+            .need_cheating_card_to_take_next_control());
+        assert!(bot.take_action(&mut game_logic).unwrap());
+        assert_eq!(
+            game_logic.get_game_log().last().unwrap().card_name,
+            Some("Cheat".to_string())
+        );
+
+        game_logic.pass(&player_uuid).unwrap();
+        assert_eq!(get_gold(&game_logic, &bot_uuid), 9);
+        assert_eq!(get_gold(&game_logic, &player_uuid), 7);
+    }
+}
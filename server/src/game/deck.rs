@@ -1,37 +1,117 @@
+#[cfg(test)]
+use super::drink::{get_revealed_drink, RevealedDrink};
 use super::drink::{DrinkCard, DrinkDeck};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::SeedableRng;
 
 #[derive(Clone, Debug)]
 pub struct AutoShufflingDeck<T> {
     draw_pile: Vec<T>,
     discard_pile: Vec<T>,
+    // Cards that have been taken out of the game. These are never shuffled back in.
+    removed_pile: Vec<T>,
+    rng: StdRng,
+    // If false, the discard pile is never shuffled back in on its own, so the deck can run out.
+    auto_shuffle: bool,
 }
 
 impl<T> AutoShufflingDeck<T> {
-    pub fn new(mut items: Vec<T>) -> Self {
-        items.shuffle(&mut thread_rng());
+    #[cfg(test)]
+    pub fn new(items: Vec<T>) -> Self {
+        Self::new_seeded(items, rand::random())
+    }
+
+    /// Creates a deck whose shuffles are fully determined by `seed`, so that
+    /// two decks created with the same items and seed are always drawn in the same order.
+    pub fn new_seeded(items: Vec<T>, seed: u64) -> Self {
+        Self::new_seeded_with_auto_shuffle(items, seed, true)
+    }
+
+    /// Same as `new_seeded`, but if `auto_shuffle` is false the discard pile is never shuffled
+    /// back into the draw pile when it runs out. Drawing from an empty draw pile then returns
+    /// `None`, which is useful for variants where running out of cards matters.
+    pub fn new_seeded_with_auto_shuffle(mut items: Vec<T>, seed: u64, auto_shuffle: bool) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        items.shuffle(&mut rng);
 
         Self {
             draw_pile: items,
             discard_pile: Vec::new(),
+            removed_pile: Vec::new(),
+            rng,
+            auto_shuffle,
         }
     }
 
     pub fn draw_card(&mut self) -> Option<T> {
-        if self.draw_pile.is_empty() {
+        if self.draw_pile.is_empty() && self.auto_shuffle {
             self.discard_pile
                 .drain(..)
                 .for_each(|card| self.draw_pile.push(card));
-            self.draw_pile.shuffle(&mut thread_rng());
+            self.draw_pile.shuffle(&mut self.rng);
         }
         self.draw_pile.pop()
     }
 
+    /// Returns the card `depth` cards from the top of the draw pile without drawing it,
+    /// where a depth of 0 is the next card to be drawn. The deck itself is left untouched.
+    ///
+    /// If the draw pile doesn't have enough cards, this looks into the shuffle of the discard
+    /// pile that `draw_card` will do once the draw pile runs out, using a copy of the deck's RNG.
+    /// Peeked cards are therefore the ones drawn next, as long as nothing is discarded before
+    /// then. Decks that don't auto-shuffle only peek into the draw pile.
+    pub fn peek_card(&self, depth: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        let draw_pile_size = self.draw_pile.len();
+        if depth < draw_pile_size {
+            return self.draw_pile.get(draw_pile_size - 1 - depth).cloned();
+        }
+        if !self.auto_shuffle {
+            return None;
+        }
+
+        let mut next_draw_pile = self.discard_pile.clone();
+        next_draw_pile.shuffle(&mut self.rng.clone());
+        let next_draw_pile_depth = depth - draw_pile_size;
+        if next_draw_pile_depth >= next_draw_pile.len() {
+            None
+        } else {
+            next_draw_pile
+                .get(next_draw_pile.len() - 1 - next_draw_pile_depth)
+                .cloned()
+        }
+    }
+
+    /// Shuffles the discard pile back into the draw pile right away, rather than waiting for the
+    /// draw pile to run out.
+    pub fn reshuffle(&mut self) {
+        self.draw_pile.append(&mut self.discard_pile);
+        self.draw_pile.shuffle(&mut self.rng);
+    }
+
     pub fn discard_card(&mut self, card: T) {
         self.discard_pile.push(card);
     }
 
+    pub fn remove_card(&mut self, card: T) {
+        self.removed_pile.push(card);
+    }
+
+    /// Takes the most recently discarded card out of the game.
+    /// Returns `false` if the discard pile is empty.
+    pub fn remove_top_discarded_card(&mut self) -> bool {
+        match self.discard_pile.pop() {
+            Some(card) => {
+                self.remove_card(card);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn draw_pile_size(&self) -> usize {
         self.draw_pile.len()
     }
@@ -39,6 +119,33 @@ impl<T> AutoShufflingDeck<T> {
     pub fn discard_pile_size(&self) -> usize {
         self.discard_pile.len()
     }
+
+    pub fn removed_pile_size(&self) -> usize {
+        self.removed_pile.len()
+    }
+
+    /// Number of cards that can still be drawn, counting the discard pile that gets shuffled back
+    /// in once the draw pile runs out.
+    pub fn drawable_card_count(&self) -> usize {
+        if self.auto_shuffle {
+            self.draw_pile.len() + self.discard_pile.len()
+        } else {
+            self.draw_pile.len()
+        }
+    }
+}
+
+impl AutoShufflingDeck<DrinkCard> {
+    /// Reveals the next drink (along with any chasers) without removing it from the deck.
+    // Test-only until a card previews drinks. Showing the next drink in a view would tell every
+    // player what's coming, which the rules don't allow.
+    #[cfg(test)]
+    pub fn peek_revealed_drink(&self) -> Option<RevealedDrink> {
+        get_revealed_drink(&mut PeekingDrinkDeck {
+            deck: self,
+            depth: 0,
+        })
+    }
 }
 
 impl DrinkDeck for AutoShufflingDeck<DrinkCard> {
@@ -46,3 +153,183 @@ impl DrinkDeck for AutoShufflingDeck<DrinkCard> {
         self.draw_card()
     }
 }
+
+/// Walks down a drink deck without drawing from it.
+#[cfg(test)]
+struct PeekingDrinkDeck<'a> {
+    deck: &'a AutoShufflingDeck<DrinkCard>,
+    depth: usize,
+}
+
+#[cfg(test)]
+impl<'a> DrinkDeck for PeekingDrinkDeck<'a> {
+    fn get_next_drink_card_or(&mut self) -> Option<DrinkCard> {
+        let drink_card_or = self.deck.peek_card(self.depth);
+        self.depth += 1;
+        drink_card_or
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::drink::create_drink_deck;
+    use super::*;
+
+    fn get_revealed_drink_display_name(revealed_drink: RevealedDrink) -> String {
+        match revealed_drink {
+            RevealedDrink::DrinkWithPossibleChasers(drink) => drink.get_display_name(),
+            RevealedDrink::DrinkEvent(drink_event) => format!("{:?}", drink_event),
+        }
+    }
+
+    #[test]
+    fn peek_card_does_not_draw() {
+        let mut deck = AutoShufflingDeck::new(vec![1, 2, 3]);
+        let top_card = deck.peek_card(0).unwrap();
+        let second_card = deck.peek_card(1).unwrap();
+        assert_eq!(deck.peek_card(3), None);
+        assert_eq!(deck.draw_pile_size(), 3);
+
+        assert_eq!(deck.draw_card(), Some(top_card));
+        assert_eq!(deck.draw_card(), Some(second_card));
+    }
+
+    #[test]
+    fn peek_card_looks_into_next_shuffle_of_discard_pile() {
+        let mut deck = AutoShufflingDeck::new(vec![1]);
+        deck.discard_card(2);
+        deck.discard_card(3);
+
+        assert_eq!(deck.peek_card(0), Some(1));
+        let second_card = deck.peek_card(1).unwrap();
+        let third_card = deck.peek_card(2).unwrap();
+        assert_eq!(deck.peek_card(3), None);
+        assert_eq!(deck.draw_pile_size(), 1);
+        assert_eq!(deck.discard_pile_size(), 2);
+
+        assert_eq!(deck.draw_card(), Some(1));
+        assert_eq!(deck.draw_card(), Some(second_card));
+        assert_eq!(deck.draw_card(), Some(third_card));
+        assert_eq!(deck.draw_card(), None);
+    }
+
+    #[test]
+    fn peek_card_does_not_change_later_shuffles() {
+        let cards: Vec<i32> = (0..10).collect();
+        let mut peeked_deck = AutoShufflingDeck::new_seeded(cards.clone(), 7);
+        let mut deck = AutoShufflingDeck::new_seeded(cards, 7);
+
+        for _ in 0..30 {
+            for depth in 0..12 {
+                peeked_deck.peek_card(depth);
+            }
+            let card = deck.draw_card().unwrap();
+            assert_eq!(peeked_deck.draw_card(), Some(card));
+            deck.discard_card(card);
+            peeked_deck.discard_card(card);
+        }
+    }
+
+    #[test]
+    fn auto_shuffling_deck_reshuffles_discards_when_empty() {
+        let mut deck = AutoShufflingDeck::new_seeded_with_auto_shuffle(vec![1, 2], 0, true);
+        for _ in 0..2 {
+            let card = deck.draw_card().unwrap();
+            deck.discard_card(card);
+        }
+        assert_eq!(deck.draw_pile_size(), 0);
+        assert_eq!(deck.drawable_card_count(), 2);
+
+        assert!(deck.peek_card(0).is_some());
+        assert!(deck.draw_card().is_some());
+        assert!(deck.draw_card().is_some());
+        assert_eq!(deck.draw_card(), None);
+    }
+
+    #[test]
+    fn finite_deck_runs_out_instead_of_reshuffling() {
+        let mut deck = AutoShufflingDeck::new_seeded_with_auto_shuffle(vec![1, 2], 0, false);
+        for _ in 0..2 {
+            let card = deck.draw_card().unwrap();
+            deck.discard_card(card);
+        }
+        assert_eq!(deck.draw_pile_size(), 0);
+        assert_eq!(deck.drawable_card_count(), 0);
+
+        assert_eq!(deck.peek_card(0), None);
+        assert_eq!(deck.draw_card(), None);
+        assert_eq!(deck.discard_pile_size(), 2);
+
+        // Discards can still be shuffled back in deliberately.
+        deck.reshuffle();
+        assert_eq!(deck.drawable_card_count(), 2);
+        assert!(deck.draw_card().is_some());
+    }
+
+    #[test]
+    fn reshuffle_moves_discard_pile_into_draw_pile() {
+        let mut deck = AutoShufflingDeck::new(vec![1, 2, 3, 4, 5]);
+        for _ in 0..3 {
+            let card = deck.draw_card().unwrap();
+            deck.discard_card(card);
+        }
+        assert_eq!(deck.draw_pile_size(), 2);
+        assert_eq!(deck.discard_pile_size(), 3);
+
+        deck.reshuffle();
+        assert_eq!(deck.draw_pile_size(), 5);
+        assert_eq!(deck.discard_pile_size(), 0);
+
+        let mut cards: Vec<i32> = (0..5).map(|_| deck.draw_card().unwrap()).collect();
+        cards.sort_unstable();
+        assert_eq!(cards, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn removed_cards_are_never_reshuffled_back_in() {
+        let mut deck = AutoShufflingDeck::new(vec![1, 2, 3, 4, 5]);
+        let removed_card = deck.draw_card().unwrap();
+        deck.remove_card(removed_card);
+        let card = deck.draw_card().unwrap();
+        deck.discard_card(card);
+        assert!(deck.remove_top_discarded_card());
+        assert!(!deck.remove_top_discarded_card());
+        assert_eq!(deck.removed_pile_size(), 2);
+
+        for _ in 0..10 {
+            let mut cards = Vec::new();
+            while let Some(card) = deck.draw_card() {
+                cards.push(card);
+                if cards.len() == 3 {
+                    break;
+                }
+            }
+            assert_eq!(cards.len(), 3);
+            assert!(!cards.contains(&removed_card));
+            assert!(!cards.contains(&card));
+            cards.into_iter().for_each(|card| deck.discard_card(card));
+            deck.reshuffle();
+            assert_eq!(deck.draw_pile_size(), 3);
+        }
+    }
+
+    #[test]
+    fn peeked_drink_matches_next_drawn_drink() {
+        // Run many times since the deck is shuffled.
+        for _ in 0..100 {
+            let mut drink_deck = AutoShufflingDeck::new(create_drink_deck());
+            // Move some drinks to the discard pile so that peeking might need to reshuffle.
+            for _ in 0..25 {
+                let drink_card = drink_deck.draw_card().unwrap();
+                drink_deck.discard_card(drink_card);
+            }
+
+            let peeked_drink = drink_deck.peek_revealed_drink().unwrap();
+            let drawn_drink = get_revealed_drink(&mut drink_deck).unwrap();
+            assert_eq!(
+                get_revealed_drink_display_name(peeked_drink),
+                get_revealed_drink_display_name(drawn_drink)
+            );
+        }
+    }
+}
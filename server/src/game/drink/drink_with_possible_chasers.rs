@@ -1,4 +1,4 @@
-use super::super::player::Player;
+use super::super::player::{Player, TransientEvent};
 use super::{Drink, DrinkCard, RevealedDrink};
 
 #[derive(Clone, Debug)]
@@ -37,21 +37,25 @@ impl DrinkWithPossibleChasers {
     }
 
     pub fn get_display_name(&self) -> String {
-        // TODO - I'm pretty sure this will end up with a comma at the end of the last element. Let's fix that.
         format!(
             "[{}]",
-            self.drinks.iter().fold(String::new(), |acc, drink| acc
-                + drink.get_display_name()
-                + ", ")
+            self.drinks
+                .iter()
+                .map(|drink| drink.get_display_name())
+                .collect::<Vec<&str>>()
+                .join(", ")
         )
     }
 
     pub fn process(&self, player: &mut Player) {
+        player.record_transient_event(TransientEvent::DrinkDrunk {
+            name: self.get_display_name(),
+        });
         let alcohol_content_modifier = self.get_combined_alcohol_content_modifier(player);
         let fortitude_modifier = self.get_combined_fortitude_modifier(player);
 
-        player.change_alcohol_content(alcohol_content_modifier);
-        player.change_fortitude(fortitude_modifier);
+        player.change_alcohol_content(alcohol_content_modifier, self.get_display_name());
+        player.change_fortitude(fortitude_modifier, self.get_display_name());
     }
 
     pub fn get_combined_alcohol_content_modifier(&self, player: &Player) -> i32 {
@@ -62,7 +66,7 @@ impl DrinkWithPossibleChasers {
         modifier
     }
 
-    fn get_combined_fortitude_modifier(&self, player: &Player) -> i32 {
+    pub fn get_combined_fortitude_modifier(&self, player: &Player) -> i32 {
         let mut modifier = 0;
         for drink in &self.drinks {
             modifier += drink.get_fortitude_modifier(player);
@@ -70,3 +74,96 @@ impl DrinkWithPossibleChasers {
         modifier
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::uuid::PlayerUUID;
+    use super::super::super::{Character, GameConfig};
+    use super::super::drink_struct::{orcish_rotgut, simple_drink};
+    use super::*;
+
+    fn get_alcohol_content_and_fortitude(player: &Player) -> (i32, i32) {
+        let player_data = player.to_game_view_player_data(PlayerUUID::new());
+        (player_data.alcohol_content, player_data.fortitude)
+    }
+
+    #[test]
+    fn get_display_name_lists_all_drinks() {
+        assert_eq!(
+            DrinkWithPossibleChasers::new(Vec::new(), None).get_display_name(),
+            "[]"
+        );
+        assert_eq!(
+            DrinkWithPossibleChasers::new(vec![simple_drink("Wine", 2, 0, false)], None)
+                .get_display_name(),
+            "[Wine]"
+        );
+        assert_eq!(
+            DrinkWithPossibleChasers::new(
+                vec![
+                    simple_drink("Wine with a Chaser", 2, 0, true),
+                    simple_drink("Dark Ale", 1, 0, false)
+                ],
+                None
+            )
+            .get_display_name(),
+            "[Wine with a Chaser, Dark Ale]"
+        );
+    }
+
+    #[test]
+    fn process_sums_modifiers_of_drink_and_chasers() {
+        let mut player =
+            Player::create_from_character(Character::Fiona, 8, &GameConfig::default(), 0);
+        player.change_fortitude(-5, "Test");
+        assert_eq!(get_alcohol_content_and_fortitude(&player), (0, 15));
+
+        let drink = DrinkWithPossibleChasers::new(
+            vec![
+                simple_drink("Wizard's Brew", 2, 2, false),
+                simple_drink("Dragon Breath Ale", 4, 0, false),
+            ],
+            None,
+        );
+        assert_eq!(drink.get_combined_alcohol_content_modifier(&player), 6);
+        assert_eq!(drink.get_combined_fortitude_modifier(&player), 2);
+
+        drink.process(&mut player);
+        assert_eq!(get_alcohol_content_and_fortitude(&player), (6, 17));
+    }
+
+    #[test]
+    fn process_clamps_combined_modifiers() {
+        let mut player =
+            Player::create_from_character(Character::Fiona, 8, &GameConfig::default(), 0);
+        player.change_alcohol_content(16, "Test");
+        player.change_fortitude(-1, "Test");
+        assert_eq!(get_alcohol_content_and_fortitude(&player), (16, 19));
+
+        DrinkWithPossibleChasers::new(
+            vec![
+                simple_drink("Wizard's Brew", 2, 2, false),
+                simple_drink("Dragon Breath Ale", 4, 0, false),
+            ],
+            None,
+        )
+        .process(&mut player);
+        assert_eq!(get_alcohol_content_and_fortitude(&player), (20, 20));
+    }
+
+    #[test]
+    fn process_applies_modifiers_atomically() {
+        let mut player =
+            Player::create_from_character(Character::Fiona, 8, &GameConfig::default(), 0);
+        assert_eq!(get_alcohol_content_and_fortitude(&player), (0, 20));
+
+        // Applied one at a time, the Holy Water would be clamped away at max
+        // fortitude and the Orcish Rotgut would leave the player at 18.
+        DrinkWithPossibleChasers::new(
+            vec![simple_drink("Holy Water", 0, 2, true), orcish_rotgut()],
+            None,
+        )
+        .process(&mut player);
+        assert_eq!(get_alcohol_content_and_fortitude(&player), (0, 20));
+    }
+}
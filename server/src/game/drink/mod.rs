@@ -4,7 +4,6 @@ mod drink_with_possible_chasers;
 use super::uuid::PlayerUUID;
 use drink_struct::{orcish_rotgut, simple_drink, troll_swill, Drink};
 pub use drink_with_possible_chasers::DrinkWithPossibleChasers;
-use std::collections::HashSet;
 use std::fmt::Debug;
 
 #[derive(Clone, Debug)]
@@ -32,6 +31,13 @@ pub enum DrinkEvent {
 }
 
 impl DrinkEvent {
+    pub fn get_display_name(&self) -> &str {
+        match &self {
+            Self::DrinkingContest => "Drinking Contest",
+            Self::RoundOnTheHouse => "Round on the House",
+        }
+    }
+
     pub fn to_default_drink_event_with_data(&self) -> DrinkEventWithData {
         match &self {
             Self::DrinkingContest => {
@@ -50,35 +56,28 @@ pub enum DrinkEventWithData {
 
 #[derive(Clone, Debug)]
 pub struct DrinkingContestData {
-    currently_winning_players: HashSet<PlayerUUID>,
+    // Kept in turn order, rather than in a set, so that drinking contests play out the same way when a game is replayed.
+    currently_winning_players: Vec<PlayerUUID>,
 }
 
 impl DrinkingContestData {
     fn new() -> Self {
         Self {
-            currently_winning_players: HashSet::new(),
+            currently_winning_players: Vec::new(),
         }
     }
 
-    pub fn get_currently_winning_players(&self) -> &HashSet<PlayerUUID> {
+    pub fn get_currently_winning_players(&self) -> &Vec<PlayerUUID> {
         &self.currently_winning_players
     }
 
-    pub fn overwrite_currently_winning_players(&mut self, winning_players: HashSet<PlayerUUID>) {
+    pub fn overwrite_currently_winning_players(&mut self, winning_players: Vec<PlayerUUID>) {
         self.currently_winning_players = winning_players;
     }
 
     pub fn get_single_winner_uuid_or(&self) -> Option<PlayerUUID> {
         if self.currently_winning_players.len() == 1 {
-            Some(
-                (*self
-                    .currently_winning_players
-                    .iter()
-                    .collect::<Vec<&PlayerUUID>>()
-                    .first()
-                    .unwrap())
-                .clone(),
-            )
+            self.currently_winning_players.first().cloned()
         } else {
             None
         }
@@ -95,6 +94,21 @@ pub fn create_simple_ale_test_drink(has_chaser: bool) -> Drink {
     simple_drink("Test Ale", 1, 0, has_chaser)
 }
 
+#[cfg(test)]
+pub fn create_dirty_dishwater_test_drink() -> Drink {
+    simple_drink("Dirty Dishwater", 0, -1, false)
+}
+
+#[cfg(test)]
+pub fn create_orcish_rotgut_test_drink() -> Drink {
+    orcish_rotgut()
+}
+
+#[cfg(test)]
+pub fn create_strong_test_drink() -> Drink {
+    simple_drink("Test Spirits", 3, 0, false)
+}
+
 pub fn create_drink_deck() -> Vec<DrinkCard> {
     vec![
         simple_drink("Dark Ale", 1, 0, false).into(),
@@ -191,3 +205,52 @@ fn push_drink_to_vec_or(
         None => Ok(drinks),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// Hands out drink cards in a fixed order.
+    struct ScriptedDrinkDeck {
+        drink_cards: VecDeque<DrinkCard>,
+    }
+
+    impl DrinkDeck for ScriptedDrinkDeck {
+        fn get_next_drink_card_or(&mut self) -> Option<DrinkCard> {
+            self.drink_cards.pop_front()
+        }
+    }
+
+    #[test]
+    fn chaser_chain_stopped_by_drink_event_keeps_drinks_and_event() {
+        let mut drink_deck = ScriptedDrinkDeck {
+            drink_cards: VecDeque::from(vec![
+                simple_drink("Light Ale with a Chaser", 1, 0, true).into(),
+                simple_drink("Wine with a Chaser", 2, 0, true).into(),
+                DrinkCard::DrinkEvent(DrinkEvent::DrinkingContest),
+            ]),
+        };
+
+        let drink = match get_revealed_drink(&mut drink_deck) {
+            Some(RevealedDrink::DrinkWithPossibleChasers(drink)) => drink,
+            Some(RevealedDrink::DrinkEvent(drink_event)) => {
+                panic!("Expected drinks but revealed {:?}", drink_event)
+            }
+            None => panic!("Expected drinks but the deck was empty"),
+        };
+        assert_eq!(
+            drink.get_display_name(),
+            "[Light Ale with a Chaser, Wine with a Chaser]"
+        );
+        assert!(drink_deck.drink_cards.is_empty());
+
+        // The drinking contest is held onto so that it's discarded along with the drinks.
+        let discardable_drink_cards = drink.take_all_discardable_drink_cards();
+        assert_eq!(discardable_drink_cards.len(), 3);
+        assert!(matches!(
+            discardable_drink_cards.last(),
+            Some(DrinkCard::DrinkEvent(DrinkEvent::DrinkingContest))
+        ));
+    }
+}
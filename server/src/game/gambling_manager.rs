@@ -1,18 +1,30 @@
 use super::game_logic::TurnInfo;
+use super::player::TransientEvent;
 use super::player_manager::PlayerManager;
+use super::player_view::GameViewGamblingData;
 use super::uuid::PlayerUUID;
 use super::Error;
 use std::default::Default;
 
+/// Gold that each player antes into a gambling round under the standard rules.
+pub const DEFAULT_GAMBLING_ANTE: i32 = 1;
+
 #[derive(Clone, Debug)]
 pub struct GamblingManager {
     gambling_round_or: Option<GamblingRound>,
+    // How much gold each ante takes from a player in rounds started by this manager.
+    base_ante: i32,
 }
 
 impl GamblingManager {
     pub fn new() -> Self {
+        Self::new_with_base_ante(DEFAULT_GAMBLING_ANTE)
+    }
+
+    pub fn new_with_base_ante(base_ante: i32) -> Self {
         Self {
             gambling_round_or: None,
+            base_ante,
         }
     }
 
@@ -27,6 +39,7 @@ impl GamblingManager {
                 current_player_turn: player_uuid.clone(),
                 winning_player: player_uuid.clone(),
                 pot_amount: 0,
+                base_ante: self.base_ante,
                 need_cheating_card_to_take_next_control: false,
             });
             self.ante_up(&player_uuid, player_manager);
@@ -50,12 +63,27 @@ impl GamblingManager {
         gambling_round.increment_player_turn();
     }
 
-    /// Forces a player to ante up. Fails silently if...
+    /// Forces a player to ante up the round's base ante. Fails silently under the same conditions
+    /// as `ante_up_amount`.
+    pub fn ante_up(&mut self, player_uuid: &PlayerUUID, player_manager: &mut PlayerManager) {
+        let base_ante = match &self.gambling_round_or {
+            Some(gambling_round) => gambling_round.base_ante,
+            None => return,
+        };
+        self.ante_up_amount(player_uuid, player_manager, base_ante);
+    }
+
+    /// Forces a player to ante up the given amount of gold. Fails silently if...
     /// 1. A gambling round is not running.
     /// 2. The player uuid doesn't map to an existing player in the game.
     ///
     /// This method can be considered atomic, since both conditions are verified before any modification.
-    pub fn ante_up(&mut self, player_uuid: &PlayerUUID, player_manager: &mut PlayerManager) {
+    pub fn ante_up_amount(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        player_manager: &mut PlayerManager,
+        amount: i32,
+    ) {
         let player = match player_manager.get_player_by_uuid_mut(player_uuid) {
             Some(player) => player,
             None => return,
@@ -66,8 +94,13 @@ impl GamblingManager {
             None => return,
         };
 
-        player.change_gold(-1);
-        gambling_round.pot_amount += 1;
+        // Players can't ante more gold than they have left, so only what was actually taken from
+        // the player goes into the pot. Anteing never gives a player gold.
+        let gold_before_ante = player.get_gold();
+        player.change_gold(-amount.max(0), "Gambling ante");
+        gambling_round.pot_amount = gambling_round
+            .pot_amount
+            .saturating_add(gold_before_ante - player.get_gold());
     }
 
     pub fn pass(&mut self, player_manager: &mut PlayerManager, turn_info: &mut TurnInfo) {
@@ -96,10 +129,9 @@ impl GamblingManager {
         };
 
         if let Some(winner) = winner_or {
-            player_manager
-                .get_player_by_uuid_mut(&winner)
-                .unwrap()
-                .change_gold(pot_amount);
+            let winner = player_manager.get_player_by_uuid_mut(&winner).unwrap();
+            winner.change_gold(pot_amount, "Won gambling round");
+            winner.record_transient_event(TransientEvent::PotAwarded { amount: pot_amount });
             self.end_round_and_discard_gold(turn_info);
         }
     }
@@ -111,6 +143,27 @@ impl GamblingManager {
         }
     }
 
+    #[cfg(test)]
+    pub fn get_pot_amount(&self) -> i32 {
+        match &self.gambling_round_or {
+            Some(gambling_round) => gambling_round.pot_amount,
+            None => 0,
+        }
+    }
+
+    pub fn get_game_view_gambling_data_or(&self) -> Option<GameViewGamblingData> {
+        self.gambling_round_or
+            .as_ref()
+            .map(|gambling_round| GameViewGamblingData {
+                pot_amount: gambling_round.pot_amount,
+                active_player_uuids: gambling_round.active_player_uuids.clone(),
+                current_player_turn: gambling_round.current_player_turn.clone(),
+                winning_player_uuid: gambling_round.winning_player.clone(),
+                need_cheating_card_to_take_next_control: gambling_round
+                    .need_cheating_card_to_take_next_control,
+            })
+    }
+
     pub fn end_round_and_discard_gold(&mut self, turn_info: &mut TurnInfo) {
         self.gambling_round_or = None;
         turn_info.set_order_drinks_phase();
@@ -166,6 +219,7 @@ struct GamblingRound {
     current_player_turn: PlayerUUID,
     winning_player: PlayerUUID,
     pot_amount: i32,
+    base_ante: i32,
     need_cheating_card_to_take_next_control: bool,
 }
 
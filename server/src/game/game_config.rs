@@ -0,0 +1,43 @@
+use super::gambling_manager::DEFAULT_GAMBLING_ANTE;
+
+/// Maximum fortitude and alcohol content under the standard rules.
+const DEFAULT_STAT_CAP: i32 = 20;
+
+/// Optional rule variants that can be set per game before it starts.
+/// The default config plays by the standard rules.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameConfig {
+    /// Maximum number of cards a player may discard during their discard phase.
+    /// Unlimited if `None`.
+    pub max_discards_per_turn: Option<usize>,
+    /// Highest fortitude a player can reach.
+    pub max_fortitude: i32,
+    /// Highest alcohol content a player can reach.
+    pub max_alcohol_content: i32,
+    /// Gold that each player antes when a gambling round starts or is raised.
+    pub gambling_ante: i32,
+    /// Picks the starting player at random, but reproducibly, so that tournament organizers can
+    /// show afterwards that the pick was fair. The first player to join starts if `None`.
+    pub first_player_seed: Option<u64>,
+    /// Ends the game as soon as a player has at least this much gold, making them the winner.
+    /// Only the last player standing wins if `None`.
+    pub gold_to_win: Option<i32>,
+    /// Waits to draw each player's starting hand until it is first needed, rather than drawing
+    /// every hand when the game starts. Makes creating many games at once faster. Players get the
+    /// same hands either way.
+    pub lazy_starting_hands: bool,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            max_discards_per_turn: None,
+            max_fortitude: DEFAULT_STAT_CAP,
+            max_alcohol_content: DEFAULT_STAT_CAP,
+            gambling_ante: DEFAULT_GAMBLING_ANTE,
+            first_player_seed: None,
+            gold_to_win: None,
+            lazy_starting_hands: false,
+        }
+    }
+}
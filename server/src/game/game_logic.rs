@@ -5,15 +5,31 @@ use super::drink::{
 };
 use super::gambling_manager::GamblingManager;
 use super::interrupt_manager::{InterruptManager, InterruptStackResolveData};
+#[cfg(test)]
+use super::player::Player;
+use super::player::{validate_deck, HAND_SIZE};
 use super::player_card::{PlayerCard, RootPlayerCard, ShouldInterrupt, TargetStyle};
 use super::player_manager::{NextPlayerUUIDOption, PlayerManager};
 use super::player_view::{
-    GameViewDrinkEvent, GameViewInterruptData, GameViewPlayerCard, GameViewPlayerData,
+    CardPlayability, GameViewDrinkEvent, GameViewGamblingData, GameViewInterruptData,
+    GameViewPlayerCard, GameViewPlayerData, GameViewStatChangeEvent, GameViewTransientEvent,
 };
 use super::uuid::PlayerUUID;
-use super::{Character, Error};
+use super::{Character, Error, GameConfig};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::Serialize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+
+/// Maximum number of idempotency keys that are remembered per player between their turns.
+const MAX_IDEMPOTENCY_KEYS_PER_PLAYER: usize = 20;
+
+/// Maximum number of transient events that are kept for a player who hasn't fetched their view.
+const MAX_UNSEEN_TRANSIENT_EVENTS: usize = 50;
+
+/// Idempotency keys paired with the result of the action that was first sent with each key.
+type IdempotentActionResults = VecDeque<(String, Result<(), Error>)>;
 
 #[derive(Clone, Debug)]
 pub struct GameLogic {
@@ -23,36 +39,236 @@ pub struct GameLogic {
     drink_deck: AutoShufflingDeck<DrinkCard>,
     turn_info: TurnInfo,
     drink_event_or: Option<DrinkEventWithData>,
+    config: GameConfig,
+    // Determines every shuffle in the game, so that the game can be replayed from its log.
+    seed: u64,
+    // Every action that successfully changed the game state, in the order they happened.
+    game_log: Vec<GameEvent>,
+    // Results of recent actions that were sent with an idempotency key, so that retried requests
+    // aren't applied twice. Each player's entries are cleared when their next turn starts.
+    idempotent_action_results: HashMap<PlayerUUID, IdempotentActionResults>,
+    // The game as it was before the most recent action, if that action can still be undone.
+    undo_snapshot_or: Option<UndoSnapshot>,
+    // Transient events that each player hasn't been sent yet, oldest first.
+    unseen_transient_events: UnseenTransientEvents,
+}
+
+/// Has its own lock so that handing out transient events only needs a read lock on the game.
+#[derive(Debug, Default)]
+struct UnseenTransientEvents(Mutex<HashMap<PlayerUUID, VecDeque<GameViewTransientEvent>>>);
+
+impl Clone for UnseenTransientEvents {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(self.0.lock().unwrap().clone()))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct UndoSnapshot {
+    player_uuid: PlayerUUID,
+    game_logic: Box<GameLogic>,
 }
 
 impl GameLogic {
+    #[cfg(test)]
     pub fn new(players_with_characters: Vec<(PlayerUUID, Character)>) -> Result<Self, Error> {
+        Self::new_with_config(players_with_characters, GameConfig::default())
+    }
+
+    pub fn new_with_config(
+        players_with_characters: Vec<(PlayerUUID, Character)>,
+        config: GameConfig,
+    ) -> Result<Self, Error> {
+        Self::new_with_seed(players_with_characters, config, rand::random())
+    }
+
+    /// Creates a game where every deck is shuffled deterministically based on `seed`.
+    pub fn new_with_seed(
+        players_with_characters: Vec<(PlayerUUID, Character)>,
+        config: GameConfig,
+        seed: u64,
+    ) -> Result<Self, Error> {
         if !(2..=8).contains(&players_with_characters.len()) {
             return Err(Error::new("Must have between 2 and 8 players"));
         }
 
-        // TODO - Set the first player to a random player (or whatever official RDI rules say).
-        let first_player_uuid = players_with_characters.first().unwrap().0.clone();
+        for (_, character) in &players_with_characters {
+            validate_deck(&character.create_deck())?;
+        }
+
+        // TODO - Set the first player to a random player (or whatever official RDI rules say)
+        // when no seed is given.
+        let first_player_index = match config.first_player_seed {
+            Some(first_player_seed) => {
+                StdRng::seed_from_u64(first_player_seed).gen_range(0..players_with_characters.len())
+            }
+            None => 0,
+        };
+        let first_player_uuid = players_with_characters[first_player_index].0.clone();
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut player_manager =
+            PlayerManager::new_with_config(players_with_characters, &config, &mut rng);
+        if let Some(first_player) = player_manager.get_player_by_uuid_mut(&first_player_uuid) {
+            first_player.record_stat_snapshot();
+        }
 
         Ok(Self {
-            player_manager: PlayerManager::new(players_with_characters),
-            gambling_manager: GamblingManager::new(),
+            player_manager,
+            gambling_manager: GamblingManager::new_with_base_ante(config.gambling_ante),
             interrupt_manager: InterruptManager::new(),
-            drink_deck: AutoShufflingDeck::new(create_drink_deck()),
+            drink_deck: AutoShufflingDeck::new_seeded(create_drink_deck(), rng.gen()),
             turn_info: TurnInfo::new(first_player_uuid),
             drink_event_or: None,
+            config,
+            seed,
+            game_log: Vec::new(),
+            idempotent_action_results: HashMap::new(),
+            undo_snapshot_or: None,
+            unseen_transient_events: UnseenTransientEvents::default(),
         })
     }
 
+    /// Rebuilds a game by re-applying every event of a recorded game log to a new game created
+    /// with the same seed, players and config. Returns an error if any event is not a legal action
+    /// at the point it's replayed.
+    #[cfg(test)]
+    pub fn replay(
+        seed: u64,
+        players_with_characters: Vec<(PlayerUUID, Character)>,
+        config: GameConfig,
+        events: &[GameEvent],
+    ) -> Result<Self, Error> {
+        let mut game_logic = Self::new_with_seed(players_with_characters, config, seed)?;
+        for (i, event) in events.iter().enumerate() {
+            if let Err(err) = game_logic.replay_event(event) {
+                return Err(Error::new(format!(
+                    "Failed to replay event {}: {:?}",
+                    i, err
+                )));
+            }
+        }
+        if game_logic.game_log != events {
+            return Err(Error::new(
+                "Replayed game log does not match the recorded game log",
+            ));
+        }
+        Ok(game_logic)
+    }
+
+    #[cfg(test)]
+    fn replay_event(&mut self, event: &GameEvent) -> Result<(), Error> {
+        let player_uuid = &event.player_uuid;
+        self.draw_starting_hand_if_needed(player_uuid);
+        match event.action {
+            GameEventAction::PlayCard => match event.card_indices.first() {
+                Some(card_index) => {
+                    self.play_card(player_uuid, &event.target_player_uuid, *card_index)
+                }
+                None => Err(Error::new("Played card is missing its card index")),
+            },
+            GameEventAction::OrderDrink => match &event.target_player_uuid {
+                Some(target_player_uuid) => self.order_drink(player_uuid, target_player_uuid),
+                None => Err(Error::new("Ordered drink is missing its target player")),
+            },
+            GameEventAction::Pass => self.pass(player_uuid),
+            GameEventAction::DiscardCards => {
+                // Both kinds of discard are logged the same way, but they can only happen during different turn phases.
+                if self.turn_info.turn_phase == TurnPhase::DiscardAndDraw {
+                    self.discard_cards_and_draw_to_full(player_uuid, event.card_indices.clone())
+                } else {
+                    match event.card_indices.first() {
+                        Some(card_index) => self.discard_single_card(player_uuid, *card_index),
+                        None => Err(Error::new("Discarded card is missing its card index")),
+                    }
+                }
+            }
+            GameEventAction::ArrangeHand => {
+                self.arrange_hand(player_uuid, event.card_indices.clone())
+            }
+            GameEventAction::Forfeit => self.forfeit(player_uuid),
+            GameEventAction::SkipTurn => self.skip_turn(player_uuid),
+        }
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
     pub fn get_turn_info(&self) -> &TurnInfo {
         &self.turn_info
     }
 
+    #[cfg(test)]
+    pub fn get_gambling_manager(&self) -> &GamblingManager {
+        &self.gambling_manager
+    }
+
+    #[cfg(test)]
+    pub fn get_player_by_uuid(&self, player_uuid: &PlayerUUID) -> Option<&Player> {
+        self.player_manager.get_player_by_uuid(player_uuid)
+    }
+
+    #[cfg(test)]
+    pub fn get_player_by_uuid_mut(&mut self, player_uuid: &PlayerUUID) -> Option<&mut Player> {
+        self.player_manager.get_player_by_uuid_mut(player_uuid)
+    }
+
     pub fn get_game_view_player_data_of_all_players(&self) -> Vec<GameViewPlayerData> {
         self.player_manager
             .get_game_view_player_data_of_all_players()
     }
 
+    pub fn get_game_view_player_data_or(
+        &self,
+        player_uuid: &PlayerUUID,
+    ) -> Option<GameViewPlayerData> {
+        self.player_manager
+            .get_player_by_uuid(player_uuid)
+            .map(|player| player.to_game_view_player_data(player_uuid.clone()))
+    }
+
+    /// Returns the players that the card at the given index in the player's hand can be directed
+    /// at. Cards that aren't directed at a single other player have no targets to pick from.
+    pub fn get_card_target_uuids(
+        &self,
+        player_uuid: &PlayerUUID,
+        card_index: usize,
+    ) -> Result<Vec<PlayerUUID>, Error> {
+        let player = match self.player_manager.get_player_by_uuid(player_uuid) {
+            Some(player) => player,
+            None => {
+                return Err(Error::new(format!(
+                    "Player does not exist with player id {}",
+                    player_uuid.to_string()
+                )))
+            }
+        };
+
+        let hand = player.get_hand();
+        let card = match hand.get(card_index) {
+            Some(card) => card,
+            None => return Err(Error::new("Card does not exist")),
+        };
+
+        if !card.is_directed() {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .player_manager
+            .clone_uuids_of_all_alive_players()
+            .into_iter()
+            .filter(|other_player_uuid| other_player_uuid != player_uuid)
+            .collect())
+    }
+
+    pub fn get_cards_until_reshuffle_or(&self, player_uuid: &PlayerUUID) -> Option<usize> {
+        self.player_manager
+            .get_player_by_uuid(player_uuid)
+            .map(|player| player.get_cards_until_reshuffle())
+    }
+
     pub fn get_game_view_player_hand(&self, player_uuid: &PlayerUUID) -> Vec<GameViewPlayerCard> {
         match self.player_manager.get_player_by_uuid(player_uuid) {
             Some(player) => player.get_game_view_hand(
@@ -65,6 +281,157 @@ impl GameLogic {
         }
     }
 
+    /// Draws the player's starting hand if the game uses `lazy_starting_hands` and it hasn't been
+    /// drawn yet. Only the player's own actions change their hand, so this is done right before
+    /// each of them.
+    pub fn draw_starting_hand_if_needed(&mut self, player_uuid: &PlayerUUID) {
+        if let Some(player) = self.player_manager.get_player_by_uuid_mut(player_uuid) {
+            player.draw_starting_hand_if_needed();
+        }
+    }
+
+    /// Whether the player can play the card at `card_index` right now, and if not, why not.
+    pub fn get_card_playability(
+        &self,
+        player_uuid: &PlayerUUID,
+        card_index: usize,
+    ) -> Result<CardPlayability, Error> {
+        let player = match self.player_manager.get_player_by_uuid(player_uuid) {
+            Some(player) => player,
+            None => {
+                return Err(Error::new(format!(
+                    "Player does not exist with player id {}",
+                    player_uuid.to_string()
+                )))
+            }
+        };
+
+        let hand = player.get_hand();
+        let card = match hand.get(card_index) {
+            Some(card) => card,
+            None => return Err(Error::new("Card does not exist")),
+        };
+
+        let reason_or = if !card.can_play(
+            player_uuid,
+            &self.gambling_manager,
+            &self.interrupt_manager,
+            &self.turn_info,
+        ) {
+            Some(self.get_cannot_play_card_reason(player_uuid, card))
+        } else if !player.can_afford_to_play(card, &self.gambling_manager) {
+            Some("Cannot start a round of gambling without any gold".to_string())
+        } else {
+            None
+        };
+
+        Ok(CardPlayability {
+            playable: reason_or.is_none(),
+            reason: reason_or,
+        })
+    }
+
+    /// Explains why `PlayerCard::can_play` returned false. Each card decides for itself when it can
+    /// be played, so this covers the usual reasons for the card's type and otherwise falls back on
+    /// a general message.
+    fn get_cannot_play_card_reason(&self, player_uuid: &PlayerUUID, card: &PlayerCard) -> String {
+        match card {
+            PlayerCard::RootPlayerCard(root_player_card) => {
+                let is_gambling_or_cheating_card =
+                    root_player_card.is_gambling_card() || root_player_card.is_cheating_card();
+                if (root_player_card.is_action_card() || is_gambling_or_cheating_card)
+                    && self.interrupt_manager.interrupt_in_progress()
+                {
+                    return "Cannot play this card while players are responding to another card"
+                        .to_string();
+                }
+
+                if is_gambling_or_cheating_card && self.gambling_manager.round_in_progress() {
+                    if !self.gambling_manager.is_turn(player_uuid) {
+                        return "It is not your turn in the round of gambling".to_string();
+                    }
+                } else if root_player_card.is_action_card() {
+                    if self.turn_info.get_current_player_turn() != player_uuid {
+                        return "It is not your turn".to_string();
+                    }
+                    if self.turn_info.turn_phase != TurnPhase::Action {
+                        return format!(
+                            "Action cards cannot be played during the {:?} phase",
+                            self.turn_info.turn_phase
+                        );
+                    }
+                    if self.gambling_manager.round_in_progress() {
+                        return "Action cards cannot be played during a round of gambling"
+                            .to_string();
+                    }
+                } else if is_gambling_or_cheating_card {
+                    return "Can only be played during a round of gambling".to_string();
+                }
+            }
+            PlayerCard::InterruptPlayerCard(_) => {
+                if !self.interrupt_manager.interrupt_in_progress() {
+                    return "There is nothing to respond to with this card".to_string();
+                }
+                if !self.interrupt_manager.is_turn_to_interrupt(player_uuid) {
+                    return "It is not your turn to respond".to_string();
+                }
+            }
+        }
+        "Cannot play card at this time".to_string()
+    }
+
+    pub fn get_game_view_stat_change_events(
+        &self,
+        player_uuid: &PlayerUUID,
+    ) -> Vec<GameViewStatChangeEvent> {
+        match self.player_manager.get_player_by_uuid(player_uuid) {
+            Some(player) => player.get_game_view_stat_change_events(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the transient events that the player hasn't been sent yet, and forgets them so that
+    /// each event is only returned once.
+    pub fn take_transient_events(&self, player_uuid: &PlayerUUID) -> Vec<GameViewTransientEvent> {
+        match self
+            .unseen_transient_events
+            .0
+            .lock()
+            .unwrap()
+            .get_mut(player_uuid)
+        {
+            Some(unseen_transient_events) => unseen_transient_events.drain(..).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Moves the transient events recorded by each player into every player's unseen events.
+    fn collect_transient_events(&mut self) {
+        let mut new_events = Vec::new();
+        for (player_uuid, player) in self.player_manager.iter_mut_players() {
+            for event in player.take_transient_events() {
+                new_events.push(GameViewTransientEvent {
+                    player_uuid: player_uuid.clone(),
+                    event,
+                });
+            }
+        }
+        if new_events.is_empty() {
+            return;
+        }
+
+        let unseen_transient_events_by_player = self.unseen_transient_events.0.get_mut().unwrap();
+        for player_uuid in self.player_manager.clone_uuids_of_all_players() {
+            let unseen_transient_events = unseen_transient_events_by_player
+                .entry(player_uuid)
+                .or_default();
+            unseen_transient_events.extend(new_events.iter().cloned());
+            while unseen_transient_events.len() > MAX_UNSEEN_TRANSIENT_EVENTS {
+                unseen_transient_events.pop_front();
+            }
+        }
+    }
+
     pub fn get_game_view_drink_event_or(&self) -> Option<GameViewDrinkEvent> {
         self.drink_event_or
             .as_ref()
@@ -74,9 +441,7 @@ impl GameLogic {
                     drinking_contest_remaining_player_uuids: Some(
                         drinking_contest_data
                             .get_currently_winning_players()
-                            .iter()
-                            .cloned()
-                            .collect(),
+                            .clone(),
                     ),
                 },
                 DrinkEventWithData::RoundOnTheHouse => GameViewDrinkEvent {
@@ -87,7 +452,20 @@ impl GameLogic {
     }
 
     pub fn get_game_view_interrupt_data_or(&self) -> Option<GameViewInterruptData> {
-        self.interrupt_manager.get_game_view_interrupt_data_or()
+        self.interrupt_manager
+            .get_game_view_interrupt_data_or(&self.player_manager)
+    }
+
+    pub fn get_game_view_gambling_data_or(&self) -> Option<GameViewGamblingData> {
+        self.gambling_manager.get_game_view_gambling_data_or()
+    }
+
+    pub fn interrupt_in_progress(&self) -> bool {
+        self.interrupt_manager.interrupt_in_progress()
+    }
+
+    pub fn get_current_interrupt_turn_or(&self) -> Option<&PlayerUUID> {
+        self.interrupt_manager.get_current_interrupt_turn_or()
     }
 
     pub fn get_turn_phase(&self) -> TurnPhase {
@@ -102,8 +480,13 @@ impl GameLogic {
     ) -> Result<(), Error> {
         self.assert_is_running()?;
 
-        let card_or = match self.player_manager.get_player_by_uuid_mut(player_uuid) {
-            Some(player) => player.pop_card_from_hand(card_index),
+        // Kept so that a card that fails to play goes back exactly where the player had it.
+        let (hand_snapshot, card_or) = match self.player_manager.get_player_by_uuid_mut(player_uuid)
+        {
+            Some(player) => (
+                player.get_hand().to_vec(),
+                player.pop_card_from_hand(card_index),
+            ),
             None => {
                 return Err(Error::new(format!(
                     "Player does not exist with player id {}",
@@ -119,6 +502,7 @@ impl GameLogic {
             None => return Err(Error::new("Card does not exist")),
         };
 
+        let card_name = card.get_display_name().to_string();
         match self.process_card(card, player_uuid, other_player_uuid_or) {
             Ok(card_or) => {
                 if let Some(card) = card_or {
@@ -127,18 +511,200 @@ impl GameLogic {
                         .unwrap()
                         .discard_card(card);
                 }
+                self.game_log.push(GameEvent {
+                    player_uuid: player_uuid.clone(),
+                    action: GameEventAction::PlayCard,
+                    target_player_uuid: other_player_uuid_or.clone(),
+                    card_name: Some(card_name),
+                    card_count: None,
+                    card_indices: vec![card_index],
+                });
                 Ok(())
             }
-            Err((card, err)) => {
+            Err((_card, err)) => {
+                // The snapshot already holds this card, so restoring it puts the card back.
                 self.player_manager
                     .get_player_by_uuid_mut(player_uuid)
                     .unwrap()
-                    .return_card_to_hand(card, card_index);
+                    .restore_hand(hand_snapshot);
                 Err(err)
             }
         }
     }
 
+    pub fn arrange_hand(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        card_order: Vec<usize>,
+    ) -> Result<(), Error> {
+        self.assert_is_running()?;
+
+        self.draw_starting_hand_if_needed(player_uuid);
+        match self.player_manager.get_player_by_uuid_mut(player_uuid) {
+            Some(player) => player.arrange_hand(card_order.clone())?,
+            None => return Err(Error::new("Player is not in the game")),
+        };
+        self.game_log.push(GameEvent {
+            player_uuid: player_uuid.clone(),
+            action: GameEventAction::ArrangeHand,
+            target_player_uuid: None,
+            card_name: None,
+            card_count: None,
+            card_indices: card_order,
+        });
+        Ok(())
+    }
+
+    /// Performs the action, unless the player already sent an action with the same idempotency key
+    /// since their turn last started. In that case the earlier result is returned instead.
+    pub fn perform_idempotent_action(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        idempotency_key_or: Option<String>,
+        action: impl FnOnce(&mut Self) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let idempotency_key = match idempotency_key_or {
+            Some(idempotency_key) => idempotency_key,
+            None => return self.perform_undoable_action(player_uuid, action),
+        };
+
+        if let Some(action_results) = self.idempotent_action_results.get(player_uuid) {
+            if let Some((_, result)) = action_results
+                .iter()
+                .find(|(key, _)| *key == idempotency_key)
+            {
+                return result.clone();
+            }
+        }
+
+        let result = self.perform_undoable_action(player_uuid, action);
+        let action_results = self
+            .idempotent_action_results
+            .entry(player_uuid.clone())
+            .or_default();
+        action_results.push_back((idempotency_key, result.clone()));
+        if action_results.len() > MAX_IDEMPOTENCY_KEYS_PER_PLAYER {
+            action_results.pop_front();
+        }
+        result
+    }
+
+    /// Performs the action, remembering the game as it was beforehand so that the player can undo
+    /// it. The action can't be undone if it...
+    /// 1. Passed the turn to another player.
+    /// 2. Knocked a player out of the game.
+    /// 3. Revealed information, such as drawing cards or revealing a drink.
+    fn perform_undoable_action(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        action: impl FnOnce(&mut Self) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        // Drawn before the snapshot is taken, so that undoing can't put the cards back.
+        self.draw_starting_hand_if_needed(player_uuid);
+        // The previous snapshot is taken out first so that it isn't cloned into the new one.
+        let previous_undo_snapshot_or = self.undo_snapshot_or.take();
+        let snapshot_or = if self.can_undo_any_action_of(player_uuid) {
+            Some(self.clone())
+        } else {
+            None
+        };
+
+        let result = action(self);
+        // Collected after every action so that events stay in the order they happened.
+        self.collect_transient_events();
+        self.undo_snapshot_or = if result.is_ok() {
+            snapshot_or
+                .filter(|snapshot| self.can_undo_back_to(snapshot))
+                .map(|snapshot| UndoSnapshot {
+                    player_uuid: player_uuid.clone(),
+                    game_logic: Box::new(snapshot),
+                })
+        } else {
+            previous_undo_snapshot_or
+        };
+        result
+    }
+
+    /// Whether any action the player takes from here could pass `can_undo_back_to`. Used to skip
+    /// cloning the game for actions that obviously can't be undone.
+    fn can_undo_any_action_of(&self, player_uuid: &PlayerUUID) -> bool {
+        // The Drink phase only ends when the next player's turn starts.
+        if self.turn_info.turn_phase == TurnPhase::Drink {
+            return false;
+        }
+
+        // The only way out of the Discard and Draw phase is to draw back up to a full hand.
+        if self.turn_info.turn_phase == TurnPhase::DiscardAndDraw
+            && self.turn_info.get_current_player_turn() == player_uuid
+        {
+            if let Some(player) = self.player_manager.get_player_by_uuid(player_uuid) {
+                return player.get_hand().len() >= HAND_SIZE;
+            }
+        }
+
+        true
+    }
+
+    fn can_undo_back_to(&self, earlier_game_logic: &GameLogic) -> bool {
+        if self.turn_info.get_current_player_turn()
+            != earlier_game_logic.turn_info.get_current_player_turn()
+            || self.player_manager.get_eliminated_player_uuids().len()
+                != earlier_game_logic
+                    .player_manager
+                    .get_eliminated_player_uuids()
+                    .len()
+            || self.turn_info.turn_phase == TurnPhase::Drink
+            || self.drink_event_or.is_some()
+        {
+            return false;
+        }
+
+        // Drawing cards or revealing drinks from a Drink Me! pile shows players something they
+        // can't unsee.
+        earlier_game_logic
+            .player_manager
+            .get_game_view_player_data_of_all_players()
+            .iter()
+            .zip(
+                self.player_manager
+                    .get_game_view_player_data_of_all_players()
+                    .iter(),
+            )
+            .all(|(earlier_player_data, player_data)| {
+                player_data.draw_pile_size == earlier_player_data.draw_pile_size
+                    && player_data.drink_me_pile_size >= earlier_player_data.drink_me_pile_size
+            })
+    }
+
+    /// Passes on behalf of a player who took too long to respond to an interrupt. Unlike the
+    /// player's own actions this can't be undone, since that would let them cancel their timeout.
+    pub fn auto_pass(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        self.pass(player_uuid)?;
+        self.undo_snapshot_or = None;
+        self.collect_transient_events();
+        Ok(())
+    }
+
+    /// Restores the game to how it was before the player's most recent action. Only a single
+    /// action can be undone, and only until another player acts.
+    pub fn undo(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        self.assert_is_running()?;
+
+        match self.undo_snapshot_or.take() {
+            Some(undo_snapshot) if &undo_snapshot.player_uuid == player_uuid => {
+                // Events that players have already been sent shouldn't be sent again.
+                let unseen_transient_events = std::mem::take(&mut self.unseen_transient_events);
+                *self = *undo_snapshot.game_logic;
+                self.unseen_transient_events = unseen_transient_events;
+                Ok(())
+            }
+            undo_snapshot_or => {
+                self.undo_snapshot_or = undo_snapshot_or;
+                Err(Error::new("There is no action to undo"))
+            }
+        }
+    }
+
     pub fn discard_cards_and_draw_to_full(
         &mut self,
         player_uuid: &PlayerUUID,
@@ -146,10 +712,24 @@ impl GameLogic {
     ) -> Result<(), Error> {
         self.assert_is_running()?;
 
-        if self.get_turn_info().get_current_player_turn() != player_uuid
-            || self.turn_info.turn_phase != TurnPhase::DiscardAndDraw
-        {
-            return Err(Error::new("Cannot discard cards at this time"));
+        if self.get_turn_info().get_current_player_turn() != player_uuid {
+            return Err(Error::new("It is not your turn"));
+        }
+
+        if self.turn_info.turn_phase != TurnPhase::DiscardAndDraw {
+            return Err(Error::new(format!(
+                "Cards can only be discarded during the discard phase (current phase: {:?})",
+                self.turn_info.turn_phase
+            )));
+        }
+
+        if let Some(max_discards_per_turn) = self.config.max_discards_per_turn {
+            if card_indices.len() > max_discards_per_turn {
+                return Err(Error::new(format!(
+                    "Cannot discard more than {} cards per turn",
+                    max_discards_per_turn
+                )));
+            }
         }
 
         let player = match self.player_manager.get_player_by_uuid_mut(player_uuid) {
@@ -172,7 +752,8 @@ impl GameLogic {
         card_indices.sort_unstable();
         card_indices.reverse();
 
-        for card_index in card_indices {
+        let card_count = card_indices.len();
+        for card_index in card_indices.clone() {
             let card = match player.pop_card_from_hand(card_index) {
                 Some(card) => card,
                 // Since we're iterating through the card indices in reverse order, and
@@ -190,26 +771,90 @@ impl GameLogic {
         }
         player.draw_to_full();
         self.turn_info.turn_phase = TurnPhase::Action;
+        self.game_log.push(GameEvent {
+            player_uuid: player_uuid.clone(),
+            action: GameEventAction::DiscardCards,
+            target_player_uuid: None,
+            card_name: None,
+            card_count: Some(card_count),
+            card_indices,
+        });
         Ok(())
     }
 
-    pub fn order_drink(
+    /// Discards a single card from the player's hand as their action, without
+    /// applying its effect. Used to bluff, since other players only see that a
+    /// card was discarded. Unlike `discard_cards_and_draw_to_full`, the player
+    /// does not draw a replacement card.
+    pub fn discard_single_card(
         &mut self,
         player_uuid: &PlayerUUID,
-        other_player_uuid: &PlayerUUID,
+        card_index: usize,
     ) -> Result<(), Error> {
         self.assert_is_running()?;
 
-        if self.get_turn_info().get_current_player_turn() != player_uuid
-            || self.turn_info.turn_phase != TurnPhase::OrderDrinks
+        if !self
+            .turn_info
+            .can_play_action_card(player_uuid, &self.gambling_manager)
+            || self.interrupt_manager.interrupt_in_progress()
         {
-            return Err(Error::new("Cannot order drinks at this time"));
+            return Err(Error::new("Cannot discard a card at this time"));
+        }
+
+        let player = match self.player_manager.get_player_by_uuid_mut(player_uuid) {
+            Some(player) => player,
+            None => return Err(Error::new("Player is not in the game")),
+        };
+
+        let card = match player.pop_card_from_hand(card_index) {
+            Some(card) => card,
+            None => return Err(Error::new("Card does not exist")),
+        };
+        player.discard_card(card);
+        self.skip_action_phase()?;
+        self.game_log.push(GameEvent {
+            player_uuid: player_uuid.clone(),
+            action: GameEventAction::DiscardCards,
+            target_player_uuid: None,
+            card_name: None,
+            card_count: Some(1),
+            card_indices: vec![card_index],
+        });
+        Ok(())
+    }
+
+    fn assert_can_order_drinks(&self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        if self.get_turn_info().get_current_player_turn() != player_uuid {
+            return Err(Error::new("It is not your turn"));
+        }
+
+        if self.turn_info.turn_phase != TurnPhase::OrderDrinks {
+            return Err(Error::new(format!(
+                "Drinks can only be ordered during the order drinks phase (current phase: {:?})",
+                self.turn_info.turn_phase
+            )));
         }
 
+        Ok(())
+    }
+
+    pub fn order_drink(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        other_player_uuid: &PlayerUUID,
+    ) -> Result<(), Error> {
+        self.assert_is_running()?;
+
+        self.assert_can_order_drinks(player_uuid)?;
+
         if player_uuid == other_player_uuid {
             return Err(Error::new("Cannot order drink for yourself"));
         }
 
+        if self.turn_info.drinks_to_order <= 0 {
+            return Err(Error::new("No drinks left to order"));
+        }
+
         let other_player = match self
             .player_manager
             .get_player_by_uuid_mut(other_player_uuid)
@@ -218,16 +863,33 @@ impl GameLogic {
             None => {
                 return Err(Error::new(format!(
                     "Player does not exist with player id {}",
-                    player_uuid.to_string()
+                    other_player_uuid.to_string()
                 )))
             }
         };
+        if other_player.is_out_of_game() {
+            return Err(Error::new(
+                "Cannot order drink for a player who is out of the game",
+            ));
+        }
 
-        if let Some(drink) = self.drink_deck.draw_card() {
-            other_player.add_drink_to_drink_pile(drink);
+        // Every drink might be sitting in players' Drink Me! piles, with none discarded yet to
+        // reshuffle. The drink can't be delivered then, so it isn't counted as ordered.
+        let drink = match self.drink_deck.draw_card() {
+            Some(drink) => drink,
+            None => return Err(Error::new("The drink deck is out of drinks")),
         };
-
-        self.turn_info.drinks_to_order -= 1;
+        other_player.add_drink_to_drink_pile(drink);
+        self.game_log.push(GameEvent {
+            player_uuid: player_uuid.clone(),
+            action: GameEventAction::OrderDrink,
+            target_player_uuid: Some(other_player_uuid.clone()),
+            card_name: None,
+            card_count: None,
+            card_indices: Vec::new(),
+        });
+
+        self.turn_info.drinks_to_order = self.turn_info.drinks_to_order.saturating_sub(1);
         if self.turn_info.drinks_to_order == 0 {
             self.start_drink_phase(player_uuid)?;
         }
@@ -235,8 +897,223 @@ impl GameLogic {
         Ok(())
     }
 
-    pub fn player_can_pass(&self, player_uuid: &PlayerUUID) -> bool {
-        self.clone().pass(player_uuid).is_ok()
+    /// Orders all of the player's remaining drinks at once, in the given order.
+    /// Either every drink is ordered or, if any target is invalid, none are.
+    pub fn order_drinks(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        other_player_uuids: Vec<PlayerUUID>,
+    ) -> Result<(), Error> {
+        self.assert_is_running()?;
+
+        self.assert_can_order_drinks(player_uuid)?;
+
+        if other_player_uuids.len() as i32 != self.turn_info.drinks_to_order {
+            return Err(Error::new(format!(
+                "Must order exactly {} drinks",
+                self.turn_info.drinks_to_order
+            )));
+        }
+
+        for other_player_uuid in &other_player_uuids {
+            if other_player_uuid == player_uuid {
+                return Err(Error::new("Cannot order drink for yourself"));
+            }
+            match self.player_manager.get_player_by_uuid(other_player_uuid) {
+                Some(other_player) => {
+                    if other_player.is_out_of_game() {
+                        return Err(Error::new(
+                            "Cannot order drink for a player who is out of the game",
+                        ));
+                    }
+                }
+                None => {
+                    return Err(Error::new(format!(
+                        "Player does not exist with player id {}",
+                        other_player_uuid.to_string()
+                    )))
+                }
+            }
+        }
+
+        if self.drink_deck.drawable_card_count() < other_player_uuids.len() {
+            return Err(Error::new("The drink deck is out of drinks"));
+        }
+
+        for other_player_uuid in &other_player_uuids {
+            self.order_drink(player_uuid, other_player_uuid)?;
+        }
+
+        Ok(())
+    }
+
+    /// Plays out the player's whole turn from the discard phase onwards. Every step is checked as
+    /// it's applied, and if any step fails the game is put back exactly as it was before the call.
+    /// Turns that would have to wait on other players, such as by playing a card that they can
+    /// respond to before ordering drinks, fail since the drinks can't be ordered yet.
+    #[cfg(test)]
+    pub fn apply_turn(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        turn_script: TurnScript,
+    ) -> Result<(), Error> {
+        let snapshot = self.clone();
+        let result = self.apply_turn_steps(player_uuid, turn_script);
+        if result.is_err() {
+            *self = snapshot;
+        }
+        result
+    }
+
+    #[cfg(test)]
+    fn apply_turn_steps(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        turn_script: TurnScript,
+    ) -> Result<(), Error> {
+        self.discard_cards_and_draw_to_full(player_uuid, turn_script.discard_card_indices)?;
+        if turn_script.cards_to_play.is_empty() {
+            self.pass(player_uuid)?;
+        }
+        for (card_index, other_player_uuid_or) in &turn_script.cards_to_play {
+            self.play_card(player_uuid, other_player_uuid_or, *card_index)?;
+        }
+        self.order_drinks(player_uuid, turn_script.drink_target_uuids)
+    }
+
+    /// Lists every action that the player can currently take, including each valid target for
+    /// directed cards and drinks. Players who are out of the game have no legal actions.
+    pub fn legal_actions(&self, player_uuid: &PlayerUUID) -> Vec<LegalAction> {
+        let mut legal_actions = Vec::new();
+
+        let player = match self.player_manager.get_player_by_uuid(player_uuid) {
+            Some(player) => player,
+            None => return legal_actions,
+        };
+        if !self.is_running() || player.is_out_of_game() {
+            return legal_actions;
+        }
+
+        if self.can_pass(player_uuid) {
+            legal_actions.push(LegalAction::Pass);
+        }
+
+        let other_alive_player_uuids: Vec<PlayerUUID> = self
+            .player_manager
+            .clone_uuids_of_all_alive_players()
+            .into_iter()
+            .filter(|other_player_uuid| other_player_uuid != player_uuid)
+            .collect();
+
+        for (card_index, card) in player.get_hand().iter().enumerate() {
+            if !card.can_play(
+                player_uuid,
+                &self.gambling_manager,
+                &self.interrupt_manager,
+                &self.turn_info,
+            ) || !player.can_afford_to_play(card, &self.gambling_manager)
+            {
+                continue;
+            }
+            if card.is_directed() {
+                for other_player_uuid in &other_alive_player_uuids {
+                    legal_actions.push(LegalAction::PlayCard {
+                        card_index,
+                        target_player_uuid: Some(other_player_uuid.clone()),
+                    });
+                }
+            } else {
+                legal_actions.push(LegalAction::PlayCard {
+                    card_index,
+                    target_player_uuid: None,
+                });
+            }
+        }
+
+        if self.turn_info.get_current_player_turn() == player_uuid {
+            match self.turn_info.turn_phase {
+                TurnPhase::DiscardAndDraw => legal_actions.push(LegalAction::DiscardCards),
+                TurnPhase::Action => {
+                    if self
+                        .turn_info
+                        .can_play_action_card(player_uuid, &self.gambling_manager)
+                        && !self.interrupt_manager.interrupt_in_progress()
+                    {
+                        legal_actions.push(LegalAction::DiscardSingleCard);
+                    }
+                }
+                TurnPhase::OrderDrinks if self.drink_deck.drawable_card_count() > 0 => {
+                    for other_player_uuid in &other_alive_player_uuids {
+                        legal_actions.push(LegalAction::OrderDrink {
+                            target_player_uuid: other_player_uuid.clone(),
+                        });
+                    }
+                }
+                TurnPhase::OrderDrinks | TurnPhase::Drink => {}
+            }
+        }
+
+        legal_actions
+    }
+
+    /// Names of the interrupt cards in the player's hand that they could respond to the current
+    /// interrupt with. Each name is only listed once, even if the player holds several copies.
+    pub fn available_interrupt_responses(&self, player_uuid: &PlayerUUID) -> Vec<String> {
+        let mut card_names: Vec<String> = Vec::new();
+        let player = match self.player_manager.get_player_by_uuid(player_uuid) {
+            Some(player) => player,
+            None => return card_names,
+        };
+        for card in player.get_hand().iter() {
+            if let PlayerCard::InterruptPlayerCard(interrupt_player_card) = card {
+                let card_name = interrupt_player_card.get_display_name();
+                if interrupt_player_card.can_play(player_uuid, &self.interrupt_manager)
+                    && !card_names.iter().any(|name| name == card_name)
+                {
+                    card_names.push(card_name.to_string());
+                }
+            }
+        }
+        card_names
+    }
+
+    pub fn can_pass(&self, player_uuid: &PlayerUUID) -> bool {
+        self.get_cannot_pass_reason_or(player_uuid).is_none()
+    }
+
+    /// Explains why the player can't pass right now, or returns `None` if they can.
+    /// Mirrors the checks made by `pass` without having to perform it.
+    pub fn get_cannot_pass_reason_or(&self, player_uuid: &PlayerUUID) -> Option<String> {
+        if !self.is_running() {
+            return Some("Game is not running".to_string());
+        }
+
+        if self.interrupt_manager.interrupt_in_progress() {
+            return if self.interrupt_manager.is_turn_to_interrupt(player_uuid) {
+                None
+            } else {
+                Some("Waiting for another player to respond to an interrupt".to_string())
+            };
+        }
+
+        if self.gambling_manager.is_turn(player_uuid)
+            || self
+                .turn_info
+                .can_play_action_card(player_uuid, &self.gambling_manager)
+        {
+            return None;
+        }
+
+        if self.gambling_manager.round_in_progress() {
+            Some("Waiting for another player to act in the gambling round".to_string())
+        } else if self.turn_info.get_current_player_turn() != player_uuid {
+            Some("It is not your turn".to_string())
+        } else {
+            Some(format!(
+                "Cannot pass during the {:?} phase",
+                self.turn_info.turn_phase
+            ))
+        }
     }
 
     fn discard_cards(&mut self, interrupt_stack_resolve_data: InterruptStackResolveData) {
@@ -251,6 +1128,19 @@ impl GameLogic {
     }
 
     pub fn pass(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        self.resolve_pass(player_uuid)?;
+        self.game_log.push(GameEvent {
+            player_uuid: player_uuid.clone(),
+            action: GameEventAction::Pass,
+            target_player_uuid: None,
+            card_name: None,
+            card_count: None,
+            card_indices: Vec::new(),
+        });
+        Ok(())
+    }
+
+    fn resolve_pass(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
         self.assert_is_running()?;
 
         if self.interrupt_manager.interrupt_in_progress() {
@@ -259,6 +1149,7 @@ impl GameLogic {
                     &mut self.player_manager,
                     &mut self.gambling_manager,
                     &mut self.turn_info,
+                    &mut self.drink_deck,
                 )?;
                 if let Some(spent_cards) = spent_cards_or {
                     if spent_cards.current_user_action_phase_is_over() {
@@ -279,7 +1170,7 @@ impl GameLogic {
                                                 self.player_manager.iter_mut_players()
                                             {
                                                 if player_uuid != &winner_uuid {
-                                                    player.change_gold(-1);
+                                                    player.change_gold(-1, "Lost drinking contest");
                                                     winning_gold_amount += 1;
                                                 }
                                             }
@@ -287,7 +1178,10 @@ impl GameLogic {
                                                 .player_manager
                                                 .get_player_by_uuid_mut(&winner_uuid)
                                             {
-                                                winner.change_gold(winning_gold_amount);
+                                                winner.change_gold(
+                                                    winning_gold_amount,
+                                                    "Won drinking contest",
+                                                );
                                             }
 
                                             self.start_next_player_turn();
@@ -309,6 +1203,7 @@ impl GameLogic {
                         };
                     }
                     self.discard_cards(spent_cards);
+                    self.player_manager.record_eliminations();
                 }
                 return Ok(());
             } else {
@@ -319,6 +1214,7 @@ impl GameLogic {
         if self.gambling_manager.is_turn(player_uuid) {
             self.gambling_manager
                 .pass(&mut self.player_manager, &mut self.turn_info);
+            self.player_manager.record_eliminations();
             return Ok(());
         }
 
@@ -333,6 +1229,86 @@ impl GameLogic {
         Err(Error::new("Cannot pass at this time"))
     }
 
+    /// Removes a player from the running game, as if they had been knocked out.
+    pub fn forfeit(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        self.assert_is_running()?;
+
+        if self.interrupt_manager.interrupt_in_progress()
+            || self.gambling_manager.round_in_progress()
+        {
+            return Err(Error::new(
+                "Cannot forfeit during an interrupt or gambling round",
+            ));
+        }
+
+        match self.player_manager.get_player_by_uuid_mut(player_uuid) {
+            Some(player) => {
+                if player.is_out_of_game() {
+                    return Err(Error::new("Player is already out of the game"));
+                }
+                player.forfeit();
+            }
+            None => return Err(Error::new("Player is not in the game")),
+        };
+        self.player_manager.record_eliminations();
+        self.game_log.push(GameEvent {
+            player_uuid: player_uuid.clone(),
+            action: GameEventAction::Forfeit,
+            target_player_uuid: None,
+            card_name: None,
+            card_count: None,
+            card_indices: Vec::new(),
+        });
+
+        if self.turn_info.player_turn == *player_uuid {
+            self.start_next_player_turn();
+        }
+
+        Ok(())
+    }
+
+    /// Skips whatever is left of the player's action phase and any drinks they haven't ordered
+    /// yet. The player still drinks from their Drink Me! pile as usual.
+    pub fn skip_turn(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        self.assert_is_running()?;
+
+        if self.turn_info.get_current_player_turn() != player_uuid {
+            return Err(Error::new("It is not your turn"));
+        }
+        if self.interrupt_manager.interrupt_in_progress()
+            || self.gambling_manager.round_in_progress()
+        {
+            return Err(Error::new(
+                "Cannot skip turn during an interrupt or gambling round",
+            ));
+        }
+        if self.turn_info.turn_phase != TurnPhase::Action
+            && self.turn_info.turn_phase != TurnPhase::OrderDrinks
+        {
+            return Err(Error::new(format!(
+                "Turn can only be skipped during the action or order drinks phase (current phase: {:?})",
+                self.turn_info.turn_phase
+            )));
+        }
+
+        let turn_info_before_skip = self.turn_info.clone();
+        self.turn_info.drinks_to_order = 0;
+        if let Err(err) = self.start_drink_phase(player_uuid) {
+            // Put the turn back as it was, so that a failed skip changes nothing.
+            self.turn_info = turn_info_before_skip;
+            return Err(err);
+        }
+        self.game_log.push(GameEvent {
+            player_uuid: player_uuid.clone(),
+            action: GameEventAction::SkipTurn,
+            target_player_uuid: None,
+            card_name: None,
+            card_count: None,
+            card_indices: Vec::new(),
+        });
+        Ok(())
+    }
+
     /// The return type for this method is a bit complex, but was carefully chosen.
     /// If `Ok` is returned, then the wrapped card should be discarded if it exists.
     /// If an error is returned, the card should be returned to the player's hand.
@@ -373,6 +1349,7 @@ impl GameLogic {
                             &mut self.player_manager,
                             &mut self.gambling_manager,
                             &mut self.turn_info,
+                            &mut self.drink_deck,
                         ) {
                             Ok(spent_cards_or) => {
                                 if let Some(spent_cards) = spent_cards_or {
@@ -380,6 +1357,7 @@ impl GameLogic {
                                         self.skip_action_phase().unwrap();
                                     }
                                     self.discard_cards(spent_cards);
+                                    self.player_manager.record_eliminations();
                                 }
                                 Ok(None)
                             }
@@ -388,6 +1366,10 @@ impl GameLogic {
                     }
                 }
             }
+        } else if matches!(card, PlayerCard::InterruptPlayerCard(_))
+            && !self.interrupt_manager.interrupt_in_progress()
+        {
+            Err((card, Error::new("There is nothing to interrupt right now")))
         } else {
             Err((card, Error::new("Card cannot be played at this time")))
         }
@@ -435,10 +1417,7 @@ impl GameLogic {
                 match &mut drink_event_with_data {
                     DrinkEventWithData::DrinkingContest(drinking_contest_data) => {
                         drinking_contest_data.overwrite_currently_winning_players(
-                            self.player_manager
-                                .clone_uuids_of_all_alive_players()
-                                .into_iter()
-                                .collect(),
+                            self.player_manager.clone_uuids_of_all_alive_players(),
                         );
                         Self::perform_drinking_contest_round(
                             &self.player_manager,
@@ -463,7 +1442,7 @@ impl GameLogic {
                         for event in discardable_drink_events {
                             self.drink_deck.discard_card(event.into());
                         }
-                        self.interrupt_manager.start_multi_player_drink_interrupt(
+                        self.interrupt_manager.start_shared_drink_interrupt(
                             drink,
                             player_uuid.clone(),
                             self.player_manager
@@ -486,8 +1465,9 @@ impl GameLogic {
         drink_deck: &mut AutoShufflingDeck<DrinkCard>,
         drinking_contest_data: &mut DrinkingContestData,
     ) {
-        let mut player_drink_alcohol_contents: HashMap<PlayerUUID, i32> = HashMap::new();
+        let mut player_drink_alcohol_contents: Vec<(PlayerUUID, i32)> = Vec::new();
         let mut max_alcohol_content = i32::MIN;
+        let mut drinks_by_player = Vec::new();
         for player_uuid in drinking_contest_data.get_currently_winning_players() {
             if let Some(revealed_drink) = get_revealed_drink(drink_deck) {
                 let drink = DrinkWithPossibleChasers::from_revealed_drink_treating_drink_event_as_empty_drink(revealed_drink);
@@ -497,28 +1477,57 @@ impl GameLogic {
                         max_alcohol_content = drink_alcohol_content;
                     }
                     player_drink_alcohol_contents
-                        .insert(player_uuid.clone(), drink_alcohol_content);
+                        .push((player_uuid.clone(), drink_alcohol_content));
                 }
-                interrupt_manager.start_single_player_drink_interrupt(drink, player_uuid.clone());
-            }
-        }
-        let mut winning_players = HashSet::new();
-        for (player_uuid, drink_alcohol_content) in player_drink_alcohol_contents {
-            if drink_alcohol_content == max_alcohol_content {
-                winning_players.insert(player_uuid);
+                drinks_by_player.push((player_uuid.clone(), drink));
             }
         }
+        interrupt_manager.start_multi_player_drink_interrupt(drinks_by_player);
+        let winning_players = player_drink_alcohol_contents
+            .into_iter()
+            .filter(|(_, drink_alcohol_content)| *drink_alcohol_content == max_alcohol_content)
+            .map(|(player_uuid, _)| player_uuid)
+            .collect();
         drinking_contest_data.overwrite_currently_winning_players(winning_players);
     }
 
     fn start_next_player_turn(&mut self) {
+        let mut previous_player_uuid = self.turn_info.player_turn.clone();
+        // Pass over players who are due to skip a turn. Each skip is used up as it's passed over,
+        // so this always ends.
+        while let NextPlayerUUIDOption::Some(next_player_uuid) = self
+            .player_manager
+            .get_next_alive_player_uuid(&previous_player_uuid)
+        {
+            let next_player_uuid = next_player_uuid.clone();
+            let skips_turn = match self
+                .player_manager
+                .get_player_by_uuid_mut(&next_player_uuid)
+            {
+                Some(next_player) => next_player.take_skipped_turn(),
+                None => false,
+            };
+            if !skips_turn {
+                break;
+            }
+            previous_player_uuid = next_player_uuid;
+        }
+
         match self
             .player_manager
-            .get_next_alive_player_uuid(&self.turn_info.player_turn)
+            .get_next_alive_player_uuid(&previous_player_uuid)
         {
             NextPlayerUUIDOption::Some(next_player_uuid) => {
-                self.turn_info = TurnInfo::new(next_player_uuid.clone());
+                let next_player_uuid = next_player_uuid.clone();
                 self.drink_event_or = None;
+                self.idempotent_action_results.remove(&next_player_uuid);
+                if let Some(next_player) = self
+                    .player_manager
+                    .get_player_by_uuid_mut(&next_player_uuid)
+                {
+                    next_player.record_stat_snapshot();
+                }
+                self.turn_info = TurnInfo::new(next_player_uuid);
             }
             NextPlayerUUIDOption::PlayerNotFound => {
                 panic!("Player not found... How'd this happen?");
@@ -545,6 +1554,40 @@ impl GameLogic {
     pub fn get_winner_or(&self) -> Option<PlayerUUID> {
         self.player_manager.get_winner_or()
     }
+
+    pub fn clone_uuids_of_all_players(&self) -> Vec<PlayerUUID> {
+        self.player_manager.clone_uuids_of_all_players()
+    }
+
+    pub fn get_eliminated_player_uuids(&self) -> Vec<PlayerUUID> {
+        self.player_manager.get_eliminated_player_uuids()
+    }
+
+    pub fn get_standings(&self) -> Vec<Vec<PlayerUUID>> {
+        self.player_manager.get_standings()
+    }
+
+    pub fn get_game_log(&self) -> &Vec<GameEvent> {
+        &self.game_log
+    }
+
+    /// The most recent events where the player acted or was targeted, oldest first.
+    pub fn get_player_events(
+        &self,
+        player_uuid: &PlayerUUID,
+        max_event_count: usize,
+    ) -> Vec<GameEvent> {
+        let mut player_events: Vec<GameEvent> = self
+            .game_log
+            .iter()
+            .rev()
+            .filter(|event| event.involves_player(player_uuid))
+            .take(max_event_count)
+            .cloned()
+            .collect();
+        player_events.reverse();
+        player_events
+    }
 }
 
 fn process_root_player_card(
@@ -565,6 +1608,19 @@ fn process_root_player_card(
         ));
     }
 
+    if root_player_card.would_start_gambling_round(&game_logic.gambling_manager) {
+        let player_has_gold = match game_logic.player_manager.get_player_by_uuid(player_uuid) {
+            Some(player) => player.get_gold() > 0,
+            None => false,
+        };
+        if !player_has_gold {
+            return Err((
+                root_player_card,
+                Error::new("Cannot start a round of gambling without any gold"),
+            ));
+        }
+    }
+
     match root_player_card.get_target_style() {
         TargetStyle::SelfPlayer => {
             if targeted_player_uuid_or.is_some() {
@@ -612,6 +1668,20 @@ fn process_root_player_card(
                     ));
                 }
 
+                let target_is_alive = match game_logic
+                    .player_manager
+                    .get_player_by_uuid(targeted_player_uuid)
+                {
+                    Some(targeted_player) => !targeted_player.is_out_of_game(),
+                    None => false,
+                };
+                if !target_is_alive {
+                    return Err((
+                        root_player_card,
+                        Error::new("Cannot direct this card at a player who is out of the game"),
+                    ));
+                }
+
                 match root_player_card.pre_interrupt_play(
                     player_uuid,
                     &mut game_logic.player_manager,
@@ -763,7 +1833,7 @@ impl TurnInfo {
     }
 
     pub fn add_drinks_to_order(&mut self, amount: i32) {
-        self.drinks_to_order += amount;
+        self.drinks_to_order = self.drinks_to_order.saturating_add(amount);
     }
 
     pub fn get_current_player_turn(&self) -> &PlayerUUID {
@@ -781,6 +1851,67 @@ impl TurnInfo {
     }
 }
 
+/// Everything that a player does on their turn, to be applied at once with `GameLogic::apply_turn`.
+#[cfg(test)]
+#[derive(Clone, Default, Debug)]
+pub struct TurnScript {
+    pub discard_card_indices: Vec<usize>,
+    // Played in order, so each index refers to the hand as it is after the previous card was
+    // played. If empty, the player passes on their action.
+    pub cards_to_play: Vec<(usize, Option<PlayerUUID>)>,
+    pub drink_target_uuids: Vec<PlayerUUID>,
+}
+
+/// A single entry in the game log.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameEvent {
+    pub player_uuid: PlayerUUID,
+    pub action: GameEventAction,
+    pub target_player_uuid: Option<PlayerUUID>,
+    pub card_name: Option<String>,
+    // Only the number of discarded cards is logged, since the cards themselves are hidden from other players.
+    pub card_count: Option<usize>,
+    // Positions in the player's hand that the action used, which are needed to replay the game.
+    // Not serialized, since they could give away information about the player's hand.
+    #[serde(skip)]
+    pub card_indices: Vec<usize>,
+}
+
+impl GameEvent {
+    fn involves_player(&self, player_uuid: &PlayerUUID) -> bool {
+        &self.player_uuid == player_uuid || self.target_player_uuid.as_ref() == Some(player_uuid)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub enum GameEventAction {
+    PlayCard,
+    OrderDrink,
+    Pass,
+    DiscardCards,
+    ArrangeHand,
+    Forfeit,
+    SkipTurn,
+}
+
+/// An action that a player is allowed to take. See `GameLogic::legal_actions`.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub enum LegalAction {
+    Pass,
+    #[serde(rename_all = "camelCase")]
+    PlayCard {
+        card_index: usize,
+        target_player_uuid: Option<PlayerUUID>,
+    },
+    DiscardCards,
+    DiscardSingleCard,
+    #[serde(rename_all = "camelCase")]
+    OrderDrink {
+        target_player_uuid: PlayerUUID,
+    },
+}
+
 #[derive(Clone, Copy, PartialEq, Debug, Serialize)]
 pub enum TurnPhase {
     DiscardAndDraw,
@@ -803,113 +1934,577 @@ fn rotate_player_vec_to_start_with_player(
 
 #[cfg(test)]
 mod tests {
-    use super::super::drink::create_simple_ale_test_drink;
+    use super::super::drink::{
+        create_dirty_dishwater_test_drink, create_simple_ale_test_drink, create_strong_test_drink,
+    };
+    use super::super::player::{Stat, TransientEvent, HAND_SIZE, MAX_STAT_SNAPSHOTS};
     use super::super::player_card::{
         change_all_other_player_fortitude_card, change_other_player_fortitude_card,
         gain_fortitude_anytime_card, gambling_cheat_card, gambling_im_in_card,
         i_dont_think_so_card, i_raise_card, ignore_drink_card,
         ignore_root_card_affecting_fortitude, leave_gambling_round_instead_of_anteing_card,
+        oh_i_guess_the_wench_thought_that_was_her_tip_card, redirect_drink_card,
+        remove_other_player_discarded_card, reshuffle_deck_anytime_card, skip_next_turn_card,
+        steal_gold_card, swap_drink_card, swap_fortitude_card,
         wench_bring_some_drinks_for_my_friends_card, winning_hand_card,
     };
     use super::*;
 
     #[test]
-    fn can_handle_simple_gambling_round() {
+    fn all_character_decks_are_valid() {
+        for character in Character::all() {
+            assert_eq!(validate_deck(&character.create_deck()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn too_small_deck_is_rejected() {
+        assert_eq!(
+            validate_deck(&[]),
+            Err(Error::new(
+                "Deck must contain at least 14 cards but only contains 0"
+            ))
+        );
+
+        let mut deck: Vec<PlayerCard> = (0..13).map(|_| gambling_im_in_card().into()).collect();
+        assert_eq!(
+            validate_deck(&deck),
+            Err(Error::new(
+                "Deck must contain at least 14 cards but only contains 13"
+            ))
+        );
+
+        deck.push(gambling_im_in_card().into());
+        assert_eq!(validate_deck(&deck), Ok(()));
+    }
+
+    fn get_hand_card_names(game_logic: &GameLogic, player_uuid: &PlayerUUID) -> Vec<String> {
+        game_logic
+            .get_game_view_player_hand(player_uuid)
+            .into_iter()
+            .map(|card| card.card_name)
+            .collect()
+    }
+
+    #[test]
+    fn can_discard_within_max_discards_per_turn() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new_with_config(
+            vec![
+                (player1_uuid.clone(), Character::Deirdre),
+                (player2_uuid, Character::Gerki),
+            ],
+            GameConfig {
+                max_discards_per_turn: Some(2),
+                ..GameConfig::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            game_logic.discard_cards_and_draw_to_full(&player1_uuid, vec![0, 1]),
+            Ok(())
+        );
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Action);
+        assert_eq!(game_logic.get_game_view_player_hand(&player1_uuid).len(), 7);
+    }
+
+    #[test]
+    fn cannot_discard_beyond_max_discards_per_turn() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new_with_config(
+            vec![
+                (player1_uuid.clone(), Character::Deirdre),
+                (player2_uuid, Character::Gerki),
+            ],
+            GameConfig {
+                max_discards_per_turn: Some(2),
+                ..GameConfig::default()
+            },
+        )
+        .unwrap();
+        let card_names = get_hand_card_names(&game_logic, &player1_uuid);
+
+        assert_eq!(
+            game_logic.discard_cards_and_draw_to_full(&player1_uuid, vec![0, 1, 2]),
+            Err(Error::new("Cannot discard more than 2 cards per turn"))
+        );
+        // Nothing should have been discarded.
+        assert_eq!(get_hand_card_names(&game_logic, &player1_uuid), card_names);
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+    }
+
+    #[test]
+    fn can_discard_single_card_as_action() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
         let mut game_logic = GameLogic::new(vec![
             (player1_uuid.clone(), Character::Deirdre),
-            (player2_uuid.clone(), Character::Gerki),
+            (player2_uuid, Character::Gerki),
         ])
         .unwrap();
-        game_logic
-            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
-            .unwrap();
-
-        // Sanity check.
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .get_gold(),
-            8
+            game_logic.discard_cards_and_draw_to_full(&player1_uuid, Vec::new()),
+            Ok(())
         );
+        let mut card_names = get_hand_card_names(&game_logic, &player1_uuid);
+
+        assert_eq!(game_logic.discard_single_card(&player1_uuid, 2), Ok(()));
+        card_names.remove(2);
+        // The card is not replaced.
+        assert_eq!(get_hand_card_names(&game_logic, &player1_uuid), card_names);
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_gold(),
-            8
+            game_logic.get_game_log().last().unwrap().action,
+            GameEventAction::DiscardCards
         );
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+    }
 
-        // Player 1 starts gambling round.
-        assert!(game_logic
-            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
-            .is_ok());
+    #[test]
+    fn cannot_discard_single_card_outside_of_action_phase() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
 
-        // Player 2 chooses not to play an interrupt card.
-        assert!(game_logic
-            .interrupt_manager
-            .is_turn_to_interrupt(&player2_uuid));
-        assert!(!game_logic.player_can_pass(&player1_uuid));
-        assert!(game_logic.player_can_pass(&player2_uuid));
-        game_logic.pass(&player2_uuid).unwrap();
-        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        let card_names = get_hand_card_names(&game_logic, &player1_uuid);
 
-        // 1 gold should be subtracted from each player.
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .get_gold(),
-            7
+            game_logic.discard_single_card(&player1_uuid, 0),
+            Err(Error::new("Cannot discard a card at this time"))
         );
+        assert_eq!(get_hand_card_names(&game_logic, &player1_uuid), card_names);
+
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_gold(),
-            7
+            game_logic.discard_cards_and_draw_to_full(&player1_uuid, Vec::new()),
+            Ok(())
         );
-        assert!(game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+        // Only the current player can discard.
+        assert_eq!(
+            game_logic.discard_single_card(&player2_uuid, 0),
+            Err(Error::new("Cannot discard a card at this time"))
+        );
+        assert_eq!(
+            game_logic.discard_single_card(&player1_uuid, 7),
+            Err(Error::new("Card does not exist"))
+        );
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Action);
+    }
 
-        // Player 2 does not take control of the gambling round, making player 1 the winner.
-        assert!(game_logic.gambling_manager.is_turn(&player2_uuid));
-        assert!(!game_logic.player_can_pass(&player1_uuid));
-        assert!(game_logic.player_can_pass(&player2_uuid));
-        game_logic.pass(&player2_uuid).unwrap();
+    #[test]
+    fn can_arrange_hand() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid, Character::Gerki),
+        ])
+        .unwrap();
+
+        let card_names = get_hand_card_names(&game_logic, &player1_uuid);
+        assert_eq!(card_names.len(), 7);
 
-        // Gambling pot should be given to the winner.
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .get_gold(),
-            9
+            game_logic.arrange_hand(&player1_uuid, vec![6, 5, 4, 3, 2, 1, 0]),
+            Ok(())
+        );
+        let mut reversed_card_names = card_names.clone();
+        reversed_card_names.reverse();
+        assert_eq!(
+            get_hand_card_names(&game_logic, &player1_uuid),
+            reversed_card_names
+        );
+
+        // Missing index.
+        assert_eq!(
+            game_logic.arrange_hand(&player1_uuid, vec![0, 1, 2, 3, 4, 5]),
+            Err(Error::new(
+                "Hand arrangement must contain each card index exactly once"
+            ))
+        );
+        // Duplicate index.
+        assert_eq!(
+            game_logic.arrange_hand(&player1_uuid, vec![0, 1, 2, 3, 4, 5, 5]),
+            Err(Error::new(
+                "Hand arrangement must contain each card index exactly once"
+            ))
+        );
+        // Out of bounds index.
+        assert_eq!(
+            game_logic.arrange_hand(&player1_uuid, vec![0, 1, 2, 3, 4, 5, 7]),
+            Err(Error::new(
+                "Hand arrangement must contain each card index exactly once"
+            ))
+        );
+
+        // Rejected arrangements should leave the hand untouched.
+        assert_eq!(
+            get_hand_card_names(&game_logic, &player1_uuid),
+            reversed_card_names
+        );
+    }
+
+    #[test]
+    fn play_card_uses_arranged_hand_order() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid, Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Replace player 1's hand with known cards.
+        let player = game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap();
+        while player.pop_card_from_hand(0).is_some() {}
+        player.return_card_to_hand(gambling_im_in_card().into(), 0);
+        player.return_card_to_hand(i_dont_think_so_card().into(), 1);
+
+        game_logic.arrange_hand(&player1_uuid, vec![1, 0]).unwrap();
+        assert_eq!(
+            get_hand_card_names(&game_logic, &player1_uuid),
+            vec!["I don't think so!", "Gambling? I'm in!"]
         );
+
+        // Index 1 now refers to the gambling card.
+        assert_eq!(game_logic.play_card(&player1_uuid, &None, 1), Ok(()));
+        assert!(game_logic.gambling_manager.round_in_progress());
         assert_eq!(
+            get_hand_card_names(&game_logic, &player1_uuid),
+            vec!["I don't think so!"]
+        );
+    }
+
+    #[test]
+    fn lazy_starting_hands_match_eager_starting_hands() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let players_with_characters = vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ];
+
+        let eager_game_logic =
+            GameLogic::new_with_seed(players_with_characters.clone(), GameConfig::default(), 7)
+                .unwrap();
+        let mut lazy_game_logic = GameLogic::new_with_seed(
+            players_with_characters,
+            GameConfig {
+                lazy_starting_hands: true,
+                ..GameConfig::default()
+            },
+            7,
+        )
+        .unwrap();
+        let has_starting_hand = |game_logic: &GameLogic, player_uuid: &PlayerUUID| {
             game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
+                .get_player_by_uuid(player_uuid)
                 .unwrap()
-                .get_gold(),
-            7
+                .has_starting_hand()
+        };
+        let first_player_uuid = lazy_game_logic
+            .get_turn_info()
+            .get_current_player_turn()
+            .clone();
+        let other_player_uuid = if first_player_uuid == player1_uuid {
+            player2_uuid.clone()
+        } else {
+            player1_uuid.clone()
+        };
+
+        // Undrawn hands already show the cards that they will be drawn from.
+        for player_uuid in [&player1_uuid, &player2_uuid] {
+            assert!(!has_starting_hand(&lazy_game_logic, player_uuid));
+            assert_eq!(
+                get_hand_card_names(&lazy_game_logic, player_uuid),
+                get_hand_card_names(&eager_game_logic, player_uuid)
+            );
+            assert_eq!(
+                lazy_game_logic.get_cards_until_reshuffle_or(player_uuid),
+                eager_game_logic.get_cards_until_reshuffle_or(player_uuid)
+            );
+        }
+
+        // Only the player who acts has their hand drawn.
+        lazy_game_logic
+            .perform_idempotent_action(&first_player_uuid, None, |game_logic| {
+                game_logic.discard_cards_and_draw_to_full(&first_player_uuid, Vec::new())
+            })
+            .unwrap();
+        assert!(has_starting_hand(&lazy_game_logic, &first_player_uuid));
+        assert!(!has_starting_hand(&lazy_game_logic, &other_player_uuid));
+        assert_eq!(
+            get_hand_card_names(&lazy_game_logic, &first_player_uuid),
+            get_hand_card_names(&eager_game_logic, &first_player_uuid)
+        );
+
+        // Hands that have already been drawn aren't topped up again.
+        lazy_game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&first_player_uuid)
+            .unwrap()
+            .pop_card_from_hand(0);
+        lazy_game_logic.draw_starting_hand_if_needed(&first_player_uuid);
+        assert_eq!(
+            get_hand_card_names(&lazy_game_logic, &first_player_uuid).len(),
+            HAND_SIZE - 1
+        );
+    }
+
+    #[test]
+    fn card_playability_explains_why_gambling_card_cannot_be_played() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid, Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        let player = game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap();
+        player.return_card_to_hand(i_raise_card().into(), 0);
+        player.return_card_to_hand(change_other_player_fortitude_card("Punch", -2).into(), 1);
+
+        // No round of gambling has been started.
+        assert_eq!(
+            game_logic.get_card_playability(&player1_uuid, 0),
+            Ok(CardPlayability {
+                playable: false,
+                reason: Some("Can only be played during a round of gambling".to_string()),
+            })
+        );
+        assert_eq!(
+            game_logic.get_card_playability(&player1_uuid, 1),
+            Ok(CardPlayability {
+                playable: true,
+                reason: None,
+            })
+        );
+        assert_eq!(
+            game_logic.get_card_playability(&player1_uuid, 100),
+            Err(Error::new("Card does not exist"))
+        );
+    }
+
+    #[test]
+    fn card_playability_explains_action_card_out_of_turn() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player2_uuid)
+            .unwrap()
+            .return_card_to_hand(change_other_player_fortitude_card("Punch", -2).into(), 0);
+
+        assert_eq!(
+            game_logic.get_card_playability(&player2_uuid, 0),
+            Ok(CardPlayability {
+                playable: false,
+                reason: Some("It is not your turn".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn player_without_gold_cannot_start_gambling_round() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        // Three players, so that the game keeps running once player 1 runs out of gold.
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid, Character::Gerki),
+            (player3_uuid, Character::Fiona),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        let player = game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap();
+        while player.pop_card_from_hand(0).is_some() {}
+        player.return_card_to_hand(gambling_im_in_card().into(), 0);
+        player.change_gold(-player.get_gold(), "Test");
+        assert_eq!(player.get_gold(), 0);
+
+        assert_eq!(
+            game_logic.play_card(&player1_uuid, &None, 0),
+            Err(Error::new(
+                "Cannot start a round of gambling without any gold"
+            ))
         );
         assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::OrderDrinks);
+        assert_eq!(
+            get_hand_card_names(&game_logic, &player1_uuid),
+            vec!["Gambling? I'm in!"]
+        );
+        assert!(get_legal_card_indices(&game_logic, &player1_uuid).is_empty());
+        assert!(!game_logic.get_game_view_player_hand(&player1_uuid)[0].is_playable);
+    }
+
+    fn get_playable_card_indices(game_logic: &GameLogic, player_uuid: &PlayerUUID) -> Vec<usize> {
+        game_logic
+            .player_manager
+            .get_player_by_uuid(player_uuid)
+            .unwrap()
+            .get_hand()
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| {
+                card.can_play(
+                    player_uuid,
+                    &game_logic.gambling_manager,
+                    &game_logic.interrupt_manager,
+                    &game_logic.turn_info,
+                )
+            })
+            .map(|(card_index, _)| card_index)
+            .collect()
+    }
+
+    fn get_legal_card_indices(game_logic: &GameLogic, player_uuid: &PlayerUUID) -> Vec<usize> {
+        let mut card_indices: Vec<usize> = game_logic
+            .legal_actions(player_uuid)
+            .into_iter()
+            .filter_map(|legal_action| match legal_action {
+                LegalAction::PlayCard { card_index, .. } => Some(card_index),
+                _ => None,
+            })
+            .collect();
+        card_indices.dedup();
+        card_indices
     }
 
     #[test]
-    fn raise_in_gambling_round() {
+    fn legal_actions_match_playable_cards_during_gambling_round() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+
+        // Any cards in player 1's starting hand that can be played at any time are also legal.
+        assert!(game_logic
+            .legal_actions(&player1_uuid)
+            .contains(&LegalAction::DiscardCards));
+        assert_eq!(
+            get_legal_card_indices(&game_logic, &player1_uuid),
+            get_playable_card_indices(&game_logic, &player1_uuid)
+        );
+        assert!(game_logic.legal_actions(&player2_uuid).is_empty());
+
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        assert!(game_logic
+            .legal_actions(&player1_uuid)
+            .contains(&LegalAction::DiscardSingleCard));
+
+        // Player 1 starts gambling round.
+        assert!(game_logic
+            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
+            .is_ok());
+        for player_uuid in [&player1_uuid, &player2_uuid] {
+            assert_eq!(
+                get_legal_card_indices(&game_logic, player_uuid),
+                get_playable_card_indices(&game_logic, player_uuid)
+            );
+        }
+        assert!(!game_logic
+            .legal_actions(&player1_uuid)
+            .contains(&LegalAction::Pass));
+        assert!(game_logic
+            .legal_actions(&player2_uuid)
+            .contains(&LegalAction::Pass));
+
+        // Player 2 doesn't interrupt, so the gambling round begins.
+        game_logic.pass(&player2_uuid).unwrap();
+        assert!(game_logic.gambling_manager.round_in_progress());
+        for player_uuid in [&player1_uuid, &player2_uuid] {
+            assert_eq!(
+                get_legal_card_indices(&game_logic, player_uuid),
+                get_playable_card_indices(&game_logic, player_uuid)
+            );
+            assert_eq!(
+                game_logic
+                    .legal_actions(player_uuid)
+                    .contains(&LegalAction::Pass),
+                game_logic.can_pass(player_uuid)
+            );
+            // Cards can't be discarded in place of an action during a gambling round.
+            assert!(!game_logic
+                .legal_actions(player_uuid)
+                .contains(&LegalAction::DiscardSingleCard));
+        }
+    }
+
+    #[test]
+    fn legal_actions_list_each_drink_target() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid.clone(), Character::Fiona),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        game_logic.pass(&player1_uuid).unwrap();
+
+        let legal_actions = game_logic.legal_actions(&player1_uuid);
+        assert!(legal_actions.contains(&LegalAction::OrderDrink {
+            target_player_uuid: player2_uuid
+        }));
+        assert!(legal_actions.contains(&LegalAction::OrderDrink {
+            target_player_uuid: player3_uuid
+        }));
+        assert!(!legal_actions.contains(&LegalAction::OrderDrink {
+            target_player_uuid: player1_uuid
+        }));
+    }
+
+    #[test]
+    fn can_handle_simple_gambling_round() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
@@ -951,8 +2546,8 @@ mod tests {
         assert!(game_logic
             .interrupt_manager
             .is_turn_to_interrupt(&player2_uuid));
-        assert!(!game_logic.player_can_pass(&player1_uuid));
-        assert!(game_logic.player_can_pass(&player2_uuid));
+        assert!(!game_logic.can_pass(&player1_uuid));
+        assert!(game_logic.can_pass(&player2_uuid));
         game_logic.pass(&player2_uuid).unwrap();
         assert!(!game_logic.interrupt_manager.interrupt_in_progress());
 
@@ -976,46 +2571,11 @@ mod tests {
         assert!(game_logic.gambling_manager.round_in_progress());
         assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
 
-        // Player 2 raises.
+        // Player 2 does not take control of the gambling round, making player 1 the winner.
         assert!(game_logic.gambling_manager.is_turn(&player2_uuid));
-        assert!(!game_logic.player_can_pass(&player1_uuid));
-        assert!(game_logic.player_can_pass(&player2_uuid));
-        assert!(game_logic
-            .process_card(i_raise_card().into(), &player2_uuid, &None)
-            .is_ok());
-
-        // Player 2 chooses not to interrupt their ante.
-        assert!(!game_logic.player_can_pass(&player1_uuid));
-        assert!(game_logic.player_can_pass(&player2_uuid));
+        assert!(!game_logic.can_pass(&player1_uuid));
+        assert!(game_logic.can_pass(&player2_uuid));
         game_logic.pass(&player2_uuid).unwrap();
-        // Player 1 chooses not to interrupt their ante.
-        assert!(game_logic.player_can_pass(&player1_uuid));
-        assert!(!game_logic.player_can_pass(&player2_uuid));
-        game_logic.pass(&player1_uuid).unwrap();
-
-        // 1 more gold should be subtracted from each player.
-        assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .get_gold(),
-            6
-        );
-        assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_gold(),
-            6
-        );
-
-        // Player 1 does not take control of the gambling round, making player 2 the winner.
-        assert!(game_logic.gambling_manager.is_turn(&player1_uuid));
-        assert!(game_logic.player_can_pass(&player1_uuid));
-        assert!(!game_logic.player_can_pass(&player2_uuid));
-        game_logic.pass(&player1_uuid).unwrap();
 
         // Gambling pot should be given to the winner.
         assert_eq!(
@@ -1024,7 +2584,7 @@ mod tests {
                 .get_player_by_uuid(&player1_uuid)
                 .unwrap()
                 .get_gold(),
-            6
+            9
         );
         assert_eq!(
             game_logic
@@ -1032,95 +2592,128 @@ mod tests {
                 .get_player_by_uuid(&player2_uuid)
                 .unwrap()
                 .get_gold(),
-            10
+            7
         );
         assert!(!game_logic.gambling_manager.round_in_progress());
         assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::OrderDrinks);
     }
 
     #[test]
-    fn leave_during_initial_ante_in_gambling_round() {
+    fn reshuffle_card_empties_discard_pile() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
         let mut game_logic = GameLogic::new(vec![
             (player1_uuid.clone(), Character::Deirdre),
-            (player2_uuid.clone(), Character::Gerki),
+            (player2_uuid, Character::Gerki),
         ])
         .unwrap();
         game_logic
-            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .discard_cards_and_draw_to_full(&player1_uuid, vec![0, 1])
             .unwrap();
+        let player_data = game_logic
+            .get_game_view_player_data_or(&player1_uuid)
+            .unwrap();
+        assert_eq!(player_data.discard_pile_size, 2);
+        let draw_pile_size = player_data.draw_pile_size;
 
-        // Sanity check.
-        assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .get_gold(),
-            8
-        );
-        assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_gold(),
-            8
-        );
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+        game_logic
+            .process_card(
+                reshuffle_deck_anytime_card("Reshuffle").into(),
+                &player1_uuid,
+                &None,
+            )
+            .unwrap();
+        let player_data = game_logic
+            .get_game_view_player_data_or(&player1_uuid)
+            .unwrap();
+        assert_eq!(player_data.discard_pile_size, 0);
+        assert_eq!(player_data.draw_pile_size, draw_pile_size + 2);
+    }
 
-        // Player 1 starts gambling round.
-        assert!(game_logic
-            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
-            .is_ok());
+    #[test]
+    fn removed_card_leaves_discard_pile_for_good() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
 
-        // Player 2 tries to leave the gambling round.
-        assert!(game_logic
-            .interrupt_manager
-            .is_turn_to_interrupt(&player2_uuid));
-        assert!(game_logic
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player2_uuid)
+            .unwrap()
+            .discard_card(gambling_im_in_card().into());
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        game_logic
             .process_card(
-                leave_gambling_round_instead_of_anteing_card("Leave gambling round").into(),
-                &player2_uuid,
-                &None
+                remove_other_player_discarded_card("Remove").into(),
+                &player1_uuid,
+                &Some(player2_uuid.clone()),
             )
-            .is_ok());
-        assert!(game_logic.gambling_manager.round_in_progress());
-        assert!(game_logic
-            .process_card(i_dont_think_so_card().into(), &player1_uuid, &None)
-            .is_ok());
-        assert!(game_logic
-            .process_card(i_dont_think_so_card().into(), &player2_uuid, &None)
-            .is_ok());
-        // Player 1 gives up and lets player 2 leave the gambling round.
-        assert!(game_logic.pass(&player1_uuid).is_ok());
+            .unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
 
-        // Since player 1 is the only player left in the gambling round, the round ends and player 1's OrderDrinks turn phase starts.
-        assert_eq!(
+        let player2_data = game_logic
+            .get_game_view_player_data_or(&player2_uuid)
+            .unwrap();
+        assert_eq!(player2_data.discard_pile_size, 0);
+        assert_eq!(player2_data.removed_pile_size, 1);
+    }
+
+    #[test]
+    fn steal_gold_card_only_takes_gold_the_target_has() {
+        // Pairs of the target's gold before the theft and the amount that should be stolen.
+        for (target_gold, stolen_gold) in [(10, 2), (1, 1)] {
+            let player1_uuid = PlayerUUID::new();
+            let player2_uuid = PlayerUUID::new();
+            let player3_uuid = PlayerUUID::new();
+
+            let mut game_logic = GameLogic::new(vec![
+                (player1_uuid.clone(), Character::Deirdre),
+                (player2_uuid.clone(), Character::Gerki),
+                (player3_uuid.clone(), Character::Fiona),
+            ])
+            .unwrap();
             game_logic
                 .player_manager
-                .get_player_by_uuid(&player1_uuid)
+                .get_player_by_uuid_mut(&player2_uuid)
                 .unwrap()
-                .get_gold(),
-            9
-        );
-        assert_eq!(
+                .change_gold(target_gold - 10, "Test setup");
             game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_gold(),
-            7
-        );
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::OrderDrinks);
+                .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+                .unwrap();
+
+            game_logic
+                .process_card(
+                    steal_gold_card("Steal", 2).into(),
+                    &player1_uuid,
+                    &Some(player2_uuid.clone()),
+                )
+                .unwrap();
+            game_logic.pass(&player2_uuid).unwrap();
+            assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+
+            let get_gold = |player_uuid: &PlayerUUID| {
+                game_logic
+                    .player_manager
+                    .get_player_by_uuid(player_uuid)
+                    .unwrap()
+                    .get_gold()
+            };
+            assert_eq!(get_gold(&player1_uuid), 10 + stolen_gold);
+            assert_eq!(get_gold(&player2_uuid), target_gold - stolen_gold);
+            assert_eq!(get_gold(&player3_uuid), 10);
+        }
     }
 
     #[test]
-    fn try_to_leave_during_initial_ante_in_gambling_round() {
+    fn skip_next_turn_card_skips_target_exactly_once() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
         let player3_uuid = PlayerUUID::new();
@@ -1135,234 +2728,359 @@ mod tests {
             .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
             .unwrap();
 
-        // Sanity check.
-        assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .get_gold(),
-            10
-        );
-        assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_gold(),
-            10
-        );
-        assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player3_uuid)
-                .unwrap()
-                .get_gold(),
-            10
-        );
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
-
-        // Player 1 starts gambling round.
-        assert!(game_logic
-            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
-            .is_ok());
-
-        // Player 2 tries to leave the gambling round.
-        assert!(game_logic
-            .interrupt_manager
-            .is_turn_to_interrupt(&player2_uuid));
-        assert!(game_logic
+        game_logic
             .process_card(
-                leave_gambling_round_instead_of_anteing_card("Leave gambling round").into(),
-                &player2_uuid,
-                &None
+                skip_next_turn_card("Nap").into(),
+                &player1_uuid,
+                &Some(player2_uuid.clone()),
             )
-            .is_ok());
-        assert!(game_logic.gambling_manager.round_in_progress());
-        assert!(game_logic.pass(&player3_uuid).is_ok());
-        assert!(game_logic
-            .process_card(i_dont_think_so_card().into(), &player1_uuid, &None)
-            .is_ok());
-        // Player 2 fails to leave the gambling round.
-        assert!(game_logic.pass(&player2_uuid).is_ok());
-        // Player 3 doesn't attempt to leave the gambling round, and antes up.
-        assert!(game_logic.pass(&player3_uuid).is_ok());
+            .unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
         assert!(!game_logic.interrupt_manager.interrupt_in_progress());
 
-        // 1 gold should be subtracted from each player.
-        assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .get_gold(),
-            9
-        );
-        assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_gold(),
-            9
-        );
-        assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player3_uuid)
-                .unwrap()
-                .get_gold(),
-            9
-        );
-        assert!(game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
-
-        // Player 2 does not take control of the gambling round.
-        assert!(game_logic.gambling_manager.is_turn(&player2_uuid));
-        assert!(!game_logic.player_can_pass(&player1_uuid));
-        assert!(game_logic.player_can_pass(&player2_uuid));
-        game_logic.pass(&player2_uuid).unwrap();
-        // Player 3 does not take control of the gambling round, making player 1 the winner.
-        assert!(game_logic.gambling_manager.is_turn(&player3_uuid));
-        assert!(!game_logic.player_can_pass(&player1_uuid));
-        assert!(game_logic.player_can_pass(&player3_uuid));
-        game_logic.pass(&player3_uuid).unwrap();
+        let end_turn = |game_logic: &mut GameLogic, player_uuid: &PlayerUUID| {
+            if game_logic.get_turn_phase() == TurnPhase::DiscardAndDraw {
+                game_logic
+                    .discard_cards_and_draw_to_full(player_uuid, Vec::new())
+                    .unwrap();
+            }
+            game_logic.skip_turn(player_uuid).unwrap();
+        };
 
-        // Gambling pot should be given to the winner.
+        // Player 2's turn is passed over once, and only once.
+        end_turn(&mut game_logic, &player1_uuid);
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .get_gold(),
-            12
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player3_uuid
         );
+        end_turn(&mut game_logic, &player3_uuid);
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_gold(),
-            9
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player1_uuid
         );
+        end_turn(&mut game_logic, &player1_uuid);
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player3_uuid)
-                .unwrap()
-                .get_gold(),
-            9
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player2_uuid
         );
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::OrderDrinks);
     }
 
     #[test]
-    fn cheat_in_gambling_round() {
+    fn cannot_play_interrupt_card_without_interrupt() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
         let mut game_logic = GameLogic::new(vec![
             (player1_uuid.clone(), Character::Deirdre),
-            (player2_uuid.clone(), Character::Gerki),
+            (player2_uuid, Character::Gerki),
         ])
         .unwrap();
         game_logic
             .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
             .unwrap();
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
 
-        // Sanity check.
         assert_eq!(
             game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .get_gold(),
-            8
+                .process_card(i_dont_think_so_card().into(), &player1_uuid, &None)
+                .unwrap_err()
+                .1,
+            Error::new("There is nothing to interrupt right now")
         );
-        assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_gold(),
-            8
+    }
+
+    #[test]
+    fn card_that_fails_to_play_returns_to_its_place_in_hand() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid, Character::Gerki),
+        ])
+        .unwrap();
+        let hand_card_names = get_hand_card_names(&game_logic, &player1_uuid);
+        let unplayable_card_indices: Vec<usize> = game_logic
+            .get_game_view_player_hand(&player1_uuid)
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| !card.is_playable)
+            .map(|(card_index, _)| card_index)
+            .collect();
+        assert!(!unplayable_card_indices.is_empty());
+
+        for card_index in unplayable_card_indices {
+            assert!(game_logic
+                .play_card(&player1_uuid, &None, card_index)
+                .is_err());
+            assert_eq!(
+                get_hand_card_names(&game_logic, &player1_uuid),
+                hand_card_names
+            );
+        }
+    }
+
+    #[test]
+    fn can_pass_matches_result_of_passing() {
+        let player_uuids = vec![PlayerUUID::new(), PlayerUUID::new(), PlayerUUID::new()];
+
+        let mut game_logic = GameLogic::new(vec![
+            (player_uuids[0].clone(), Character::Deirdre),
+            (player_uuids[1].clone(), Character::Gerki),
+            (player_uuids[2].clone(), Character::Fiona),
+        ])
+        .unwrap();
+
+        // Walk through a game, alternating between passing and taking other actions so that
+        // gambling rounds, interrupts and drink phases all come up along the way.
+        for step in 0..200 {
+            if !game_logic.is_running() {
+                break;
+            }
+
+            for player_uuid in &player_uuids {
+                assert_eq!(
+                    game_logic.can_pass(player_uuid),
+                    game_logic.clone().pass(player_uuid).is_ok()
+                );
+            }
+
+            let prefer_pass = step % 2 == 0;
+            let next_action_or = player_uuids.iter().find_map(|player_uuid| {
+                let legal_actions = game_logic.legal_actions(player_uuid);
+                let preferred_action_or = legal_actions
+                    .iter()
+                    .find(|action| (action == &&LegalAction::Pass) == prefer_pass)
+                    .cloned();
+                preferred_action_or
+                    .or_else(|| legal_actions.into_iter().next())
+                    .map(|action| (player_uuid.clone(), action))
+            });
+            // TODO - A player who is knocked out while it's their turn to respond to an interrupt
+            // or to act in a gambling round currently leaves nobody with a legal action. Stop
+            // walking through the game if that happens.
+            let (player_uuid, action) = match next_action_or {
+                Some(next_action) => next_action,
+                None => break,
+            };
+
+            match action {
+                LegalAction::Pass => game_logic.pass(&player_uuid),
+                LegalAction::PlayCard {
+                    card_index,
+                    target_player_uuid,
+                } => game_logic.play_card(&player_uuid, &target_player_uuid, card_index),
+                LegalAction::DiscardCards => {
+                    game_logic.discard_cards_and_draw_to_full(&player_uuid, Vec::new())
+                }
+                LegalAction::DiscardSingleCard => game_logic.discard_single_card(&player_uuid, 0),
+                LegalAction::OrderDrink { target_player_uuid } => {
+                    game_logic.order_drink(&player_uuid, &target_player_uuid)
+                }
+            }
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn cannot_pass_reason_explains_why_player_cannot_pass() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        assert_eq!(
+            game_logic.get_cannot_pass_reason_or(&player1_uuid),
+            Some("Cannot pass during the DiscardAndDraw phase".to_string())
         );
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
 
-        // Player 1 starts gambling round.
-        assert!(game_logic
+        // Player 1 can pass their own action phase.
+        assert_eq!(game_logic.get_cannot_pass_reason_or(&player1_uuid), None);
+        assert_eq!(
+            game_logic.get_cannot_pass_reason_or(&player2_uuid),
+            Some("It is not your turn".to_string())
+        );
+
+        // Player 1 starts a gambling round, so player 2 must respond to the ante.
+        game_logic
             .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
-            .is_ok());
+            .unwrap();
+        assert_eq!(
+            game_logic.get_cannot_pass_reason_or(&player1_uuid),
+            Some("Waiting for another player to respond to an interrupt".to_string())
+        );
+        assert_eq!(game_logic.get_cannot_pass_reason_or(&player2_uuid), None);
 
-        // Player 2 chooses not to play an interrupt card.
-        assert!(game_logic
-            .interrupt_manager
-            .is_turn_to_interrupt(&player2_uuid));
-        assert!(!game_logic.player_can_pass(&player1_uuid));
-        assert!(game_logic.player_can_pass(&player2_uuid));
+        // Once player 2 antes, it's their turn in the gambling round.
         game_logic.pass(&player2_uuid).unwrap();
-        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+        assert_eq!(
+            game_logic.get_cannot_pass_reason_or(&player1_uuid),
+            Some("Waiting for another player to act in the gambling round".to_string())
+        );
+        assert_eq!(game_logic.get_cannot_pass_reason_or(&player2_uuid), None);
+    }
 
-        // 1 gold should be subtracted from each player.
+    #[test]
+    fn first_player_seed_picks_starting_player_reproducibly() {
+        let players_with_characters = vec![
+            (PlayerUUID::new(), Character::Deirdre),
+            (PlayerUUID::new(), Character::Gerki),
+            (PlayerUUID::new(), Character::Fiona),
+            (PlayerUUID::new(), Character::Zot),
+        ];
+        let get_first_player_uuid = |first_player_seed: u64| {
+            GameLogic::new_with_config(
+                players_with_characters.clone(),
+                GameConfig {
+                    first_player_seed: Some(first_player_seed),
+                    ..GameConfig::default()
+                },
+            )
+            .unwrap()
+            .get_turn_info()
+            .get_current_player_turn()
+            .clone()
+        };
+
+        for first_player_seed in 0..20 {
+            assert_eq!(
+                get_first_player_uuid(first_player_seed),
+                get_first_player_uuid(first_player_seed)
+            );
+        }
+
+        let first_player_uuids: HashSet<PlayerUUID> = (0..20).map(get_first_player_uuid).collect();
+        assert!(first_player_uuids.len() > 1);
+    }
+
+    #[test]
+    fn winning_gambling_pot_past_gold_threshold_ends_game() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new_with_config(
+            vec![
+                (player1_uuid.clone(), Character::Deirdre),
+                (player2_uuid.clone(), Character::Gerki),
+            ],
+            GameConfig {
+                gold_to_win: Some(9),
+                ..GameConfig::default()
+            },
+        )
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Player 1 starts a gambling round and each player antes 1 gold.
+        game_logic
+            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
+            .unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
+        assert!(game_logic.is_running());
+
+        // Player 2 doesn't take control, so player 1 wins the pot and reaches 9 gold.
+        game_logic.pass(&player2_uuid).unwrap();
         assert_eq!(
             game_logic
                 .player_manager
                 .get_player_by_uuid(&player1_uuid)
                 .unwrap()
                 .get_gold(),
-            7
+            9
         );
+        assert!(!game_logic.is_running());
+        assert_eq!(game_logic.get_winner_or(), Some(player1_uuid.clone()));
         assert_eq!(
+            game_logic.player_manager.get_standings(),
+            vec![vec![player1_uuid], vec![player2_uuid]]
+        );
+    }
+
+    #[test]
+    fn huge_pot_and_drink_orders_saturate_instead_of_overflowing() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        for player_uuid in [&player1_uuid, &player2_uuid] {
             game_logic
                 .player_manager
-                .get_player_by_uuid(&player2_uuid)
+                .get_player_by_uuid_mut(player_uuid)
                 .unwrap()
-                .get_gold(),
-            7
-        );
-        assert!(game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+                .change_gold(i32::MAX, "Test setup");
+        }
 
-        // Player 2 plays a winning hand card.
-        assert!(game_logic
-            .process_card(winning_hand_card().into(), &player2_uuid, &None)
-            .is_ok());
+        game_logic
+            .gambling_manager
+            .start_round(player1_uuid.clone(), &mut game_logic.player_manager);
+        for player_uuid in [&player1_uuid, &player2_uuid] {
+            game_logic.gambling_manager.ante_up_amount(
+                player_uuid,
+                &mut game_logic.player_manager,
+                i32::MAX,
+            );
+        }
+        assert_eq!(game_logic.gambling_manager.get_pot_amount(), i32::MAX);
 
-        // Player 1 attempts to play a regular gambling card.
-        assert_eq!(
-            game_logic
-                .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
-                .unwrap_err()
-                .1,
-            Error::new("Card cannot be played at this time")
+        // Anteing a negative amount can't take gold out of the pot.
+        game_logic.gambling_manager.ante_up_amount(
+            &player1_uuid,
+            &mut game_logic.player_manager,
+            i32::MIN,
         );
+        assert_eq!(game_logic.gambling_manager.get_pot_amount(), i32::MAX);
 
-        // Player 1 plays a cheating card.
-        assert!(game_logic
-            .process_card(
-                gambling_cheat_card("Card up the sleeve").into(),
-                &player1_uuid,
-                &None
-            )
-            .is_ok());
+        game_logic.turn_info.add_drinks_to_order(i32::MAX);
+        game_logic.turn_info.add_drinks_to_order(i32::MAX);
+        assert_eq!(game_logic.turn_info.drinks_to_order, i32::MAX);
+    }
 
-        // Player 2 does not take control of the gambling round, making player 1 the winner.
-        assert!(game_logic.gambling_manager.is_turn(&player2_uuid));
-        assert!(!game_logic.player_can_pass(&player1_uuid));
-        assert!(game_logic.player_can_pass(&player2_uuid));
+    #[test]
+    fn gambling_ante_is_taken_from_config() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new_with_config(
+            vec![
+                (player1_uuid.clone(), Character::Deirdre),
+                (player2_uuid.clone(), Character::Gerki),
+            ],
+            GameConfig {
+                gambling_ante: 2,
+                ..GameConfig::default()
+            },
+        )
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Player 1 starts gambling round and player 2 antes.
+        game_logic
+            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
+            .unwrap();
         game_logic.pass(&player2_uuid).unwrap();
 
-        // Gambling pot should be given to the winner.
+        // 2 gold should be subtracted from each player.
         assert_eq!(
             game_logic
                 .player_manager
                 .get_player_by_uuid(&player1_uuid)
                 .unwrap()
                 .get_gold(),
-            9
+            6
         );
         assert_eq!(
             game_logic
@@ -1370,14 +3088,71 @@ mod tests {
                 .get_player_by_uuid(&player2_uuid)
                 .unwrap()
                 .get_gold(),
-            7
+            6
+        );
+        assert_eq!(game_logic.gambling_manager.get_pot_amount(), 4);
+
+        // Player 2 does not take control of the gambling round, so player 1 wins the whole pot.
+        game_logic.pass(&player2_uuid).unwrap();
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            10
         );
         assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::OrderDrinks);
     }
 
     #[test]
-    fn cannot_play_gambling_cards_during_game_interrupts() {
+    fn near_broke_player_only_antes_gold_they_have() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new_with_config(
+            vec![
+                (player1_uuid.clone(), Character::Deirdre),
+                (player2_uuid.clone(), Character::Gerki),
+                (player3_uuid.clone(), Character::Fiona),
+            ],
+            GameConfig {
+                gambling_ante: 3,
+                ..GameConfig::default()
+            },
+        )
+        .unwrap();
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player2_uuid)
+            .unwrap()
+            .change_gold(-8, "Test setup");
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Player 1 starts a gambling round, then the others ante.
+        game_logic
+            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
+            .unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
+        game_logic.pass(&player3_uuid).unwrap();
+
+        // Player 2 only had 2 gold to give.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            0
+        );
+        assert_eq!(game_logic.gambling_manager.get_pot_amount(), 8);
+    }
+
+    #[test]
+    fn raise_in_gambling_round() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
@@ -1410,283 +3185,2550 @@ mod tests {
         assert!(!game_logic.gambling_manager.round_in_progress());
         assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
 
-        // Start gambling round.
+        // Player 1 starts gambling round.
         assert!(game_logic
             .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
             .is_ok());
 
-        // Other player can choose to interrupt their ante (but doesn't yet).
+        // Player 2 chooses not to play an interrupt card.
         assert!(game_logic
             .interrupt_manager
             .is_turn_to_interrupt(&player2_uuid));
-
-        // Neither player can play other gambling cards.
-        assert!(!i_raise_card().can_play(
-            &player1_uuid,
-            &game_logic.gambling_manager,
-            &game_logic.interrupt_manager,
-            &game_logic.turn_info
-        ));
-        assert!(!i_raise_card().can_play(
-            &player2_uuid,
-            &game_logic.gambling_manager,
-            &game_logic.interrupt_manager,
-            &game_logic.turn_info
-        ));
-        assert!(!gambling_im_in_card().can_play(
-            &player1_uuid,
-            &game_logic.gambling_manager,
-            &game_logic.interrupt_manager,
-            &game_logic.turn_info
-        ));
-        assert!(!gambling_im_in_card().can_play(
-            &player2_uuid,
-            &game_logic.gambling_manager,
-            &game_logic.interrupt_manager,
-            &game_logic.turn_info
-        ));
-
-        // Player 2 passes and antes.
+        assert!(!game_logic.can_pass(&player1_uuid));
+        assert!(game_logic.can_pass(&player2_uuid));
         game_logic.pass(&player2_uuid).unwrap();
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
 
-        // Player 2 can now play a gambling card.
-        assert!(!i_raise_card().can_play(
-            &player1_uuid,
-            &game_logic.gambling_manager,
-            &game_logic.interrupt_manager,
-            &game_logic.turn_info
-        ));
-        assert!(i_raise_card().can_play(
-            &player2_uuid,
-            &game_logic.gambling_manager,
+        // 1 gold should be subtracted from each player.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            7
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            7
+        );
+        assert!(game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 2 raises.
+        assert!(game_logic.gambling_manager.is_turn(&player2_uuid));
+        assert!(!game_logic.can_pass(&player1_uuid));
+        assert!(game_logic.can_pass(&player2_uuid));
+        assert!(game_logic
+            .process_card(i_raise_card().into(), &player2_uuid, &None)
+            .is_ok());
+
+        // Player 2 chooses not to interrupt their ante.
+        assert!(!game_logic.can_pass(&player1_uuid));
+        assert!(game_logic.can_pass(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+        // Player 1 chooses not to interrupt their ante.
+        assert!(game_logic.can_pass(&player1_uuid));
+        assert!(!game_logic.can_pass(&player2_uuid));
+        game_logic.pass(&player1_uuid).unwrap();
+
+        // 1 more gold should be subtracted from each player.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            6
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            6
+        );
+
+        // Player 1 does not take control of the gambling round, making player 2 the winner.
+        assert!(game_logic.gambling_manager.is_turn(&player1_uuid));
+        assert!(game_logic.can_pass(&player1_uuid));
+        assert!(!game_logic.can_pass(&player2_uuid));
+        game_logic.pass(&player1_uuid).unwrap();
+
+        // Gambling pot should be given to the winner.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            6
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            10
+        );
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::OrderDrinks);
+    }
+
+    #[test]
+    fn gambling_view_reports_raiser_as_controller() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        assert!(game_logic.get_game_view_gambling_data_or().is_none());
+
+        // Player 1 starts gambling round and player 2 doesn't interrupt.
+        game_logic
+            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
+            .unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
+        assert_eq!(
+            game_logic
+                .get_game_view_gambling_data_or()
+                .unwrap()
+                .winning_player_uuid,
+            player1_uuid
+        );
+
+        // Player 2 raises, and neither player interrupts their ante.
+        game_logic
+            .process_card(i_raise_card().into(), &player2_uuid, &None)
+            .unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
+        game_logic.pass(&player1_uuid).unwrap();
+
+        let gambling_data = game_logic.get_game_view_gambling_data_or().unwrap();
+        assert_eq!(gambling_data.winning_player_uuid, player2_uuid);
+        assert_eq!(gambling_data.current_player_turn, player1_uuid);
+    }
+
+    #[test]
+    fn leave_during_initial_ante_in_gambling_round() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Sanity check.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            8
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            8
+        );
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 1 starts gambling round.
+        assert!(game_logic
+            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
+            .is_ok());
+
+        // Player 2 tries to leave the gambling round.
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player2_uuid));
+        assert!(game_logic
+            .process_card(
+                leave_gambling_round_instead_of_anteing_card("Leave gambling round").into(),
+                &player2_uuid,
+                &None
+            )
+            .is_ok());
+        assert!(game_logic.gambling_manager.round_in_progress());
+        assert!(game_logic
+            .process_card(i_dont_think_so_card().into(), &player1_uuid, &None)
+            .is_ok());
+        assert!(game_logic
+            .process_card(i_dont_think_so_card().into(), &player2_uuid, &None)
+            .is_ok());
+        // Player 1 gives up and lets player 2 leave the gambling round.
+        assert!(game_logic.pass(&player1_uuid).is_ok());
+
+        // Since player 1 is the only player left in the gambling round, the round ends and player 1's OrderDrinks turn phase starts.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            9
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            7
+        );
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::OrderDrinks);
+    }
+
+    #[test]
+    fn try_to_leave_during_initial_ante_in_gambling_round() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid.clone(), Character::Fiona),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Sanity check.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            10
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            10
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player3_uuid)
+                .unwrap()
+                .get_gold(),
+            10
+        );
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 1 starts gambling round.
+        assert!(game_logic
+            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
+            .is_ok());
+
+        // Player 2 tries to leave the gambling round.
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player2_uuid));
+        assert!(game_logic
+            .process_card(
+                leave_gambling_round_instead_of_anteing_card("Leave gambling round").into(),
+                &player2_uuid,
+                &None
+            )
+            .is_ok());
+        assert!(game_logic.gambling_manager.round_in_progress());
+        assert!(game_logic.pass(&player3_uuid).is_ok());
+        assert!(game_logic
+            .process_card(i_dont_think_so_card().into(), &player1_uuid, &None)
+            .is_ok());
+        // Player 2 fails to leave the gambling round.
+        assert!(game_logic.pass(&player2_uuid).is_ok());
+        // Player 3 doesn't attempt to leave the gambling round, and antes up.
+        assert!(game_logic.pass(&player3_uuid).is_ok());
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+
+        // 1 gold should be subtracted from each player.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            9
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            9
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player3_uuid)
+                .unwrap()
+                .get_gold(),
+            9
+        );
+        assert!(game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 2 does not take control of the gambling round.
+        assert!(game_logic.gambling_manager.is_turn(&player2_uuid));
+        assert!(!game_logic.can_pass(&player1_uuid));
+        assert!(game_logic.can_pass(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+        // Player 3 does not take control of the gambling round, making player 1 the winner.
+        assert!(game_logic.gambling_manager.is_turn(&player3_uuid));
+        assert!(!game_logic.can_pass(&player1_uuid));
+        assert!(game_logic.can_pass(&player3_uuid));
+        game_logic.pass(&player3_uuid).unwrap();
+
+        // Gambling pot should be given to the winner.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            12
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            9
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player3_uuid)
+                .unwrap()
+                .get_gold(),
+            9
+        );
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::OrderDrinks);
+    }
+
+    #[test]
+    fn cheat_in_gambling_round() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Sanity check.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            8
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            8
+        );
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 1 starts gambling round.
+        assert!(game_logic
+            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
+            .is_ok());
+
+        // Player 2 chooses not to play an interrupt card.
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player2_uuid));
+        assert!(!game_logic.can_pass(&player1_uuid));
+        assert!(game_logic.can_pass(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+
+        // 1 gold should be subtracted from each player.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            7
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            7
+        );
+        assert!(game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 2 plays a winning hand card.
+        assert!(game_logic
+            .process_card(winning_hand_card().into(), &player2_uuid, &None)
+            .is_ok());
+
+        // Player 1 attempts to play a regular gambling card.
+        assert_eq!(
+            game_logic
+                .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
+                .unwrap_err()
+                .1,
+            Error::new("Card cannot be played at this time")
+        );
+
+        // Player 1 plays a cheating card.
+        assert!(game_logic
+            .process_card(
+                gambling_cheat_card("Card up the sleeve").into(),
+                &player1_uuid,
+                &None
+            )
+            .is_ok());
+
+        // Player 2 does not take control of the gambling round, making player 1 the winner.
+        assert!(game_logic.gambling_manager.is_turn(&player2_uuid));
+        assert!(!game_logic.can_pass(&player1_uuid));
+        assert!(game_logic.can_pass(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+
+        // Gambling pot should be given to the winner.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            9
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            7
+        );
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::OrderDrinks);
+    }
+
+    #[test]
+    fn cannot_play_gambling_cards_during_game_interrupts() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Sanity check.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            8
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            8
+        );
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Start gambling round.
+        assert!(game_logic
+            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
+            .is_ok());
+
+        // Other player can choose to interrupt their ante (but doesn't yet).
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player2_uuid));
+
+        // Neither player can play other gambling cards.
+        assert!(!i_raise_card().can_play(
+            &player1_uuid,
+            &game_logic.gambling_manager,
+            &game_logic.interrupt_manager,
+            &game_logic.turn_info
+        ));
+        assert!(!i_raise_card().can_play(
+            &player2_uuid,
+            &game_logic.gambling_manager,
+            &game_logic.interrupt_manager,
+            &game_logic.turn_info
+        ));
+        assert!(!gambling_im_in_card().can_play(
+            &player1_uuid,
+            &game_logic.gambling_manager,
+            &game_logic.interrupt_manager,
+            &game_logic.turn_info
+        ));
+        assert!(!gambling_im_in_card().can_play(
+            &player2_uuid,
+            &game_logic.gambling_manager,
+            &game_logic.interrupt_manager,
+            &game_logic.turn_info
+        ));
+
+        // Player 2 passes and antes.
+        game_logic.pass(&player2_uuid).unwrap();
+
+        // Player 2 can now play a gambling card.
+        assert!(!i_raise_card().can_play(
+            &player1_uuid,
+            &game_logic.gambling_manager,
+            &game_logic.interrupt_manager,
+            &game_logic.turn_info
+        ));
+        assert!(i_raise_card().can_play(
+            &player2_uuid,
+            &game_logic.gambling_manager,
+            &game_logic.interrupt_manager,
+            &game_logic.turn_info
+        ));
+        assert!(!gambling_im_in_card().can_play(
+            &player1_uuid,
+            &game_logic.gambling_manager,
+            &game_logic.interrupt_manager,
+            &game_logic.turn_info
+        ));
+        assert!(gambling_im_in_card().can_play(
+            &player2_uuid,
+            &game_logic.gambling_manager,
+            &game_logic.interrupt_manager,
+            &game_logic.turn_info
+        ));
+    }
+
+    #[test]
+    fn round_cannot_be_ended_in_response_to_ante() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        let can_end_round = |game_logic: &GameLogic, player_uuid: &PlayerUUID| {
+            oh_i_guess_the_wench_thought_that_was_her_tip_card().can_play(
+                player_uuid,
+                &game_logic.gambling_manager,
+                &game_logic.interrupt_manager,
+                &game_logic.turn_info,
+            )
+        };
+
+        // Player 1 starts a round, and player 2 antes.
+        game_logic
+            .process_card(gambling_im_in_card().into(), &player1_uuid, &None)
+            .unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+
+        // Player 2 raises, so both players are about to ante again.
+        game_logic
+            .process_card(i_raise_card().into(), &player2_uuid, &None)
+            .unwrap();
+        assert!(game_logic.interrupt_manager.is_resolving_ante());
+        assert!(!can_end_round(&game_logic, &player1_uuid));
+        assert!(!can_end_round(&game_logic, &player2_uuid));
+        assert!(game_logic
+            .process_card(
+                oh_i_guess_the_wench_thought_that_was_her_tip_card().into(),
+                &player1_uuid,
+                &None
+            )
+            .is_err());
+        assert!(game_logic.gambling_manager.round_in_progress());
+
+        // Once everyone has anted, the round can be ended.
+        game_logic.pass(&player2_uuid).unwrap();
+        game_logic.pass(&player1_uuid).unwrap();
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+        assert!(can_end_round(&game_logic, &player1_uuid));
+        assert!(can_end_round(&game_logic, &player2_uuid));
+        game_logic
+            .process_card(
+                oh_i_guess_the_wench_thought_that_was_her_tip_card().into(),
+                &player1_uuid,
+                &None,
+            )
+            .unwrap();
+        assert!(!game_logic.gambling_manager.round_in_progress());
+    }
+
+    #[test]
+    fn can_handle_change_other_player_fortitude_card() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid.clone(), Character::Fiona),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Sanity check.
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 1 attempts to hurt player 2.
+        assert!(game_logic
+            .process_card(
+                change_other_player_fortitude_card("Punch in the face", -2).into(),
+                &player1_uuid,
+                &Some(player2_uuid.clone())
+            )
+            .is_ok());
+
+        // Sanity check.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_fortitude(),
+            20
+        );
+        assert!(game_logic.interrupt_manager.interrupt_in_progress());
+
+        // Player 2 chooses not to play an interrupt card.
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+
+        // Fortitude should be reduced.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_fortitude(),
+            18
+        );
+
+        // Fortitude for other player should remain unchanged.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player3_uuid)
+                .unwrap()
+                .get_fortitude(),
+            20
+        );
+
+        // Should proceed to player 1's order drink phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+    }
+
+    #[test]
+    fn change_other_player_fortitude_card_is_recorded_in_stat_change_events() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        assert!(game_logic
+            .get_game_view_stat_change_events(&player2_uuid)
+            .is_empty());
+
+        // Player 1 hurts player 2.
+        assert!(game_logic
+            .process_card(
+                change_other_player_fortitude_card("Punch in the face", -2).into(),
+                &player1_uuid,
+                &Some(player2_uuid.clone())
+            )
+            .is_ok());
+        game_logic.pass(&player2_uuid).unwrap();
+
+        let stat_change_events = game_logic.get_game_view_stat_change_events(&player2_uuid);
+        assert_eq!(stat_change_events.len(), 1);
+        assert_eq!(stat_change_events[0].amount, -2);
+        assert_eq!(stat_change_events[0].stat, Stat::Fortitude);
+        assert_eq!(stat_change_events[0].reason, "Punch in the face");
+
+        // Player 1's stats were never changed.
+        assert!(game_logic
+            .get_game_view_stat_change_events(&player1_uuid)
+            .is_empty());
+    }
+
+    #[test]
+    fn stat_change_events_are_bounded() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid, Character::Gerki),
+        ])
+        .unwrap();
+
+        let player = game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap();
+        for i in 0..25 {
+            player.change_gold(1, format!("Test {}", i));
+        }
+
+        let stat_change_events = game_logic.get_game_view_stat_change_events(&player1_uuid);
+        assert_eq!(stat_change_events.len(), 20);
+        assert_eq!(stat_change_events.first().unwrap().reason, "Test 5");
+        assert_eq!(stat_change_events.last().unwrap().reason, "Test 24");
+    }
+
+    #[test]
+    fn cannot_direct_card_at_player_who_is_out_of_game() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid, Character::Fiona),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player2_uuid)
+            .unwrap()
+            .forfeit();
+
+        // Player 1 attempts to hurt player 2, who is already out.
+        assert_eq!(
+            game_logic
+                .process_card(
+                    change_other_player_fortitude_card("Punch in the face", -2).into(),
+                    &player1_uuid,
+                    &Some(player2_uuid)
+                )
+                .unwrap_err()
+                .1,
+            Error::new("Cannot direct this card at a player who is out of the game")
+        );
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Action);
+    }
+
+    #[test]
+    fn all_other_players_card_skips_players_who_are_out_of_game() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid.clone(), Character::Fiona),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player2_uuid)
+            .unwrap()
+            .forfeit();
+
+        assert!(game_logic
+            .process_card(
+                change_all_other_player_fortitude_card("Punch everyone in the face", -2).into(),
+                &player1_uuid,
+                &None
+            )
+            .is_ok());
+
+        // Player 2 is skipped, so player 3 is the only one who may interrupt.
+        assert!(!game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player2_uuid));
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player3_uuid));
+        game_logic.pass(&player3_uuid).unwrap();
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_fortitude(),
+            20
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player3_uuid)
+                .unwrap()
+                .get_fortitude(),
+            18
+        );
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+    }
+
+    #[test]
+    fn can_handle_change_all_other_player_fortitude_card() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid.clone(), Character::Fiona),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Sanity check.
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 1 attempts to hurt all other players.
+        assert!(game_logic
+            .process_card(
+                change_all_other_player_fortitude_card("Punch everyone in the face", -2).into(),
+                &player1_uuid,
+                &None
+            )
+            .is_ok());
+
+        // Sanity check.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_fortitude(),
+            20
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player3_uuid)
+                .unwrap()
+                .get_fortitude(),
+            20
+        );
+        assert!(game_logic.interrupt_manager.interrupt_in_progress());
+
+        // Player 2 chooses not to play an interrupt card.
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+        assert!(game_logic.interrupt_manager.interrupt_in_progress());
+
+        // Fortitude should be reduced.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_fortitude(),
+            18
+        );
+
+        // Player 3 plays an interrupt card.
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player3_uuid));
+        assert!(game_logic
+            .process_card(
+                ignore_root_card_affecting_fortitude("Block punch").into(),
+                &player3_uuid,
+                &None
+            )
+            .is_ok());
+        // Player 1 stops the interrupt.
+        assert!(game_logic
+            .process_card(i_dont_think_so_card().into(), &player1_uuid, &None)
+            .is_ok());
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player3_uuid));
+        game_logic.pass(&player3_uuid).unwrap();
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+
+        // Fortitude should be reduced.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player3_uuid)
+                .unwrap()
+                .get_fortitude(),
+            18
+        );
+
+        // Should proceed to player 1's order drink phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+    }
+
+    #[test]
+    fn chain_of_i_dont_think_so_cards_alternates_outcome() {
+        // An odd number of "I don't think so!" cards negates the Sometimes card, while an even
+        // number lets it resolve.
+        for (chain_length, expected_fortitude) in [(1, 18), (2, 20), (3, 18)] {
+            let player1_uuid = PlayerUUID::new();
+            let player2_uuid = PlayerUUID::new();
+
+            let mut game_logic = GameLogic::new(vec![
+                (player1_uuid.clone(), Character::Deirdre),
+                (player2_uuid.clone(), Character::Gerki),
+            ])
+            .unwrap();
+            game_logic
+                .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+                .unwrap();
+
+            // Player 1 punches player 2, who tries to block it.
+            game_logic
+                .process_card(
+                    change_other_player_fortitude_card("Punch", -2).into(),
+                    &player1_uuid,
+                    &Some(player2_uuid.clone()),
+                )
+                .unwrap();
+            game_logic
+                .process_card(
+                    ignore_root_card_affecting_fortitude("Block punch").into(),
+                    &player2_uuid,
+                    &None,
+                )
+                .unwrap();
+
+            // Players take turns countering each other, starting with player 1.
+            for i in 0..chain_length {
+                let player_uuid = if i % 2 == 0 {
+                    &player1_uuid
+                } else {
+                    &player2_uuid
+                };
+                assert!(game_logic
+                    .interrupt_manager
+                    .is_turn_to_interrupt(player_uuid));
+                game_logic
+                    .process_card(i_dont_think_so_card().into(), player_uuid, &None)
+                    .unwrap();
+            }
+
+            while game_logic.interrupt_manager.interrupt_in_progress() {
+                if game_logic
+                    .interrupt_manager
+                    .is_turn_to_interrupt(&player1_uuid)
+                {
+                    game_logic.pass(&player1_uuid).unwrap();
+                } else {
+                    game_logic.pass(&player2_uuid).unwrap();
+                }
+            }
+
+            assert_eq!(
+                game_logic
+                    .player_manager
+                    .get_player_by_uuid(&player2_uuid)
+                    .unwrap()
+                    .get_fortitude(),
+                expected_fortitude,
+                "Unexpected fortitude after a chain of {} \"I don't think so!\" cards",
+                chain_length
+            );
+            assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+        }
+    }
+
+    #[test]
+    fn can_handle_swap_fortitude_card() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .change_fortitude(-5, "Test");
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player2_uuid)
+            .unwrap()
+            .change_fortitude(-2, "Test");
+
+        // Player 1 swaps fortitude with player 2.
+        assert!(game_logic
+            .process_card(
+                swap_fortitude_card("Swap").into(),
+                &player1_uuid,
+                &Some(player2_uuid.clone())
+            )
+            .is_ok());
+        assert!(game_logic.interrupt_manager.interrupt_in_progress());
+
+        // Player 2 chooses not to play an interrupt card.
+        game_logic.pass(&player2_uuid).unwrap();
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_fortitude(),
+            18
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_fortitude(),
+            15
+        );
+    }
+
+    #[test]
+    fn swap_fortitude_card_can_be_ignored() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .change_fortitude(-5, "Test");
+
+        assert!(game_logic
+            .process_card(
+                swap_fortitude_card("Swap").into(),
+                &player1_uuid,
+                &Some(player2_uuid.clone())
+            )
+            .is_ok());
+
+        // Player 2 ignores the card.
+        assert!(game_logic
+            .process_card(
+                ignore_root_card_affecting_fortitude("Ignore").into(),
+                &player2_uuid,
+                &None
+            )
+            .is_ok());
+        game_logic.pass(&player1_uuid).unwrap();
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_fortitude(),
+            15
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_fortitude(),
+            20
+        );
+    }
+
+    #[test]
+    fn cannot_play_directed_card_on_self() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid, Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 1 attempts to hurt self.
+        assert_eq!(
+            game_logic
+                .process_card(
+                    change_other_player_fortitude_card("Punch in the face", -2).into(),
+                    &player1_uuid,
+                    &Some(player1_uuid.clone())
+                )
+                .unwrap_err()
+                .1,
+            Error::new("Must not direct this card at yourself")
+        );
+
+        // Should stay at player 1's action phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Action);
+    }
+
+    #[test]
+    fn can_handle_interrupted_change_other_player_fortitude_card() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Sanity check.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .get_gold(),
+            8
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_gold(),
+            8
+        );
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Reduce player 2's fortitude to ensure that it is properly restored.
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player2_uuid)
+            .unwrap()
+            .change_fortitude(-2, "Test");
+
+        assert!(game_logic
+            .process_card(
+                change_other_player_fortitude_card("Punch in the face", -2).into(),
+                &player1_uuid,
+                &Some(player2_uuid.clone())
+            )
+            .is_ok());
+
+        assert!(gain_fortitude_anytime_card("Heal", 1).can_play(
+            &player1_uuid,
+            &game_logic.gambling_manager,
             &game_logic.interrupt_manager,
             &game_logic.turn_info
         ));
-        assert!(!gambling_im_in_card().can_play(
+        assert!(game_logic
+            .process_card(
+                gain_fortitude_anytime_card("Heal", 1).into(),
+                &player1_uuid,
+                &None
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn can_gain_fortitude_during_game_interrupt() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        assert!(game_logic
+            .process_card(
+                change_other_player_fortitude_card("Punch in the face", -2).into(),
+                &player1_uuid,
+                &Some(player2_uuid.clone())
+            )
+            .is_ok());
+
+        // Player 2 plays an interrupt card.
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player2_uuid));
+        assert!(game_logic
+            .process_card(
+                ignore_root_card_affecting_fortitude("Block punch").into(),
+                &player2_uuid,
+                &None
+            )
+            .is_ok());
+        // Player 1 chooses not to play a countering interrupt card.
+        assert!(game_logic
+            .interrupt_manager
+            .is_turn_to_interrupt(&player1_uuid));
+        game_logic.pass(&player1_uuid).unwrap();
+        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+
+        // Fortitude should not be reduced.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .get_fortitude(),
+            20
+        );
+    }
+
+    #[test]
+    fn can_order_drinks_after_action_phase() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 1 skips their action phase.
+        assert!(game_logic.pass(&player1_uuid).is_ok());
+
+        // Should proceed to player 1's order drink phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+
+        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+
+        // Should proceed to player 2's discard phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+    }
+
+    #[test]
+    fn cannot_order_drink_once_no_drinks_are_left_to_order() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        game_logic.pass(&player1_uuid).unwrap();
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+
+        // Simulate the count reaching zero without the turn having moved on yet.
+        game_logic.turn_info.add_drinks_to_order(-1);
+        assert_eq!(game_logic.turn_info.drinks_to_order, 0);
+
+        assert_eq!(
+            game_logic.order_drink(&player1_uuid, &player2_uuid),
+            Err(Error::new("No drinks left to order"))
+        );
+        assert_eq!(game_logic.turn_info.drinks_to_order, 0);
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+        assert!(game_logic
+            .get_game_log()
+            .iter()
+            .all(|game_event| game_event.action != GameEventAction::OrderDrink));
+    }
+
+    #[test]
+    fn cannot_order_drink_out_of_turn() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic.turn_info.turn_phase = TurnPhase::OrderDrinks;
+
+        assert_eq!(
+            game_logic.order_drink(&player2_uuid, &player1_uuid),
+            Err(Error::new("It is not your turn"))
+        );
+        assert_eq!(
+            game_logic.order_drinks(&player2_uuid, vec![player1_uuid.clone()]),
+            Err(Error::new("It is not your turn"))
+        );
+    }
+
+    #[test]
+    fn cannot_order_drink_outside_order_drinks_phase() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::DiscardAndDraw);
+
+        assert_eq!(
+            game_logic.order_drink(&player1_uuid, &player2_uuid),
+            Err(Error::new(
+                "Drinks can only be ordered during the order drinks phase (current phase: DiscardAndDraw)"
+            ))
+        );
+        assert_eq!(
+            game_logic.order_drinks(&player1_uuid, vec![player2_uuid.clone()]),
+            Err(Error::new(
+                "Drinks can only be ordered during the order drinks phase (current phase: DiscardAndDraw)"
+            ))
+        );
+    }
+
+    #[test]
+    fn cannot_order_drink_from_exhausted_drink_deck() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        game_logic.pass(&player1_uuid).unwrap();
+
+        // Every drink has been handed out and none have been discarded yet.
+        while game_logic.drink_deck.draw_card().is_some() {}
+
+        assert_eq!(
+            game_logic.order_drink(&player1_uuid, &player2_uuid),
+            Err(Error::new("The drink deck is out of drinks"))
+        );
+        assert_eq!(
+            game_logic.order_drinks(&player1_uuid, vec![player2_uuid.clone()]),
+            Err(Error::new("The drink deck is out of drinks"))
+        );
+        assert_eq!(game_logic.turn_info.drinks_to_order, 1);
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+        assert!(!game_logic
+            .legal_actions(&player1_uuid)
+            .iter()
+            .any(|legal_action| matches!(legal_action, LegalAction::OrderDrink { .. })));
+        assert_eq!(
+            game_logic
+                .get_game_view_player_data_or(&player2_uuid)
+                .unwrap()
+                .drink_me_pile_size,
+            0
+        );
+
+        // The player can still end their turn.
+        game_logic.skip_turn(&player1_uuid).unwrap();
+        assert_eq!(
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player2_uuid
+        );
+    }
+
+    #[test]
+    fn replayed_idempotency_key_only_orders_drink_once() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        game_logic.pass(&player1_uuid).unwrap();
+        game_logic.turn_info.add_drinks_to_order(1);
+        assert_eq!(game_logic.turn_info.drinks_to_order, 2);
+
+        let order_drink =
+            |game_logic: &mut GameLogic| game_logic.order_drink(&player1_uuid, &player2_uuid);
+
+        assert_eq!(
+            game_logic.perform_idempotent_action(
+                &player1_uuid,
+                Some("key1".to_string()),
+                order_drink
+            ),
+            Ok(())
+        );
+        assert_eq!(game_logic.turn_info.drinks_to_order, 1);
+
+        // Retrying with the same key returns the earlier result without ordering another drink.
+        assert_eq!(
+            game_logic.perform_idempotent_action(
+                &player1_uuid,
+                Some("key1".to_string()),
+                order_drink
+            ),
+            Ok(())
+        );
+        assert_eq!(game_logic.turn_info.drinks_to_order, 1);
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+
+        // A new key is applied as normal.
+        assert_eq!(
+            game_logic.perform_idempotent_action(
+                &player1_uuid,
+                Some("key2".to_string()),
+                order_drink
+            ),
+            Ok(())
+        );
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+    }
+
+    #[test]
+    fn can_order_multiple_drinks() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 1 skips their action phase.
+        assert!(game_logic.pass(&player1_uuid).is_ok());
+
+        // Should proceed to player 1's order drink phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+
+        assert!(game_logic
+            .process_card(
+                wench_bring_some_drinks_for_my_friends_card().into(),
+                &player1_uuid,
+                &None
+            )
+            .is_ok());
+
+        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+
+        // Should proceed to player 2's discard phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+    }
+
+    #[test]
+    fn can_order_batch_of_drinks() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid.clone(), Character::Fiona),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        assert!(game_logic.pass(&player1_uuid).is_ok());
+        assert!(game_logic
+            .process_card(
+                wench_bring_some_drinks_for_my_friends_card().into(),
+                &player1_uuid,
+                &None
+            )
+            .is_ok());
+
+        let get_drink_me_pile_size = |game_logic: &GameLogic, player_uuid: &PlayerUUID| {
+            game_logic
+                .player_manager
+                .get_player_by_uuid(player_uuid)
+                .unwrap()
+                .to_game_view_player_data(player_uuid.clone())
+                .drink_me_pile_size
+        };
+
+        // Wrong number of drinks.
+        assert_eq!(
+            game_logic.order_drinks(
+                &player1_uuid,
+                vec![player2_uuid.clone(), player3_uuid.clone()]
+            ),
+            Err(Error::new("Must order exactly 3 drinks"))
+        );
+        // One invalid target spoils the whole batch.
+        assert_eq!(
+            game_logic.order_drinks(
+                &player1_uuid,
+                vec![
+                    player2_uuid.clone(),
+                    player3_uuid.clone(),
+                    player1_uuid.clone()
+                ]
+            ),
+            Err(Error::new("Cannot order drink for yourself"))
+        );
+        assert_eq!(get_drink_me_pile_size(&game_logic, &player2_uuid), 0);
+        assert_eq!(get_drink_me_pile_size(&game_logic, &player3_uuid), 0);
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+
+        assert_eq!(
+            game_logic.order_drinks(
+                &player1_uuid,
+                vec![
+                    player2_uuid.clone(),
+                    player3_uuid.clone(),
+                    player2_uuid.clone()
+                ]
+            ),
+            Ok(())
+        );
+        assert_eq!(get_drink_me_pile_size(&game_logic, &player2_uuid), 2);
+        assert_eq!(get_drink_me_pile_size(&game_logic, &player3_uuid), 1);
+
+        // Should proceed to player 2's discard phase.
+        assert_eq!(
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player2_uuid
+        );
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+    }
+
+    #[test]
+    fn resolved_drink_is_sent_as_transient_event_once() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        game_logic.pass(&player1_uuid).unwrap();
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .add_drink_to_drink_pile(create_simple_ale_test_drink(false).into());
+        game_logic
+            .order_drink(&player1_uuid, &player2_uuid)
+            .unwrap();
+        // The drink is drunk once nobody is left to interrupt it.
+        game_logic.pass(&player1_uuid).unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
+        game_logic.pass(&player1_uuid).unwrap();
+        // Actions taken through `perform_undoable_action` collect these on their own.
+        game_logic.collect_transient_events();
+
+        let drink_drunk_event = GameViewTransientEvent {
+            player_uuid: player1_uuid.clone(),
+            event: TransientEvent::DrinkDrunk {
+                name: "[Test Ale]".to_string(),
+            },
+        };
+        for player_uuid in [&player1_uuid, &player2_uuid] {
+            let transient_events = game_logic.take_transient_events(player_uuid);
+            assert_eq!(
+                transient_events
+                    .iter()
+                    .filter(|event| **event == drink_drunk_event)
+                    .count(),
+                1
+            );
+            assert!(game_logic.take_transient_events(player_uuid).is_empty());
+        }
+    }
+
+    #[test]
+    fn player_drinks_top_drink_after_ordering_drinks() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 1 skips their action phase.
+        assert!(game_logic.pass(&player1_uuid).is_ok());
+
+        // Should proceed to player 1's order drink phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+
+        // Order drink for next player.
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .add_drink_to_drink_pile(create_simple_ale_test_drink(false).into());
+        let player1_drink_me_pile_size = game_logic
+            .player_manager
+            .get_player_by_uuid(&player1_uuid)
+            .unwrap()
+            .to_game_view_player_data(player1_uuid.clone())
+            .drink_me_pile_size;
+        let player1_alcohol_content = game_logic
+            .player_manager
+            .get_player_by_uuid(&player1_uuid)
+            .unwrap()
+            .to_game_view_player_data(player1_uuid.clone())
+            .alcohol_content;
+        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+
+        // Should proceed to player 1's drink phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Drink);
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .to_game_view_player_data(player1_uuid.clone())
+                .drink_me_pile_size,
+            player1_drink_me_pile_size - 1
+        );
+        assert!(game_logic.can_pass(&player1_uuid));
+        game_logic.pass(&player1_uuid).unwrap();
+        assert!(game_logic.can_pass(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .to_game_view_player_data(player1_uuid.clone())
+                .alcohol_content,
+            player1_alcohol_content
+        );
+        assert!(game_logic.can_pass(&player1_uuid));
+        game_logic.pass(&player1_uuid).unwrap();
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .to_game_view_player_data(player1_uuid.clone())
+                .alcohol_content,
+            player1_alcohol_content + 1
+        );
+
+        // Should proceed to player 2's discard phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+    }
+
+    #[test]
+    fn player_is_eliminated_immediately_after_drink() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+        let player_uuids = [
+            player1_uuid.clone(),
+            player2_uuid.clone(),
+            player3_uuid.clone(),
+        ];
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid, Character::Fiona),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        assert!(game_logic.pass(&player1_uuid).is_ok());
+
+        // Bring player 1 to the edge of passing out.
+        let player1 = game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap();
+        player1.change_alcohol_content(19, "Test");
+        player1.add_drink_to_drink_pile(create_simple_ale_test_drink(false).into());
+        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Drink);
+
+        // Nobody interrupts the drink.
+        while game_logic.interrupt_manager.interrupt_in_progress() {
+            let player_uuid = player_uuids
+                .iter()
+                .find(|player_uuid| {
+                    game_logic
+                        .interrupt_manager
+                        .is_turn_to_interrupt(player_uuid)
+                })
+                .unwrap()
+                .clone();
+            game_logic.pass(&player_uuid).unwrap();
+        }
+
+        let player1_data = game_logic
+            .get_game_view_player_data_of_all_players()
+            .into_iter()
+            .find(|player_data| player_data.player_uuid == player1_uuid)
+            .unwrap();
+        assert_eq!(player1_data.alcohol_content, 20);
+        assert!(player1_data.is_dead);
+        assert_eq!(game_logic.get_eliminated_player_uuids(), vec![player1_uuid]);
+
+        // The game continues with the remaining players.
+        assert!(game_logic.is_running());
+        assert_eq!(
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player2_uuid
+        );
+    }
+
+    #[test]
+    fn player_passes_out_from_drink_that_only_lowers_fortitude() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+        let player_uuids = [
+            player1_uuid.clone(),
+            player2_uuid.clone(),
+            player3_uuid.clone(),
+        ];
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid, Character::Fiona),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        assert!(game_logic.pass(&player1_uuid).is_ok());
+
+        // Player 1's fortitude is just above their alcohol content.
+        let player1 = game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap();
+        player1.change_alcohol_content(10, "Test");
+        player1.change_fortitude(-9, "Test");
+        player1.add_drink_to_drink_pile(create_dirty_dishwater_test_drink().into());
+        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Drink);
+
+        // Nobody interrupts the drink. Player 1 stays in the game until it's drunk.
+        while game_logic.interrupt_manager.interrupt_in_progress() {
+            assert!(game_logic.get_eliminated_player_uuids().is_empty());
+            let player_uuid = player_uuids
+                .iter()
+                .find(|player_uuid| {
+                    game_logic
+                        .interrupt_manager
+                        .is_turn_to_interrupt(player_uuid)
+                })
+                .unwrap()
+                .clone();
+            game_logic.pass(&player_uuid).unwrap();
+        }
+
+        let player1_data = game_logic
+            .get_game_view_player_data_or(&player1_uuid)
+            .unwrap();
+        assert_eq!(player1_data.alcohol_content, 10);
+        assert_eq!(player1_data.fortitude, 10);
+        assert!(player1_data.is_dead);
+        assert_eq!(game_logic.get_eliminated_player_uuids(), vec![player1_uuid]);
+        assert!(game_logic.is_running());
+        assert_eq!(
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player2_uuid
+        );
+    }
+
+    #[test]
+    fn player_can_ignore_drink() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        assert!(!game_logic.gambling_manager.round_in_progress());
+        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+
+        // Player 1 skips their action phase.
+        assert!(game_logic.pass(&player1_uuid).is_ok());
+
+        // Should proceed to player 1's order drink phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+
+        // Order drink for next player.
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .add_drink_to_drink_pile(create_simple_ale_test_drink(false).into());
+        let player1_drink_me_pile_size = game_logic
+            .player_manager
+            .get_player_by_uuid(&player1_uuid)
+            .unwrap()
+            .to_game_view_player_data(player1_uuid.clone())
+            .drink_me_pile_size;
+        let player1_alcohol_content = game_logic
+            .player_manager
+            .get_player_by_uuid(&player1_uuid)
+            .unwrap()
+            .to_game_view_player_data(player1_uuid.clone())
+            .alcohol_content;
+        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+
+        // Should proceed to player 1's drink phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Drink);
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .to_game_view_player_data(player1_uuid.clone())
+                .drink_me_pile_size,
+            player1_drink_me_pile_size - 1
+        );
+        assert!(game_logic.can_pass(&player1_uuid));
+        game_logic.pass(&player1_uuid).unwrap();
+        assert!(game_logic.can_pass(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+        assert!(game_logic
+            .process_card(
+                ignore_drink_card("Ignore Drink").into(),
+                &player1_uuid,
+                &None
+            )
+            .is_ok());
+        // Player 2 passes on the chance to interrupt player 1's 'Ignore Drink' card.
+        assert!(game_logic.can_pass(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .to_game_view_player_data(player1_uuid.clone())
+                .alcohol_content,
+            player1_alcohol_content
+        );
+
+        // Should proceed to player 2's discard phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+    }
+
+    #[test]
+    fn player_can_redirect_drink() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Player 1 skips their action phase.
+        assert!(game_logic.pass(&player1_uuid).is_ok());
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .add_drink_to_drink_pile(create_simple_ale_test_drink(false).into());
+        let player1_alcohol_content = game_logic
+            .player_manager
+            .get_player_by_uuid(&player1_uuid)
+            .unwrap()
+            .to_game_view_player_data(player1_uuid.clone())
+            .alcohol_content;
+        let player2_alcohol_content = game_logic
+            .player_manager
+            .get_player_by_uuid(&player2_uuid)
+            .unwrap()
+            .to_game_view_player_data(player2_uuid.clone())
+            .alcohol_content;
+        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Drink);
+
+        // Both players pass on the chance to modify the drink.
+        game_logic.pass(&player1_uuid).unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
+
+        // Player 1 passes the drink to player 2.
+        assert!(game_logic
+            .process_card(
+                redirect_drink_card("Redirect Drink").into(),
+                &player1_uuid,
+                &None
+            )
+            .is_ok());
+        // Player 2 passes on the chance to interrupt player 1's 'Redirect Drink' card.
+        assert!(game_logic.can_pass(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .to_game_view_player_data(player1_uuid.clone())
+                .alcohol_content,
+            player1_alcohol_content
+        );
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player2_uuid)
+                .unwrap()
+                .to_game_view_player_data(player2_uuid.clone())
+                .alcohol_content,
+            player2_alcohol_content + 1
+        );
+
+        // Should proceed to player 2's discard phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+    }
+
+    #[test]
+    fn player_can_swap_drink_with_top_of_drink_deck() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+
+        // Player 1 skips their action phase.
+        assert!(game_logic.pass(&player1_uuid).is_ok());
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .add_drink_to_drink_pile(create_simple_ale_test_drink(false).into());
+        let player1_alcohol_content = game_logic
+            .player_manager
+            .get_player_by_uuid(&player1_uuid)
+            .unwrap()
+            .to_game_view_player_data(player1_uuid.clone())
+            .alcohol_content;
+        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Drink);
+
+        // Make sure we know which drink is on top of the drink deck.
+        game_logic.drink_deck = AutoShufflingDeck::new(vec![create_strong_test_drink().into()]);
+
+        // Both players pass on the chance to modify the drink.
+        game_logic.pass(&player1_uuid).unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
+
+        // Player 1 swaps their drink with the top of the drink deck.
+        assert!(game_logic
+            .process_card(swap_drink_card("Swap Drink").into(), &player1_uuid, &None)
+            .is_ok());
+        // Player 2 passes on the chance to interrupt player 1's 'Swap Drink' card.
+        assert!(game_logic.can_pass(&player2_uuid));
+        game_logic.pass(&player2_uuid).unwrap();
+
+        // Player 1 drank the swapped drink instead of the original one.
+        assert_eq!(
+            game_logic
+                .player_manager
+                .get_player_by_uuid(&player1_uuid)
+                .unwrap()
+                .to_game_view_player_data(player1_uuid.clone())
+                .alcohol_content,
+            player1_alcohol_content + 3
+        );
+        assert_eq!(game_logic.drink_deck.draw_pile_size(), 0);
+        assert_eq!(game_logic.drink_deck.discard_pile_size(), 2);
+
+        // Should proceed to player 2's discard phase.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+    }
+
+    #[test]
+    fn only_targeted_player_has_interrupt_responses_to_fortitude_attack() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        for player_uuid in [&player1_uuid, &player2_uuid] {
+            game_logic
+                .player_manager
+                .get_player_by_uuid_mut(player_uuid)
+                .unwrap()
+                .return_card_to_hand(ignore_root_card_affecting_fortitude("Ignore").into(), 0);
+        }
+        assert!(game_logic
+            .available_interrupt_responses(&player2_uuid)
+            .is_empty());
+
+        // Player 1 attacks player 2's fortitude.
+        assert!(game_logic
+            .process_card(
+                change_other_player_fortitude_card("Punch in the face", -2).into(),
+                &player1_uuid,
+                &Some(player2_uuid.clone())
+            )
+            .is_ok());
+
+        assert!(game_logic
+            .available_interrupt_responses(&player2_uuid)
+            .contains(&"Ignore".to_string()));
+        assert!(game_logic
+            .available_interrupt_responses(&player1_uuid)
+            .is_empty());
+    }
+
+    #[test]
+    fn discarding_out_of_turn_explains_why() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            game_logic.discard_cards_and_draw_to_full(&player2_uuid, Vec::new()),
+            Err(Error::new("It is not your turn"))
+        );
+
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        assert_eq!(
+            game_logic.discard_cards_and_draw_to_full(&player1_uuid, Vec::new()),
+            Err(Error::new(
+                "Cards can only be discarded during the discard phase (current phase: Action)"
+            ))
+        );
+    }
+
+    #[test]
+    fn stat_snapshots_are_recorded_at_the_start_of_each_turn() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        let get_player_data = |game_logic: &GameLogic, player_uuid: &PlayerUUID| {
+            game_logic
+                .player_manager
+                .get_player_by_uuid(player_uuid)
+                .unwrap()
+                .to_game_view_player_data(player_uuid.clone())
+        };
+
+        // Player 1's first turn has already started.
+        assert_eq!(
+            get_player_data(&game_logic, &player1_uuid).gold_history,
+            vec![8]
+        );
+        assert!(get_player_data(&game_logic, &player2_uuid)
+            .gold_history
+            .is_empty());
+
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .change_gold(-3, "Test");
+        for _ in 0..3 {
+            game_logic.start_next_player_turn();
+        }
+        let player1_data = get_player_data(&game_logic, &player1_uuid);
+        assert_eq!(player1_data.gold_history, vec![8, 5]);
+        assert_eq!(player1_data.fortitude_history, vec![20, 20]);
+        assert_eq!(player1_data.alcohol_content_history, vec![0, 0]);
+        assert_eq!(
+            get_player_data(&game_logic, &player2_uuid)
+                .gold_history
+                .len(),
+            2
+        );
+
+        // Only the most recent turns are kept.
+        for _ in 0..30 {
+            game_logic.start_next_player_turn();
+        }
+        assert_eq!(
+            get_player_data(&game_logic, &player1_uuid)
+                .gold_history
+                .len(),
+            MAX_STAT_SNAPSHOTS
+        );
+        assert_eq!(
+            get_player_data(&game_logic, &player2_uuid)
+                .gold_history
+                .len(),
+            MAX_STAT_SNAPSHOTS
+        );
+    }
+
+    #[test]
+    fn player_events_only_include_events_involving_player() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid.clone(), Character::Fiona),
+        ])
+        .unwrap();
+        let play_card = |game_logic: &mut GameLogic,
+                         card: PlayerCard,
+                         player_uuid: &PlayerUUID,
+                         target_player_uuid_or: Option<PlayerUUID>| {
+            game_logic
+                .player_manager
+                .get_player_by_uuid_mut(player_uuid)
+                .unwrap()
+                .return_card_to_hand(card, 0);
+            game_logic
+                .play_card(player_uuid, &target_player_uuid_or, 0)
+                .unwrap();
+        };
+
+        // Player 1 wins a gambling round that everyone antes into.
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        play_card(
+            &mut game_logic,
+            gambling_im_in_card().into(),
             &player1_uuid,
-            &game_logic.gambling_manager,
-            &game_logic.interrupt_manager,
-            &game_logic.turn_info
-        ));
-        assert!(gambling_im_in_card().can_play(
-            &player2_uuid,
-            &game_logic.gambling_manager,
-            &game_logic.interrupt_manager,
-            &game_logic.turn_info
-        ));
+            None,
+        );
+        game_logic.pass(&player2_uuid).unwrap();
+        game_logic.pass(&player3_uuid).unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
+        game_logic.pass(&player3_uuid).unwrap();
+        game_logic.skip_turn(&player1_uuid).unwrap();
+
+        game_logic
+            .discard_cards_and_draw_to_full(&player2_uuid, Vec::new())
+            .unwrap();
+        game_logic.skip_turn(&player2_uuid).unwrap();
+
+        // Player 3 punches player 2, who doesn't respond.
+        game_logic
+            .discard_cards_and_draw_to_full(&player3_uuid, Vec::new())
+            .unwrap();
+        play_card(
+            &mut game_logic,
+            change_other_player_fortitude_card("Punch in the face", -2).into(),
+            &player3_uuid,
+            Some(player2_uuid.clone()),
+        );
+        game_logic.pass(&player2_uuid).unwrap();
+
+        let player2_events = game_logic.get_player_events(&player2_uuid, 100);
+        assert_eq!(
+            player2_events
+                .iter()
+                .map(|event| (event.player_uuid.clone(), event.action))
+                .collect::<Vec<(PlayerUUID, GameEventAction)>>(),
+            vec![
+                (player2_uuid.clone(), GameEventAction::Pass),
+                (player2_uuid.clone(), GameEventAction::Pass),
+                (player2_uuid.clone(), GameEventAction::DiscardCards),
+                (player2_uuid.clone(), GameEventAction::SkipTurn),
+                (player3_uuid.clone(), GameEventAction::PlayCard),
+                (player2_uuid.clone(), GameEventAction::Pass),
+            ]
+        );
+        assert_eq!(
+            player2_events[4].target_player_uuid,
+            Some(player2_uuid.clone())
+        );
+
+        // Only the most recent events are kept.
+        assert_eq!(
+            game_logic.get_player_events(&player2_uuid, 2),
+            player2_events[4..].to_vec()
+        );
+    }
+
+    #[test]
+    fn game_log_records_actions_in_order() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ])
+        .unwrap();
+        assert!(game_logic.get_game_log().is_empty());
+
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, vec![0, 1])
+            .unwrap();
+
+        // Player 1 punches player 2, who doesn't respond.
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .return_card_to_hand(
+                change_other_player_fortitude_card("Punch in the face", -2).into(),
+                0,
+            );
+        game_logic
+            .play_card(&player1_uuid, &Some(player2_uuid.clone()), 0)
+            .unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
+
+        // Failed actions shouldn't be logged.
+        assert!(game_logic
+            .order_drink(&player1_uuid, &player1_uuid)
+            .is_err());
+        game_logic
+            .order_drink(&player1_uuid, &player2_uuid)
+            .unwrap();
+
+        assert_eq!(
+            game_logic.get_game_log(),
+            &vec![
+                GameEvent {
+                    player_uuid: player1_uuid.clone(),
+                    action: GameEventAction::DiscardCards,
+                    target_player_uuid: None,
+                    card_name: None,
+                    card_count: Some(2),
+                    card_indices: vec![1, 0],
+                },
+                GameEvent {
+                    player_uuid: player1_uuid.clone(),
+                    action: GameEventAction::PlayCard,
+                    target_player_uuid: Some(player2_uuid.clone()),
+                    card_name: Some("Punch in the face".to_string()),
+                    card_count: None,
+                    card_indices: vec![0],
+                },
+                GameEvent {
+                    player_uuid: player2_uuid.clone(),
+                    action: GameEventAction::Pass,
+                    target_player_uuid: None,
+                    card_name: None,
+                    card_count: None,
+                    card_indices: Vec::new(),
+                },
+                GameEvent {
+                    player_uuid: player1_uuid,
+                    action: GameEventAction::OrderDrink,
+                    target_player_uuid: Some(player2_uuid),
+                    card_name: None,
+                    card_count: None,
+                    card_indices: Vec::new(),
+                },
+            ]
+        );
     }
 
     #[test]
-    fn can_handle_change_other_player_fortitude_card() {
+    fn players_who_go_broke_at_once_draw() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
-        let player3_uuid = PlayerUUID::new();
 
         let mut game_logic = GameLogic::new(vec![
             (player1_uuid.clone(), Character::Deirdre),
             (player2_uuid.clone(), Character::Gerki),
-            (player3_uuid.clone(), Character::Fiona),
         ])
         .unwrap();
-        game_logic
-            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
-            .unwrap();
-
-        // Sanity check.
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
 
-        // Player 1 attempts to hurt player 2.
-        assert!(game_logic
-            .process_card(
-                change_other_player_fortitude_card("Punch in the face", -2).into(),
-                &player1_uuid,
-                &Some(player2_uuid.clone())
-            )
-            .is_ok());
-
-        // Sanity check.
-        assert_eq!(
+        // Both players lose their last gold at the same time.
+        for player_uuid in [&player1_uuid, &player2_uuid] {
             game_logic
                 .player_manager
-                .get_player_by_uuid(&player2_uuid)
+                .get_player_by_uuid_mut(player_uuid)
                 .unwrap()
-                .get_fortitude(),
-            20
-        );
-        assert!(game_logic.interrupt_manager.interrupt_in_progress());
-
-        // Player 2 chooses not to play an interrupt card.
-        assert!(game_logic
-            .interrupt_manager
-            .is_turn_to_interrupt(&player2_uuid));
-        game_logic.pass(&player2_uuid).unwrap();
-        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+                .change_gold(-8, "Test");
+        }
+        game_logic.player_manager.record_eliminations();
 
-        // Fortitude should be reduced.
+        assert!(!game_logic.is_running());
+        assert_eq!(game_logic.get_winner_or(), None);
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_fortitude(),
-            18
+            game_logic.get_standings(),
+            vec![vec![player1_uuid.clone(), player2_uuid]]
         );
-
-        // Fortitude for other player should remain unchanged.
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player3_uuid)
-                .unwrap()
-                .get_fortitude(),
-            20
+            game_logic.discard_cards_and_draw_to_full(&player1_uuid, Vec::new()),
+            Err(Error::new("Game must be running to perform this action"))
         );
-
-        // Should proceed to player 1's order drink phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
     }
 
     #[test]
-    fn can_handle_change_all_other_player_fortitude_card() {
+    fn skipping_turn_advances_to_next_player() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
-        let player3_uuid = PlayerUUID::new();
 
         let mut game_logic = GameLogic::new(vec![
             (player1_uuid.clone(), Character::Deirdre),
             (player2_uuid.clone(), Character::Gerki),
-            (player3_uuid.clone(), Character::Fiona),
         ])
         .unwrap();
-        game_logic
-            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
-            .unwrap();
-
-        // Sanity check.
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
-
-        // Player 1 attempts to hurt all other players.
-        assert!(game_logic
-            .process_card(
-                change_all_other_player_fortitude_card("Punch everyone in the face", -2).into(),
-                &player1_uuid,
-                &None
-            )
-            .is_ok());
-
-        // Sanity check.
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_fortitude(),
-            20
+            game_logic.skip_turn(&player1_uuid),
+            Err(Error::new(
+                "Turn can only be skipped during the action or order drinks phase (current phase: DiscardAndDraw)"
+            ))
         );
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player3_uuid)
-                .unwrap()
-                .get_fortitude(),
-            20
+            game_logic.skip_turn(&player2_uuid),
+            Err(Error::new("It is not your turn"))
         );
-        assert!(game_logic.interrupt_manager.interrupt_in_progress());
-
-        // Player 2 chooses not to play an interrupt card.
-        assert!(game_logic
-            .interrupt_manager
-            .is_turn_to_interrupt(&player2_uuid));
-        game_logic.pass(&player2_uuid).unwrap();
-        assert!(game_logic.interrupt_manager.interrupt_in_progress());
 
-        // Fortitude should be reduced.
+        // Skip from player 1's action phase.
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Action);
+        assert_eq!(game_logic.skip_turn(&player1_uuid), Ok(()));
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
-                .unwrap()
-                .get_fortitude(),
-            18
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player2_uuid
         );
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
 
-        // Player 3 plays an interrupt card.
-        assert!(game_logic
-            .interrupt_manager
-            .is_turn_to_interrupt(&player3_uuid));
-        assert!(game_logic
-            .process_card(
-                ignore_root_card_affecting_fortitude("Block punch").into(),
-                &player3_uuid,
-                &None
-            )
-            .is_ok());
-        // Player 1 stops the interrupt.
-        assert!(game_logic
-            .process_card(i_dont_think_so_card().into(), &player1_uuid, &None)
-            .is_ok());
-        assert!(game_logic
-            .interrupt_manager
-            .is_turn_to_interrupt(&player2_uuid));
+        // Skip from player 2's order drinks phase without ordering a drink.
+        game_logic
+            .discard_cards_and_draw_to_full(&player2_uuid, Vec::new())
+            .unwrap();
         game_logic.pass(&player2_uuid).unwrap();
-        assert!(game_logic
-            .interrupt_manager
-            .is_turn_to_interrupt(&player3_uuid));
-        game_logic.pass(&player3_uuid).unwrap();
-        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
-
-        // Fortitude should be reduced.
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+        assert_eq!(game_logic.skip_turn(&player2_uuid), Ok(()));
+        assert_eq!(
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player1_uuid
+        );
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
         assert_eq!(
             game_logic
-                .player_manager
-                .get_player_by_uuid(&player3_uuid)
+                .get_game_view_player_data_or(&player1_uuid)
                 .unwrap()
-                .get_fortitude(),
-            18
+                .drink_me_pile_size,
+            0
         );
-
-        // Should proceed to player 1's order drink phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
     }
 
     #[test]
-    fn cannot_play_directed_card_on_self() {
+    fn skipping_turn_still_drinks_from_drink_me_pile() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
         let mut game_logic = GameLogic::new(vec![
             (player1_uuid.clone(), Character::Deirdre),
-            (player2_uuid, Character::Gerki),
+            (player2_uuid.clone(), Character::Gerki),
         ])
         .unwrap();
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .add_drink_to_drink_pile(create_simple_ale_test_drink(false).into());
         game_logic
             .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
             .unwrap();
 
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
-
-        // Player 1 attempts to hurt self.
+        assert_eq!(game_logic.skip_turn(&player1_uuid), Ok(()));
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Drink);
         assert_eq!(
             game_logic
-                .process_card(
-                    change_other_player_fortitude_card("Punch in the face", -2).into(),
-                    &player1_uuid,
-                    &Some(player1_uuid.clone())
-                )
-                .unwrap_err()
-                .1,
-            Error::new("Must not direct this card at yourself")
+                .get_game_view_player_data_or(&player1_uuid)
+                .unwrap()
+                .drink_me_pile_size,
+            0
         );
 
-        // Should stay at player 1's action phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Action);
+        // Nobody interrupts the drink, so player 1 drinks it.
+        game_logic.pass(&player1_uuid).unwrap();
+        game_logic.pass(&player2_uuid).unwrap();
+        game_logic.pass(&player1_uuid).unwrap();
+        assert_eq!(
+            game_logic
+                .get_game_view_player_data_or(&player1_uuid)
+                .unwrap()
+                .alcohol_content,
+            1
+        );
+        assert_eq!(
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player2_uuid
+        );
     }
 
     #[test]
-    fn can_handle_interrupted_change_other_player_fortitude_card() {
+    fn apply_turn_plays_out_whole_turn() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
@@ -1695,62 +5737,41 @@ mod tests {
             (player2_uuid.clone(), Character::Gerki),
         ])
         .unwrap();
-        game_logic
-            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
-            .unwrap();
 
-        // Sanity check.
+        assert_eq!(
+            game_logic.apply_turn(
+                &player1_uuid,
+                TurnScript {
+                    discard_card_indices: vec![0, 1],
+                    cards_to_play: Vec::new(),
+                    drink_target_uuids: vec![player2_uuid.clone()],
+                },
+            ),
+            Ok(())
+        );
         assert_eq!(
             game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .get_gold(),
-            8
+                .get_game_log()
+                .iter()
+                .map(|game_event| game_event.action)
+                .collect::<Vec<GameEventAction>>(),
+            vec![
+                GameEventAction::DiscardCards,
+                GameEventAction::Pass,
+                GameEventAction::OrderDrink
+            ]
         );
         assert_eq!(
             game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
+                .get_game_view_player_data_or(&player2_uuid)
                 .unwrap()
-                .get_gold(),
-            8
+                .drink_me_pile_size,
+            1
         );
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
-
-        // Reduce player 2's fortitude to ensure that it is properly restored.
-        game_logic
-            .player_manager
-            .get_player_by_uuid_mut(&player2_uuid)
-            .unwrap()
-            .change_fortitude(-2);
-
-        assert!(game_logic
-            .process_card(
-                change_other_player_fortitude_card("Punch in the face", -2).into(),
-                &player1_uuid,
-                &Some(player2_uuid.clone())
-            )
-            .is_ok());
-
-        assert!(gain_fortitude_anytime_card("Heal", 1).can_play(
-            &player1_uuid,
-            &game_logic.gambling_manager,
-            &game_logic.interrupt_manager,
-            &game_logic.turn_info
-        ));
-        assert!(game_logic
-            .process_card(
-                gain_fortitude_anytime_card("Heal", 1).into(),
-                &player1_uuid,
-                &None
-            )
-            .is_ok());
     }
 
     #[test]
-    fn can_gain_fortitude_during_game_interrupt() {
+    fn apply_turn_with_invalid_middle_step_leaves_game_unchanged() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
@@ -1758,53 +5779,48 @@ mod tests {
             (player1_uuid.clone(), Character::Deirdre),
             (player2_uuid.clone(), Character::Gerki),
         ])
-        .unwrap();
-        game_logic
-            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
-            .unwrap();
-
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
-
-        assert!(game_logic
-            .process_card(
-                change_other_player_fortitude_card("Punch in the face", -2).into(),
-                &player1_uuid,
-                &Some(player2_uuid.clone())
-            )
-            .is_ok());
+        .unwrap();
+        let game_logic_before = format!("{:?}", game_logic);
+        let hand_before = game_logic.get_game_view_player_hand(&player1_uuid);
 
-        // Player 2 plays an interrupt card.
-        assert!(game_logic
-            .interrupt_manager
-            .is_turn_to_interrupt(&player2_uuid));
-        assert!(game_logic
-            .process_card(
-                ignore_root_card_affecting_fortitude("Block punch").into(),
-                &player2_uuid,
-                &None
-            )
-            .is_ok());
-        // Player 1 chooses not to play a countering interrupt card.
-        assert!(game_logic
-            .interrupt_manager
-            .is_turn_to_interrupt(&player1_uuid));
-        game_logic.pass(&player1_uuid).unwrap();
-        assert!(!game_logic.interrupt_manager.interrupt_in_progress());
+        // The discard is valid, but there is no card to play at index 100.
+        assert_eq!(
+            game_logic.apply_turn(
+                &player1_uuid,
+                TurnScript {
+                    discard_card_indices: vec![0, 1],
+                    cards_to_play: vec![(100, None)],
+                    drink_target_uuids: vec![player2_uuid.clone()],
+                },
+            ),
+            Err(Error::new("Card does not exist"))
+        );
 
-        // Fortitude should not be reduced.
+        assert_eq!(format!("{:?}", game_logic), game_logic_before);
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+        assert!(game_logic.get_game_log().is_empty());
         assert_eq!(
             game_logic
-                .player_manager
-                .get_player_by_uuid(&player2_uuid)
+                .get_game_view_player_hand(&player1_uuid)
+                .iter()
+                .map(|card| card.card_name.clone())
+                .collect::<Vec<String>>(),
+            hand_before
+                .iter()
+                .map(|card| card.card_name.clone())
+                .collect::<Vec<String>>()
+        );
+        assert_eq!(
+            game_logic
+                .get_game_view_player_data_or(&player2_uuid)
                 .unwrap()
-                .get_fortitude(),
-            20
+                .drink_me_pile_size,
+            0
         );
     }
 
     #[test]
-    fn can_order_drinks_after_action_phase() {
+    fn player_can_undo_pass() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
@@ -1814,26 +5830,42 @@ mod tests {
         ])
         .unwrap();
         game_logic
-            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .perform_idempotent_action(&player1_uuid, None, |game_logic| {
+                game_logic.discard_cards_and_draw_to_full(&player1_uuid, vec![0, 1])
+            })
             .unwrap();
+        // Drawing cards can't be undone.
+        assert_eq!(
+            game_logic.undo(&player1_uuid),
+            Err(Error::new("There is no action to undo"))
+        );
 
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
-
-        // Player 1 skips their action phase.
-        assert!(game_logic.pass(&player1_uuid).is_ok());
-
-        // Should proceed to player 1's order drink phase.
+        game_logic
+            .perform_idempotent_action(&player1_uuid, None, |game_logic| {
+                game_logic.pass(&player1_uuid)
+            })
+            .unwrap();
         assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+        let game_log_len = game_logic.get_game_log().len();
 
-        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+        // Only the player who passed can undo it.
+        assert_eq!(
+            game_logic.undo(&player2_uuid),
+            Err(Error::new("There is no action to undo"))
+        );
+        assert_eq!(game_logic.undo(&player1_uuid), Ok(()));
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Action);
+        assert_eq!(game_logic.get_game_log().len(), game_log_len - 1);
 
-        // Should proceed to player 2's discard phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+        // Only a single action can be undone.
+        assert_eq!(
+            game_logic.undo(&player1_uuid),
+            Err(Error::new("There is no action to undo"))
+        );
     }
 
     #[test]
-    fn can_order_multiple_drinks() {
+    fn failed_action_keeps_previous_undo_snapshot() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
@@ -1845,34 +5877,31 @@ mod tests {
         game_logic
             .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
             .unwrap();
-
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
-
-        // Player 1 skips their action phase.
-        assert!(game_logic.pass(&player1_uuid).is_ok());
-
-        // Should proceed to player 1's order drink phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
-
+        game_logic
+            .perform_idempotent_action(&player1_uuid, None, |game_logic| {
+                game_logic.pass(&player1_uuid)
+            })
+            .unwrap();
+        // Snapshots never hold on to the snapshot that came before them.
         assert!(game_logic
-            .process_card(
-                wench_bring_some_drinks_for_my_friends_card().into(),
-                &player1_uuid,
-                &None
-            )
-            .is_ok());
-
-        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
-        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
-        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+            .undo_snapshot_or
+            .as_ref()
+            .unwrap()
+            .game_logic
+            .undo_snapshot_or
+            .is_none());
 
-        // Should proceed to player 2's discard phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+        assert!(game_logic
+            .perform_idempotent_action(&player1_uuid, None, |game_logic| {
+                game_logic.discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            })
+            .is_err());
+        assert_eq!(game_logic.undo(&player1_uuid), Ok(()));
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Action);
     }
 
     #[test]
-    fn player_drinks_top_drink_after_ordering_drinks() {
+    fn player_cannot_undo_once_turn_has_advanced() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
@@ -1884,78 +5913,28 @@ mod tests {
         game_logic
             .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
             .unwrap();
-
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
-
-        // Player 1 skips their action phase.
-        assert!(game_logic.pass(&player1_uuid).is_ok());
-
-        // Should proceed to player 1's order drink phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
-
-        // Order drink for next player.
         game_logic
-            .player_manager
-            .get_player_by_uuid_mut(&player1_uuid)
-            .unwrap()
-            .add_drink_to_drink_pile(create_simple_ale_test_drink(false).into());
-        let player1_drink_me_pile_size = game_logic
-            .player_manager
-            .get_player_by_uuid(&player1_uuid)
-            .unwrap()
-            .to_game_view_player_data(player1_uuid.clone())
-            .drink_me_pile_size;
-        let player1_alcohol_content = game_logic
-            .player_manager
-            .get_player_by_uuid(&player1_uuid)
-            .unwrap()
-            .to_game_view_player_data(player1_uuid.clone())
-            .alcohol_content;
-        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
-
-        // Should proceed to player 1's drink phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Drink);
+            .perform_idempotent_action(&player1_uuid, None, |game_logic| {
+                game_logic.skip_turn(&player1_uuid)
+            })
+            .unwrap();
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .to_game_view_player_data(player1_uuid.clone())
-                .drink_me_pile_size,
-            player1_drink_me_pile_size - 1
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player2_uuid
         );
-        assert!(game_logic.player_can_pass(&player1_uuid));
-        game_logic.pass(&player1_uuid).unwrap();
-        assert!(game_logic.player_can_pass(&player2_uuid));
-        game_logic.pass(&player2_uuid).unwrap();
+
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .to_game_view_player_data(player1_uuid.clone())
-                .alcohol_content,
-            player1_alcohol_content
+            game_logic.undo(&player1_uuid),
+            Err(Error::new("There is no action to undo"))
         );
-        assert!(game_logic.player_can_pass(&player1_uuid));
-        game_logic.pass(&player1_uuid).unwrap();
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .to_game_view_player_data(player1_uuid.clone())
-                .alcohol_content,
-            player1_alcohol_content + 1
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player2_uuid
         );
-
-        // Should proceed to player 2's discard phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
     }
 
     #[test]
-    fn player_can_ignore_drink() {
+    fn opponent_wins_when_player_forfeits() {
         let player1_uuid = PlayerUUID::new();
         let player2_uuid = PlayerUUID::new();
 
@@ -1964,76 +5943,55 @@ mod tests {
             (player2_uuid.clone(), Character::Gerki),
         ])
         .unwrap();
-        game_logic
-            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
-            .unwrap();
-
-        assert!(!game_logic.gambling_manager.round_in_progress());
-        assert_eq!(game_logic.turn_info.turn_phase, TurnPhase::Action);
+        assert!(game_logic.is_running());
 
-        // Player 1 skips their action phase.
-        assert!(game_logic.pass(&player1_uuid).is_ok());
+        // Player 1 forfeits on their own turn.
+        assert_eq!(game_logic.forfeit(&player1_uuid), Ok(()));
+        assert!(!game_logic.is_running());
+        assert_eq!(game_logic.get_winner_or(), Some(player2_uuid));
+        assert_eq!(
+            game_logic.get_eliminated_player_uuids(),
+            vec![player1_uuid.clone()]
+        );
+        assert_eq!(
+            game_logic.forfeit(&player1_uuid),
+            Err(Error::new("Game must be running to perform this action"))
+        );
+    }
 
-        // Should proceed to player 1's order drink phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+    #[test]
+    fn turn_advances_when_current_player_forfeits() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
 
-        // Order drink for next player.
-        game_logic
-            .player_manager
-            .get_player_by_uuid_mut(&player1_uuid)
-            .unwrap()
-            .add_drink_to_drink_pile(create_simple_ale_test_drink(false).into());
-        let player1_drink_me_pile_size = game_logic
-            .player_manager
-            .get_player_by_uuid(&player1_uuid)
-            .unwrap()
-            .to_game_view_player_data(player1_uuid.clone())
-            .drink_me_pile_size;
-        let player1_alcohol_content = game_logic
-            .player_manager
-            .get_player_by_uuid(&player1_uuid)
-            .unwrap()
-            .to_game_view_player_data(player1_uuid.clone())
-            .alcohol_content;
-        assert!(game_logic.order_drink(&player1_uuid, &player2_uuid).is_ok());
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid.clone(), Character::Fiona),
+        ])
+        .unwrap();
 
-        // Should proceed to player 1's drink phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::Drink);
+        // Player 3 forfeits out of turn.
+        assert_eq!(game_logic.forfeit(&player3_uuid), Ok(()));
+        assert!(game_logic.is_running());
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .to_game_view_player_data(player1_uuid.clone())
-                .drink_me_pile_size,
-            player1_drink_me_pile_size - 1
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player1_uuid
         );
-        assert!(game_logic.player_can_pass(&player1_uuid));
-        game_logic.pass(&player1_uuid).unwrap();
-        assert!(game_logic.player_can_pass(&player2_uuid));
-        game_logic.pass(&player2_uuid).unwrap();
-        assert!(game_logic
-            .process_card(
-                ignore_drink_card("Ignore Drink").into(),
-                &player1_uuid,
-                &None
-            )
-            .is_ok());
-        // Player 2 passes on the chance to interrupt player 1's 'Ignore Drink' card.
-        assert!(game_logic.player_can_pass(&player2_uuid));
-        game_logic.pass(&player2_uuid).unwrap();
         assert_eq!(
-            game_logic
-                .player_manager
-                .get_player_by_uuid(&player1_uuid)
-                .unwrap()
-                .to_game_view_player_data(player1_uuid.clone())
-                .alcohol_content,
-            player1_alcohol_content
+            game_logic.forfeit(&player3_uuid),
+            Err(Error::new("Player is already out of the game"))
         );
 
-        // Should proceed to player 2's discard phase.
-        assert_eq!(game_logic.get_turn_phase(), TurnPhase::DiscardAndDraw);
+        // Player 1 forfeits on their own turn.
+        assert_eq!(game_logic.forfeit(&player1_uuid), Ok(()));
+        assert_eq!(
+            game_logic.get_turn_info().get_current_player_turn(),
+            &player2_uuid
+        );
+        assert!(!game_logic.is_running());
+        assert_eq!(game_logic.get_winner_or(), Some(player2_uuid));
     }
 
     #[test]
@@ -2067,6 +6025,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cannot_order_drinks_for_player_who_is_out_of_game() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        let mut game_logic = GameLogic::new(vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+            (player3_uuid.clone(), Character::Fiona),
+        ])
+        .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        game_logic.pass(&player1_uuid).unwrap();
+        game_logic
+            .player_manager
+            .get_player_by_uuid_mut(&player2_uuid)
+            .unwrap()
+            .forfeit();
+
+        assert_eq!(
+            game_logic.order_drink(&player1_uuid, &player2_uuid),
+            Err(Error::new(
+                "Cannot order drink for a player who is out of the game"
+            ))
+        );
+        assert_eq!(
+            game_logic.order_drinks(&player1_uuid, vec![player2_uuid.clone()]),
+            Err(Error::new(
+                "Cannot order drink for a player who is out of the game"
+            ))
+        );
+        assert_eq!(game_logic.turn_info.drinks_to_order, 1);
+        assert_eq!(game_logic.get_turn_phase(), TurnPhase::OrderDrinks);
+
+        // Players still in the game can be ordered for.
+        assert!(game_logic.order_drink(&player1_uuid, &player3_uuid).is_ok());
+    }
+
     #[test]
     fn test_rotate_player_vec_to_start_with_player() {
         let player1_uuid = PlayerUUID::new();
@@ -1,4 +1,7 @@
-use super::drink::{DrinkCard, DrinkWithPossibleChasers};
+use super::deck::AutoShufflingDeck;
+use super::drink::{
+    get_drink_with_possible_chasers_skipping_drink_events, DrinkCard, DrinkWithPossibleChasers,
+};
 use super::gambling_manager::GamblingManager;
 use super::game_logic::TurnInfo;
 use super::player_card::{
@@ -6,7 +9,8 @@ use super::player_card::{
 };
 use super::player_manager::{NextPlayerUUIDOption, PlayerManager};
 use super::player_view::{
-    GameViewInterruptData, GameViewInterruptStack, GameViewInterruptStackRootItem,
+    GameViewDrinkPreview, GameViewInterruptData, GameViewInterruptStack,
+    GameViewInterruptStackRootItem,
 };
 use super::uuid::PlayerUUID;
 use super::Error;
@@ -28,11 +32,24 @@ impl InterruptManager {
         self.interrupt_stacks.first()?.get_current_interrupt()
     }
 
-    fn get_current_interrupt_turn_or(&self) -> Option<&PlayerUUID> {
+    pub fn get_current_interrupt_turn_or(&self) -> Option<&PlayerUUID> {
         Some(self.interrupt_stacks.first()?.get_current_interrupt_turn())
     }
 
-    pub fn get_game_view_interrupt_data_or(&self) -> Option<GameViewInterruptData> {
+    /// Whether players are waiting to ante, even if other cards have since been played in response.
+    pub fn is_resolving_ante(&self) -> bool {
+        self.interrupt_stacks.iter().any(|interrupt_stack| {
+            matches!(
+                interrupt_stack.get_current_root_interrupt_or(),
+                Some(GameInterruptType::AboutToAnte)
+            )
+        })
+    }
+
+    pub fn get_game_view_interrupt_data_or(
+        &self,
+        player_manager: &PlayerManager,
+    ) -> Option<GameViewInterruptData> {
         let current_interrupt_turn = match self.get_current_interrupt_turn_or() {
             Some(current_interrupt_turn) => current_interrupt_turn.clone(),
             None => return None,
@@ -57,11 +74,14 @@ impl InterruptManager {
                                 .get_display_name()
                                 .to_string(),
                             item_type: String::from("rootPlayerCard"),
+                            drink_preview: None,
                         }
                     }
                     InterruptRoot::Drink(drink_with_owner) => GameViewInterruptStackRootItem {
                         name: drink_with_owner.drink.get_display_name(),
                         item_type: String::from("drinkEvent"),
+                        drink_preview: interrupt_stack
+                            .get_drink_preview_or(&drink_with_owner.drink, player_manager),
                     },
                 },
                 interrupt_card_names,
@@ -74,6 +94,56 @@ impl InterruptManager {
         })
     }
 
+    /// Describes every interrupt stack, its sessions and their interrupt cards. Sessions are listed
+    /// in the order they will resolve, while cards are listed in the order they were played.
+    #[cfg(test)]
+    pub fn debug_interrupt_state(&self) -> String {
+        let current_interrupt_turn = match self.get_current_interrupt_turn_or() {
+            Some(current_interrupt_turn) => current_interrupt_turn,
+            None => return String::from("No interrupt in progress"),
+        };
+
+        let mut lines = vec![format!(
+            "{} interrupt stack(s), current interrupt turn: {}",
+            self.interrupt_stacks.len(),
+            current_interrupt_turn.to_string()
+        )];
+        for (stack_index, interrupt_stack) in self.interrupt_stacks.iter().enumerate() {
+            let root_name = match &interrupt_stack.root {
+                InterruptRoot::RootPlayerCard(root_player_card_with_owner) => {
+                    root_player_card_with_owner
+                        .root_card
+                        .get_display_name()
+                        .to_string()
+                }
+                InterruptRoot::Drink(drink_with_owner) => drink_with_owner.drink.get_display_name(),
+            };
+            lines.push(format!(
+                "Stack {}: root \"{}\", {} session(s), current interrupt turn: {}",
+                stack_index,
+                root_name,
+                interrupt_stack.sessions.len(),
+                interrupt_stack.get_current_interrupt_turn().to_string()
+            ));
+            for (session_index, session) in interrupt_stack.sessions.iter().rev().enumerate() {
+                let interrupt_card_names: Vec<String> = session
+                    .interrupt_cards
+                    .iter()
+                    .map(|interrupt_card| format!("\"{}\"", interrupt_card.card.get_display_name()))
+                    .collect();
+                lines.push(format!(
+                    "  Session {}: {:?} targeting {}, {} interrupt card(s): [{}]",
+                    session_index,
+                    session.root_card_interrupt_type,
+                    session.primary_targeted_player_uuid.to_string(),
+                    interrupt_card_names.len(),
+                    interrupt_card_names.join(", ")
+                ));
+            }
+        }
+        lines.join("\n")
+    }
+
     pub fn start_single_player_root_player_card_interrupt(
         &mut self,
         root_card: RootPlayerCard,
@@ -184,7 +254,20 @@ impl InterruptManager {
         }
     }
 
+    /// Queues up a separate drink interrupt for each player, which are resolved one after another
+    /// in the given order. Each player gets their own chance to modify or avoid their drink.
     pub fn start_multi_player_drink_interrupt(
+        &mut self,
+        drinks_by_player: Vec<(PlayerUUID, DrinkWithPossibleChasers)>,
+    ) {
+        for (targeted_player_uuid, drink) in drinks_by_player {
+            self.start_single_player_drink_interrupt(drink, targeted_player_uuid);
+        }
+    }
+
+    /// Creates a single drink interrupt where the drink affects the targeted player and every
+    /// secondary player when it's consumed, such as during a `Round on the House` drink event.
+    pub fn start_shared_drink_interrupt(
         &mut self,
         drink: DrinkWithPossibleChasers,
         targeted_player_uuid: PlayerUUID,
@@ -219,6 +302,7 @@ impl InterruptManager {
         player_manager: &mut PlayerManager,
         gambling_manager: &mut GamblingManager,
         turn_info: &mut TurnInfo,
+        drink_deck: &mut AutoShufflingDeck<DrinkCard>,
     ) -> Result<Option<InterruptStackResolveData>, (InterruptPlayerCard, Error)> {
         if !self.is_turn_to_interrupt(&player_uuid) {
             return Err((
@@ -228,7 +312,13 @@ impl InterruptManager {
         }
         match self.push_to_current_stack(card, player_uuid) {
             Ok(_) => Ok(self
-                .increment_player_turn(player_manager, gambling_manager, turn_info, false)
+                .increment_player_turn(
+                    player_manager,
+                    gambling_manager,
+                    turn_info,
+                    drink_deck,
+                    false,
+                )
                 .unwrap()),
             Err(err) => Err(err),
         }
@@ -247,8 +337,15 @@ impl InterruptManager {
         player_manager: &mut PlayerManager,
         gambling_manager: &mut GamblingManager,
         turn_info: &mut TurnInfo,
+        drink_deck: &mut AutoShufflingDeck<DrinkCard>,
     ) -> Result<Option<InterruptStackResolveData>, Error> {
-        self.increment_player_turn(player_manager, gambling_manager, turn_info, true)
+        self.increment_player_turn(
+            player_manager,
+            gambling_manager,
+            turn_info,
+            drink_deck,
+            true,
+        )
     }
 
     fn increment_player_turn(
@@ -256,8 +353,19 @@ impl InterruptManager {
         player_manager: &mut PlayerManager,
         gambling_manager: &mut GamblingManager,
         turn_info: &mut TurnInfo,
+        drink_deck: &mut AutoShufflingDeck<DrinkCard>,
         is_passing: bool,
     ) -> Result<Option<InterruptStackResolveData>, Error> {
+        // A stack without any sessions has nothing to resolve, so the interrupt turn would
+        // otherwise keep going around the table forever.
+        if let Some(current_stack) = self.interrupt_stacks.first() {
+            if current_stack.get_current_session().is_none() {
+                return Err(Error::new(
+                    "Game interrupt stack has no session to resolve - this is an internal error",
+                ));
+            }
+        }
+
         let current_stack_session_is_only_interruptable_by_targeted_player =
             if let Some(current_stack) = self.interrupt_stacks.first() {
                 if let Some(current_session) = current_stack.get_current_session() {
@@ -286,6 +394,7 @@ impl InterruptManager {
                         player_manager,
                         gambling_manager,
                         turn_info,
+                        drink_deck,
                     ) {
                         Ok(interrupt_stack_resolve_data) => Ok(Some(interrupt_stack_resolve_data)),
                         Err(err) => Err(err),
@@ -299,7 +408,7 @@ impl InterruptManager {
                     // looped back around to the last player who played a card, then
                     // that ends the interrupt stack since that player was uninterrupted.
                     if Some(next_player_uuid) == self.get_last_player_to_play_on_current_stack() {
-                        match self.resolve_current_stack_session(player_manager, gambling_manager, turn_info) {
+                        match self.resolve_current_stack_session(player_manager, gambling_manager, turn_info, drink_deck) {
                             Ok(interrupt_stack_resolve_data) => Ok(Some(interrupt_stack_resolve_data)),
                             Err(err) => Err(err)
                         }
@@ -314,7 +423,7 @@ impl InterruptManager {
                     Err(Error::new("Uh oh! Failed to increment player turn. This is an internal error, due to some sort of bug."))
                 },
                 NextPlayerUUIDOption::OnlyPlayerLeft => {
-                    match self.resolve_current_stack_session(player_manager, gambling_manager, turn_info) {
+                    match self.resolve_current_stack_session(player_manager, gambling_manager, turn_info, drink_deck) {
                         Ok(interrupt_stack_resolve_data) => Ok(Some(interrupt_stack_resolve_data)),
                         Err(err) => Err(err)
                     }
@@ -331,6 +440,7 @@ impl InterruptManager {
         player_manager: &mut PlayerManager,
         gambling_manager: &mut GamblingManager,
         turn_info: &mut TurnInfo,
+        drink_deck: &mut AutoShufflingDeck<DrinkCard>,
     ) -> Result<InterruptStackResolveData, Error> {
         if self.interrupt_stacks.is_empty() {
             return Err(Error::new("No stacks to resolve"));
@@ -341,8 +451,18 @@ impl InterruptManager {
         let mut spent_interrupt_cards = Vec::new();
 
         let mut should_cancel_root_card = ShouldCancelPreviousCard::No;
+        let mut should_redirect_drink = false;
+        let mut should_swap_drink = false;
 
-        let mut session = current_stack.sessions.pop().unwrap(); // TODO - Handle this unwrap.
+        let mut session = match current_stack.sessions.pop() {
+            Some(session) => session,
+            None => {
+                self.interrupt_stacks.insert(0, current_stack);
+                return Err(Error::new(
+                    "Game interrupt stack has no session to resolve - this is an internal error",
+                ));
+            }
+        };
 
         while let Some(game_interrupt_data) = session.interrupt_cards.pop() {
             match game_interrupt_data.card.interrupt(
@@ -370,6 +490,8 @@ impl InterruptManager {
                         should_cancel_root_card = ShouldCancelPreviousCard::Ignore;
                     }
                 }
+                ShouldCancelPreviousCard::RedirectDrink => should_redirect_drink = true,
+                ShouldCancelPreviousCard::SwapDrink => should_swap_drink = true,
                 ShouldCancelPreviousCard::No => {}
             };
             spent_interrupt_cards.push((
@@ -418,8 +540,10 @@ impl InterruptManager {
                     })
                 }
             }
-            ShouldCancelPreviousCard::No => {
-                match &current_stack.root {
+            ShouldCancelPreviousCard::RedirectDrink
+            | ShouldCancelPreviousCard::SwapDrink
+            | ShouldCancelPreviousCard::No => {
+                match &mut current_stack.root {
                     InterruptRoot::RootPlayerCard(root_player_card_with_interrupt_data) => {
                         root_player_card_with_interrupt_data
                             .root_card
@@ -454,6 +578,33 @@ impl InterruptManager {
                         }
                     }
                     InterruptRoot::Drink(drink_with_interrupt_data) => {
+                        if should_swap_drink {
+                            // If the drink deck is empty, the original drink is kept.
+                            if let Some((swapped_drink, skipped_drink_events)) =
+                                get_drink_with_possible_chasers_skipping_drink_events(drink_deck)
+                            {
+                                let original_drink = std::mem::replace(
+                                    &mut drink_with_interrupt_data.drink,
+                                    swapped_drink,
+                                );
+                                for drink_card in original_drink.take_all_discardable_drink_cards()
+                                {
+                                    drink_deck.discard_card(drink_card);
+                                }
+                                for drink_event in skipped_drink_events {
+                                    drink_deck.discard_card(drink_event.into());
+                                }
+                            }
+                        }
+
+                        if should_redirect_drink {
+                            if let NextPlayerUUIDOption::Some(next_player_uuid) = player_manager
+                                .get_next_alive_player_uuid(&session.primary_targeted_player_uuid)
+                            {
+                                session.primary_targeted_player_uuid = next_player_uuid.clone();
+                            }
+                        }
+
                         if let Some(targeted_player) = player_manager
                             .get_player_by_uuid_mut(&session.primary_targeted_player_uuid)
                         {
@@ -633,6 +784,26 @@ impl GameInterruptStack {
         &self.current_interrupt_turn
     }
 
+    fn get_current_root_interrupt_or(&self) -> Option<GameInterruptType> {
+        Some(self.get_current_session()?.root_card_interrupt_type)
+    }
+
+    /// Works out what the drink will do to the player it targets, based on that player's character.
+    fn get_drink_preview_or(
+        &self,
+        drink: &DrinkWithPossibleChasers,
+        player_manager: &PlayerManager,
+    ) -> Option<GameViewDrinkPreview> {
+        // The `AboutToDrink` session is always first, since it's the last to be resolved.
+        let player_uuid = &self.sessions.first()?.primary_targeted_player_uuid;
+        let player = player_manager.get_player_by_uuid(player_uuid)?;
+        Some(GameViewDrinkPreview {
+            player_uuid: player_uuid.clone(),
+            alcohol_content_change: drink.get_combined_alcohol_content_modifier(player),
+            fortitude_change: drink.get_combined_fortitude_modifier(player),
+        })
+    }
+
     fn push_game_interrupt_data_to_current_stack(
         &mut self,
         game_interrupt_data: GameInterruptData,
@@ -748,10 +919,72 @@ impl InterruptStackResolveData {
 
 #[cfg(test)]
 mod tests {
-    use super::super::player_card::change_other_player_fortitude_card;
-    use super::super::Character;
+    use super::super::drink::{
+        create_drink_deck, create_orcish_rotgut_test_drink, create_simple_ale_test_drink,
+    };
+    use super::super::player::Player;
+    use super::super::player_card::{
+        change_other_player_fortitude_card, i_dont_think_so_card,
+        ignore_root_card_affecting_fortitude,
+    };
+    use super::super::{Character, GameConfig};
     use super::*;
 
+    #[test]
+    fn stack_without_sessions_returns_error_instead_of_panicking() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let mut interrupt_manager = InterruptManager::new();
+        let mut player_manager = PlayerManager::new(vec![
+            (player1_uuid.clone(), Character::Gerki),
+            (player2_uuid.clone(), Character::Deirdre),
+        ]);
+        let mut gambling_manager = GamblingManager::new();
+        let mut turn_info = TurnInfo::new_test(player1_uuid.clone());
+        let mut drink_deck = AutoShufflingDeck::new(create_drink_deck());
+
+        interrupt_manager.interrupt_stacks.push(GameInterruptStack {
+            root: InterruptRoot::RootPlayerCard(RootPlayerCardWithInterruptData {
+                root_card: change_other_player_fortitude_card("Test card", -1),
+                root_card_owner_uuid: player1_uuid,
+            }),
+            current_interrupt_turn: player2_uuid.clone(),
+            sessions: Vec::new(),
+        });
+        let error = Err(Error::new(
+            "Game interrupt stack has no session to resolve - this is an internal error",
+        ));
+
+        // Passing doesn't move the interrupt turn along, since the stack could never resolve.
+        for _ in 0..3 {
+            assert_eq!(
+                interrupt_manager
+                    .pass(
+                        &mut player_manager,
+                        &mut gambling_manager,
+                        &mut turn_info,
+                        &mut drink_deck,
+                    )
+                    .map(|_| ()),
+                error
+            );
+            assert!(interrupt_manager.is_turn_to_interrupt(&player2_uuid));
+        }
+
+        assert_eq!(
+            interrupt_manager
+                .resolve_current_stack_session(
+                    &mut player_manager,
+                    &mut gambling_manager,
+                    &mut turn_info,
+                    &mut drink_deck,
+                )
+                .map(|_| ()),
+            error
+        );
+        assert!(interrupt_manager.interrupt_in_progress());
+    }
+
     #[test]
     fn player_root_player_card_interrupt_ends_after_targeted_player_passes_2_player_game() {
         let player1_uuid = PlayerUUID::new();
@@ -763,6 +996,7 @@ mod tests {
         ]);
         let mut gambling_manager = GamblingManager::new();
         let mut turn_info = TurnInfo::new_test(player1_uuid.clone());
+        let mut drink_deck = AutoShufflingDeck::new(create_drink_deck());
 
         assert!(interrupt_manager
             .start_single_player_root_player_card_interrupt(
@@ -773,7 +1007,12 @@ mod tests {
             .is_ok());
         assert!(interrupt_manager.is_turn_to_interrupt(&player2_uuid));
         assert!(interrupt_manager
-            .pass(&mut player_manager, &mut gambling_manager, &mut turn_info)
+            .pass(
+                &mut player_manager,
+                &mut gambling_manager,
+                &mut turn_info,
+                &mut drink_deck
+            )
             .is_ok());
         assert!(!interrupt_manager.interrupt_in_progress());
     }
@@ -791,6 +1030,7 @@ mod tests {
         ]);
         let mut gambling_manager = GamblingManager::new();
         let mut turn_info = TurnInfo::new_test(player1_uuid.clone());
+        let mut drink_deck = AutoShufflingDeck::new(create_drink_deck());
 
         assert!(interrupt_manager
             .start_single_player_root_player_card_interrupt(
@@ -801,7 +1041,12 @@ mod tests {
             .is_ok());
         assert!(interrupt_manager.is_turn_to_interrupt(&player3_uuid));
         assert!(interrupt_manager
-            .pass(&mut player_manager, &mut gambling_manager, &mut turn_info)
+            .pass(
+                &mut player_manager,
+                &mut gambling_manager,
+                &mut turn_info,
+                &mut drink_deck
+            )
             .is_ok());
         assert!(!interrupt_manager.interrupt_in_progress());
     }
@@ -817,6 +1062,7 @@ mod tests {
         ]);
         let mut gambling_manager = GamblingManager::new();
         let mut turn_info = TurnInfo::new_test(player1_uuid.clone());
+        let mut drink_deck = AutoShufflingDeck::new(create_drink_deck());
 
         interrupt_manager.start_single_player_drink_interrupt(
             DrinkWithPossibleChasers::new(vec![], None),
@@ -825,21 +1071,147 @@ mod tests {
         // All players pass on the chance to modify the drink.
         assert!(interrupt_manager.is_turn_to_interrupt(&player1_uuid));
         assert!(interrupt_manager
-            .pass(&mut player_manager, &mut gambling_manager, &mut turn_info)
+            .pass(
+                &mut player_manager,
+                &mut gambling_manager,
+                &mut turn_info,
+                &mut drink_deck
+            )
             .is_ok());
         assert!(interrupt_manager.is_turn_to_interrupt(&player2_uuid));
         assert!(interrupt_manager
-            .pass(&mut player_manager, &mut gambling_manager, &mut turn_info)
+            .pass(
+                &mut player_manager,
+                &mut gambling_manager,
+                &mut turn_info,
+                &mut drink_deck
+            )
             .is_ok());
         // Targeted player passes on the chance to interrupt the drink.
         assert!(interrupt_manager.is_turn_to_interrupt(&player1_uuid));
         assert!(interrupt_manager
-            .pass(&mut player_manager, &mut gambling_manager, &mut turn_info)
+            .pass(
+                &mut player_manager,
+                &mut gambling_manager,
+                &mut turn_info,
+                &mut drink_deck
+            )
             .is_ok());
 
         assert!(!interrupt_manager.interrupt_in_progress());
     }
 
+    #[test]
+    fn debug_interrupt_state_shows_chain_of_interrupt_cards() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let mut interrupt_manager = InterruptManager::new();
+        let mut player_manager = PlayerManager::new(vec![
+            (player1_uuid.clone(), Character::Gerki),
+            (player2_uuid.clone(), Character::Deirdre),
+        ]);
+        let mut gambling_manager = GamblingManager::new();
+        let mut turn_info = TurnInfo::new_test(player1_uuid.clone());
+        let mut drink_deck = AutoShufflingDeck::new(create_drink_deck());
+
+        assert_eq!(
+            interrupt_manager.debug_interrupt_state(),
+            "No interrupt in progress"
+        );
+
+        // Player 1 punches player 2, who blocks it, and then player 1 counters the block.
+        assert!(interrupt_manager
+            .start_single_player_root_player_card_interrupt(
+                change_other_player_fortitude_card("Punch", -2),
+                player1_uuid.clone(),
+                player2_uuid.clone()
+            )
+            .is_ok());
+        assert!(interrupt_manager
+            .play_interrupt_card(
+                ignore_root_card_affecting_fortitude("Block punch"),
+                player2_uuid.clone(),
+                &mut player_manager,
+                &mut gambling_manager,
+                &mut turn_info,
+                &mut drink_deck
+            )
+            .is_ok());
+        assert!(interrupt_manager
+            .play_interrupt_card(
+                i_dont_think_so_card(),
+                player1_uuid.clone(),
+                &mut player_manager,
+                &mut gambling_manager,
+                &mut turn_info,
+                &mut drink_deck
+            )
+            .is_ok());
+
+        let debug_interrupt_state = interrupt_manager.debug_interrupt_state();
+        let lines: Vec<&str> = debug_interrupt_state.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            format!(
+                "1 interrupt stack(s), current interrupt turn: {}",
+                player2_uuid.to_string()
+            )
+        );
+        assert_eq!(
+            lines[1],
+            format!(
+                "Stack 0: root \"Punch\", 1 session(s), current interrupt turn: {}",
+                player2_uuid.to_string()
+            )
+        );
+        assert!(lines[2].starts_with("  Session 0: DirectedActionCardPlayed("));
+        assert!(lines[2].ends_with(&format!(
+            "targeting {}, 2 interrupt card(s): [\"Block punch\", \"I don't think so!\"]",
+            player2_uuid.to_string()
+        )));
+    }
+
+    #[test]
+    fn drink_preview_depends_on_who_is_drinking() {
+        let orc_player_uuid = PlayerUUID::new();
+        let human_player_uuid = PlayerUUID::new();
+        let mut player_manager = PlayerManager::new(vec![
+            (orc_player_uuid.clone(), Character::Gerki),
+            (human_player_uuid.clone(), Character::Deirdre),
+        ]);
+        *player_manager
+            .get_player_by_uuid_mut(&orc_player_uuid)
+            .unwrap() = Player::create_test_orc(&GameConfig::default());
+
+        let get_drink_preview = |player_uuid: &PlayerUUID| {
+            let mut interrupt_manager = InterruptManager::new();
+            interrupt_manager.start_single_player_drink_interrupt(
+                DrinkWithPossibleChasers::new(vec![create_orcish_rotgut_test_drink()], None),
+                player_uuid.clone(),
+            );
+            let mut interrupt_data = interrupt_manager
+                .get_game_view_interrupt_data_or(&player_manager)
+                .unwrap();
+            interrupt_data
+                .interrupts
+                .remove(0)
+                .root_item
+                .drink_preview
+                .unwrap()
+        };
+
+        let orc_drink_preview = get_drink_preview(&orc_player_uuid);
+        assert_eq!(orc_drink_preview.player_uuid, orc_player_uuid);
+        assert_eq!(orc_drink_preview.alcohol_content_change, 2);
+        assert_eq!(orc_drink_preview.fortitude_change, 0);
+
+        let human_drink_preview = get_drink_preview(&human_player_uuid);
+        assert_eq!(human_drink_preview.player_uuid, human_player_uuid);
+        assert_eq!(human_drink_preview.alcohol_content_change, 0);
+        assert_eq!(human_drink_preview.fortitude_change, -2);
+    }
+
     #[test]
     fn drink_interrupt_ends_after_everyone_passes_3_player_game() {
         let player1_uuid = PlayerUUID::new();
@@ -853,6 +1225,7 @@ mod tests {
         ]);
         let mut gambling_manager = GamblingManager::new();
         let mut turn_info = TurnInfo::new_test(player1_uuid.clone());
+        let mut drink_deck = AutoShufflingDeck::new(create_drink_deck());
 
         interrupt_manager.start_single_player_drink_interrupt(
             DrinkWithPossibleChasers::new(vec![], None),
@@ -861,22 +1234,117 @@ mod tests {
         // All players pass on the chance to modify the drink.
         assert!(interrupt_manager.is_turn_to_interrupt(&player1_uuid));
         assert!(interrupt_manager
-            .pass(&mut player_manager, &mut gambling_manager, &mut turn_info)
+            .pass(
+                &mut player_manager,
+                &mut gambling_manager,
+                &mut turn_info,
+                &mut drink_deck
+            )
             .is_ok());
         assert!(interrupt_manager.is_turn_to_interrupt(&player2_uuid));
         assert!(interrupt_manager
-            .pass(&mut player_manager, &mut gambling_manager, &mut turn_info)
+            .pass(
+                &mut player_manager,
+                &mut gambling_manager,
+                &mut turn_info,
+                &mut drink_deck
+            )
             .is_ok());
         assert!(interrupt_manager.is_turn_to_interrupt(&player3_uuid));
         assert!(interrupt_manager
-            .pass(&mut player_manager, &mut gambling_manager, &mut turn_info)
+            .pass(
+                &mut player_manager,
+                &mut gambling_manager,
+                &mut turn_info,
+                &mut drink_deck
+            )
             .is_ok());
         // Targeted player passes on the chance to interrupt the drink.
         assert!(interrupt_manager.is_turn_to_interrupt(&player1_uuid));
         assert!(interrupt_manager
-            .pass(&mut player_manager, &mut gambling_manager, &mut turn_info)
+            .pass(
+                &mut player_manager,
+                &mut gambling_manager,
+                &mut turn_info,
+                &mut drink_deck
+            )
             .is_ok());
 
         assert!(!interrupt_manager.interrupt_in_progress());
     }
+
+    #[test]
+    fn multi_player_drink_interrupt_resolves_each_drink_in_order() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+        let player_uuids = [
+            player1_uuid.clone(),
+            player2_uuid.clone(),
+            player3_uuid.clone(),
+        ];
+        let mut interrupt_manager = InterruptManager::new();
+        let mut player_manager = PlayerManager::new(vec![
+            (player1_uuid.clone(), Character::Gerki),
+            (player2_uuid.clone(), Character::Deirdre),
+            (player3_uuid.clone(), Character::Zot),
+        ]);
+        let mut gambling_manager = GamblingManager::new();
+        let mut turn_info = TurnInfo::new_test(player1_uuid.clone());
+        let mut drink_deck = AutoShufflingDeck::new(create_drink_deck());
+
+        interrupt_manager.start_multi_player_drink_interrupt(
+            player_uuids
+                .iter()
+                .map(|player_uuid| {
+                    (
+                        player_uuid.clone(),
+                        DrinkWithPossibleChasers::new(
+                            vec![create_simple_ale_test_drink(false)],
+                            None,
+                        ),
+                    )
+                })
+                .collect(),
+        );
+
+        for (i, targeted_player_uuid) in player_uuids.iter().enumerate() {
+            // All players pass on the chance to modify the drink, starting with the targeted player.
+            for j in 0..player_uuids.len() {
+                assert!(interrupt_manager.is_turn_to_interrupt(&player_uuids[(i + j) % 3]));
+                interrupt_manager
+                    .pass(
+                        &mut player_manager,
+                        &mut gambling_manager,
+                        &mut turn_info,
+                        &mut drink_deck,
+                    )
+                    .unwrap();
+            }
+            // Targeted player passes on the chance to interrupt the drink.
+            assert!(interrupt_manager.is_turn_to_interrupt(targeted_player_uuid));
+            interrupt_manager
+                .pass(
+                    &mut player_manager,
+                    &mut gambling_manager,
+                    &mut turn_info,
+                    &mut drink_deck,
+                )
+                .unwrap();
+
+            // Only the players whose drinks have been resolved so far have drunk.
+            for (k, player_uuid) in player_uuids.iter().enumerate() {
+                assert_eq!(
+                    player_manager
+                        .get_player_by_uuid(player_uuid)
+                        .unwrap()
+                        .to_game_view_player_data(player_uuid.clone())
+                        .alcohol_content,
+                    if k <= i { 1 } else { 0 }
+                );
+            }
+        }
+
+        assert!(!interrupt_manager.interrupt_in_progress());
+    }
 }
@@ -1,9 +1,13 @@
+#[cfg(test)]
+mod bot;
 mod deck;
 mod drink;
 mod error;
 mod gambling_manager;
+mod game_config;
 mod game_logic;
 mod interrupt_manager;
+mod password;
 mod player;
 mod player_card;
 mod player_manager;
@@ -13,6 +17,7 @@ mod uuid;
 pub use self::uuid::GameUUID;
 pub use self::uuid::PlayerUUID;
 pub use error::Error;
+pub use game_config::GameConfig;
 
 use game_logic::GameLogic;
 use player_card::{
@@ -20,19 +25,49 @@ use player_card::{
     combined_interrupt_player_card, gain_fortitude_anytime_card, gambling_cheat_card,
     gambling_im_in_card, i_dont_think_so_card, i_raise_card, ignore_drink_card,
     ignore_root_card_affecting_fortitude, leave_gambling_round_instead_of_anteing_card,
-    oh_i_guess_the_wench_thought_that_was_her_tip_card,
+    oh_i_guess_the_wench_thought_that_was_her_tip_card, redirect_drink_card,
+    remove_other_player_discarded_card, reshuffle_deck_anytime_card, skip_next_turn_card,
+    steal_gold_card, swap_drink_card, swap_fortitude_card,
     wench_bring_some_drinks_for_my_friends_card, winning_hand_card, PlayerCard,
 };
-use player_view::{GameView, ListedGameView};
-use std::collections::HashMap;
+use player_view::{
+    CardCatalog, CardCatalogEntry, CardPlayability, CardTarget, CardTargets, CharacterDeckPreview,
+    CharacterDeckPreviewEntry, GameLog, GamePhase, GameView, ListedGameView, PlayerStateView,
+    TableView,
+};
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+const MAX_PLAYERS_PER_GAME: usize = 8;
+const UNKNOWN_PLAYER_DISPLAY_NAME: &str = "Unknown Player";
+
+/// A player waiting in a game's lobby.
+#[derive(Clone)]
+struct LobbyPlayer {
+    player_uuid: PlayerUUID,
+    // Is `None` until the player picks a character.
+    character_or: Option<Character>,
+    // Whether the player is ready for the game to start. Cleared whenever the character or the
+    // game config changes, so that nobody is started with a setup they didn't agree to.
+    ready: bool,
+}
 
 #[derive(Clone)]
 pub struct Game {
     display_name: String,
-    players: Vec<(PlayerUUID, Option<Character>)>,
+    players: Vec<LobbyPlayer>,
+    config: GameConfig,
     // Is `Some` if game is running, otherwise is `None`.
     game_logic_or: Option<GameLogic>,
+    // Whether the result of the most recently finished game has already been handed out.
+    finished_game_result_taken: bool,
+    // The player who must currently respond to an interrupt, and when they were first seen
+    // holding it up.
+    interrupt_turn_started_or: Option<(PlayerUUID, Instant)>,
+    // The PHC string of the join password's hash. Is `None` for public games, which anyone can
+    // join.
+    password_hash_or: Option<String>,
 }
 
 impl Game {
@@ -40,7 +75,33 @@ impl Game {
         Self {
             display_name,
             players: Vec::new(),
+            config: GameConfig::default(),
             game_logic_or: None,
+            finished_game_result_taken: false,
+            interrupt_turn_started_or: None,
+            password_hash_or: None,
+        }
+    }
+
+    /// Makes the game private, so that players must give the password to join it.
+    pub fn set_password(&mut self, password: &str) {
+        self.password_hash_or = Some(password::hash_password(password));
+    }
+
+    pub fn requires_password(&self) -> bool {
+        self.password_hash_or.is_some()
+    }
+
+    /// Checks the password that a player gave to join the game. Public games accept any password.
+    pub fn check_password(&self, password_or: Option<&str>) -> Result<(), Error> {
+        let password_hash = match &self.password_hash_or {
+            Some(password_hash) => password_hash,
+            None => return Ok(()),
+        };
+        match password_or {
+            Some(password) if password::password_matches(password, password_hash) => Ok(()),
+            Some(_) => Err(Error::new("Incorrect game password")),
+            None => Err(Error::new("This game requires a password to join")),
         }
     }
 
@@ -48,23 +109,54 @@ impl Game {
         // TODO - Can't join game when it is already running. Perhaps allow for joining as spectator?
         if self.player_is_in_game(&player_uuid) {
             Err(Error::new("Player is already in this game"))
+        } else if self.players.len() >= MAX_PLAYERS_PER_GAME {
+            Err(Error::new("Game is full"))
         } else {
-            self.players.push((player_uuid, None));
+            self.players.push(LobbyPlayer {
+                player_uuid,
+                character_or: None,
+                ready: false,
+            });
             Ok(())
         }
     }
 
+    /// Removes the player from the game. If they were the owner, the next player to have joined
+    /// becomes the owner.
     pub fn leave(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
         // TODO - Stop the game if a player leaves while it is running.
-        if !self.player_is_in_game(player_uuid) {
-            Err(Error::new("Player is not in this game"))
-        } else {
-            self.players.retain(|(uuid, _)| uuid != player_uuid);
-            Ok(())
+        self.assert_player_is_in_game(player_uuid)?;
+        self.players
+            .retain(|lobby_player| &lobby_player.player_uuid != player_uuid);
+        Ok(())
+    }
+
+    /// Removes another player from the game. Can only be done by the game owner before the game starts.
+    pub fn kick_player(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        kicked_player_uuid: &PlayerUUID,
+    ) -> Result<(), Error> {
+        if !self.is_owner(player_uuid) {
+            return Err(Error::new("Must be game owner to kick players"));
+        }
+
+        if self.is_running() {
+            return Err(Error::new("Cannot kick players while game is running"));
+        }
+
+        if player_uuid == kicked_player_uuid {
+            return Err(Error::new("Cannot kick yourself"));
         }
+
+        self.leave(kicked_player_uuid)
     }
 
-    pub fn start(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+    pub fn start(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        player_uuids_to_display_names: &HashMap<PlayerUUID, String>,
+    ) -> Result<(), Error> {
         if !self.is_owner(player_uuid) {
             return Err(Error::new("Must be game owner to start game"));
         }
@@ -76,20 +168,68 @@ impl Game {
         let players: Vec<(PlayerUUID, Character)> = self
             .players
             .iter()
-            .filter_map(|(player_uuid, character_or)| {
-                character_or
-                    .as_ref()
-                    .map(|character| (player_uuid.clone(), *character))
+            .filter_map(|lobby_player| {
+                lobby_player
+                    .character_or
+                    .map(|character| (lobby_player.player_uuid.clone(), character))
             })
             .collect();
         if players.len() < self.players.len() {
-            return Err(Error::new("Not all players have selected a character"));
+            let display_names_without_character: Vec<&str> = self
+                .players
+                .iter()
+                .filter(|lobby_player| lobby_player.character_or.is_none())
+                .map(|lobby_player| {
+                    match player_uuids_to_display_names.get(&lobby_player.player_uuid) {
+                        Some(display_name) => display_name.as_str(),
+                        None => UNKNOWN_PLAYER_DISPLAY_NAME,
+                    }
+                })
+                .collect();
+            return Err(Error::new(format!(
+                "Not all players have selected a character: {}",
+                display_names_without_character.join(", ")
+            )));
         }
-        let game_logic = match GameLogic::new(players) {
+        // The owner is implicitly ready since they are the one starting the game.
+        if self
+            .players
+            .iter()
+            .any(|lobby_player| &lobby_player.player_uuid != player_uuid && !lobby_player.ready)
+        {
+            return Err(Error::new("Not all players are ready"));
+        }
+        let game_logic = match GameLogic::new_with_config(players, self.config.clone()) {
             Ok(game_logic) => game_logic,
             Err(err) => return Err(err),
         };
         self.game_logic_or = Some(game_logic);
+        self.finished_game_result_taken = false;
+        self.interrupt_turn_started_or = None;
+        Ok(())
+    }
+
+    /// Forcibly ends the running game and returns all players to the lobby, keeping their
+    /// selected characters. An escape hatch for games that get stuck.
+    pub fn abort(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        if !self.is_owner(player_uuid) {
+            return Err(Error::new("Must be game owner to abort game"));
+        }
+
+        if !self.is_running() {
+            return Err(Error::new("Game is not currently running"));
+        }
+
+        self.game_logic_or = None;
+        Ok(())
+    }
+
+    /// Uses the same message as `GameManager` gives for a player that isn't in any game, so that
+    /// players see the same error no matter which check catches it.
+    fn assert_player_is_in_game(&self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        if !self.player_is_in_game(player_uuid) {
+            return Err(Error::new("Player is not in a game"));
+        }
         Ok(())
     }
 
@@ -98,15 +238,73 @@ impl Game {
         player_uuid: &PlayerUUID,
         character: Character,
     ) -> Result<(), Error> {
-        if !self.player_is_in_game(player_uuid) {
-            return Err(Error::new("Player is not in this game"));
-        }
+        self.assert_player_is_in_game(player_uuid)?;
         if self.is_running() {
             return Err(Error::new("Cannot change characters while game is running"));
         }
-        self.players.iter_mut().for_each(|(uuid, character_or)| {
-            if uuid == player_uuid {
-                *character_or = Some(character);
+        self.players.iter_mut().for_each(|lobby_player| {
+            if &lobby_player.player_uuid == player_uuid {
+                lobby_player.character_or = Some(character);
+                lobby_player.ready = false;
+            }
+        });
+        Ok(())
+    }
+
+    /// Sets the rule variants to use for the game. Can only be done by the game owner before the game starts.
+    pub fn set_config(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        config: GameConfig,
+    ) -> Result<(), Error> {
+        if !self.is_owner(player_uuid) {
+            return Err(Error::new("Must be game owner to change game config"));
+        }
+        if self.is_running() {
+            return Err(Error::new(
+                "Cannot change game config while game is running",
+            ));
+        }
+        if config.max_fortitude <= 0 || config.max_alcohol_content <= 0 {
+            return Err(Error::new(
+                "Max fortitude and alcohol content must be positive",
+            ));
+        }
+        if config.gambling_ante <= 0 {
+            return Err(Error::new("Gambling ante must be positive"));
+        }
+        if matches!(config.gold_to_win, Some(gold_to_win) if gold_to_win <= 0) {
+            return Err(Error::new("Gold to win must be positive"));
+        }
+        self.config = config;
+        self.players
+            .iter_mut()
+            .for_each(|lobby_player| lobby_player.ready = false);
+        Ok(())
+    }
+
+    /// Changes the name shown in the game list. Only the game owner can do this, and only before
+    /// the game starts.
+    pub fn rename(&mut self, player_uuid: &PlayerUUID, game_name: String) -> Result<(), Error> {
+        if !self.is_owner(player_uuid) {
+            return Err(Error::new("Must be game owner to rename game"));
+        }
+        if self.is_running() {
+            return Err(Error::new("Cannot rename game while game is running"));
+        }
+        self.display_name = game_name;
+        Ok(())
+    }
+
+    /// Marks a player as ready to start the game, or unmarks them if they were already ready.
+    pub fn toggle_ready(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        self.assert_player_is_in_game(player_uuid)?;
+        if self.is_running() {
+            return Err(Error::new("Cannot change readiness while game is running"));
+        }
+        self.players.iter_mut().for_each(|lobby_player| {
+            if &lobby_player.player_uuid == player_uuid {
+                lobby_player.ready = !lobby_player.ready;
             }
         });
         Ok(())
@@ -116,6 +314,10 @@ impl Game {
         self.players.is_empty()
     }
 
+    pub fn is_finished(&self) -> bool {
+        self.get_phase() == GamePhase::Finished
+    }
+
     /// Plays a card from the given player's hand.
     ///
     /// Accepts a zero-based card index which refers to a card in the player's hand.
@@ -125,9 +327,25 @@ impl Game {
         player_uuid: &PlayerUUID,
         other_player_uuid_or: &Option<PlayerUUID>,
         card_index: usize,
+        idempotency_key_or: Option<String>,
+    ) -> Result<(), Error> {
+        self.get_game_logic_mut()?.perform_idempotent_action(
+            player_uuid,
+            idempotency_key_or,
+            |game_logic| game_logic.play_card(player_uuid, other_player_uuid_or, card_index),
+        )
+    }
+
+    /// Reorder the cards in a player's hand.
+    ///
+    /// Accepts a permutation of the player's current hand indices, where the card at `card_order[i]` is moved to index `i`.
+    pub fn arrange_hand(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        card_order: Vec<usize>,
     ) -> Result<(), Error> {
         self.get_game_logic_mut()?
-            .play_card(player_uuid, other_player_uuid_or, card_index)
+            .arrange_hand(player_uuid, card_order)
     }
 
     /// Discards any number of cards from the given player's hand.
@@ -140,9 +358,28 @@ impl Game {
         &mut self,
         player_uuid: &PlayerUUID,
         card_indices: Vec<usize>,
+        idempotency_key_or: Option<String>,
     ) -> Result<(), Error> {
-        self.get_game_logic_mut()?
-            .discard_cards_and_draw_to_full(player_uuid, card_indices)
+        self.get_game_logic_mut()?.perform_idempotent_action(
+            player_uuid,
+            idempotency_key_or,
+            |game_logic| game_logic.discard_cards_and_draw_to_full(player_uuid, card_indices),
+        )
+    }
+
+    /// Discard a single card from the player's hand as their action, without
+    /// playing it or drawing a replacement.
+    pub fn discard_single_card(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        card_index: usize,
+        idempotency_key_or: Option<String>,
+    ) -> Result<(), Error> {
+        self.get_game_logic_mut()?.perform_idempotent_action(
+            player_uuid,
+            idempotency_key_or,
+            |game_logic| game_logic.discard_single_card(player_uuid, card_index),
+        )
     }
 
     /// Order a drink for another player.
@@ -154,30 +391,183 @@ impl Game {
         &mut self,
         player_uuid: &PlayerUUID,
         other_player_uuid: &PlayerUUID,
+        idempotency_key_or: Option<String>,
     ) -> Result<(), Error> {
-        self.get_game_logic_mut()?
-            .order_drink(player_uuid, other_player_uuid)
+        self.get_game_logic_mut()?.perform_idempotent_action(
+            player_uuid,
+            idempotency_key_or,
+            |game_logic| game_logic.order_drink(player_uuid, other_player_uuid),
+        )
+    }
+
+    /// Order all of the player's remaining drinks at once.
+    ///
+    /// Must contain exactly as many players as the player has drinks left to order.
+    pub fn order_drinks(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        other_player_uuids: Vec<PlayerUUID>,
+        idempotency_key_or: Option<String>,
+    ) -> Result<(), Error> {
+        self.get_game_logic_mut()?.perform_idempotent_action(
+            player_uuid,
+            idempotency_key_or,
+            |game_logic| game_logic.order_drinks(player_uuid, other_player_uuids),
+        )
     }
 
     fn player_can_pass(&self, player_uuid: &PlayerUUID) -> bool {
         if let Some(game_logic) = &self.game_logic_or {
-            game_logic.player_can_pass(player_uuid)
+            game_logic.can_pass(player_uuid)
         } else {
             false
         }
     }
 
-    pub fn pass(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
-        self.get_game_logic_mut()?.pass(player_uuid)
+    fn get_cannot_pass_reason_or(&self, player_uuid: &PlayerUUID) -> Option<String> {
+        match &self.game_logic_or {
+            Some(game_logic) => game_logic.get_cannot_pass_reason_or(player_uuid),
+            None => Some("Game is not running".to_string()),
+        }
+    }
+
+    pub fn pass(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        idempotency_key_or: Option<String>,
+    ) -> Result<(), Error> {
+        self.get_game_logic_mut()?.perform_idempotent_action(
+            player_uuid,
+            idempotency_key_or,
+            |game_logic| game_logic.pass(player_uuid),
+        )
+    }
+
+    /// Knock the player out of the running game while keeping them in the game lobby.
+    pub fn forfeit(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        idempotency_key_or: Option<String>,
+    ) -> Result<(), Error> {
+        self.get_game_logic_mut()?.perform_idempotent_action(
+            player_uuid,
+            idempotency_key_or,
+            |game_logic| game_logic.forfeit(player_uuid),
+        )
+    }
+
+    /// Whether `auto_pass_expired_interrupt` would change anything, so that callers only need to
+    /// lock the game for writing when it would.
+    pub fn needs_interrupt_deadline_update(
+        &self,
+        requesting_player_uuid: &PlayerUUID,
+        deadline: Duration,
+    ) -> bool {
+        let interrupt_turn_or = self
+            .game_logic_or
+            .as_ref()
+            .and_then(|game_logic| game_logic.get_current_interrupt_turn_or());
+        match (interrupt_turn_or, &self.interrupt_turn_started_or) {
+            (None, None) => false,
+            (Some(player_uuid), Some((started_player_uuid, started_at)))
+                if player_uuid == started_player_uuid =>
+            {
+                player_uuid != requesting_player_uuid && started_at.elapsed() >= deadline
+            }
+            // The deadline needs to be started or cleared.
+            _ => true,
+        }
+    }
+
+    /// Passes on behalf of the player who must respond to the current interrupt if they have taken
+    /// longer than `deadline` to do so. Returns the player that was passed for, if any.
+    /// The deadline is counted from the first call that sees the player holding up the interrupt,
+    /// so this should be called regularly. Requests from the idle player themselves never pass for
+    /// them, since the request may be their own pass, which would otherwise land on the next
+    /// interrupt.
+    pub fn auto_pass_expired_interrupt(
+        &mut self,
+        requesting_player_uuid: &PlayerUUID,
+        deadline: Duration,
+    ) -> Option<PlayerUUID> {
+        let game_logic = match &mut self.game_logic_or {
+            Some(game_logic) => game_logic,
+            None => {
+                self.interrupt_turn_started_or = None;
+                return None;
+            }
+        };
+        let player_uuid = match game_logic.get_current_interrupt_turn_or() {
+            Some(player_uuid) => player_uuid.clone(),
+            None => {
+                self.interrupt_turn_started_or = None;
+                return None;
+            }
+        };
+
+        let started_at = match &self.interrupt_turn_started_or {
+            Some((started_player_uuid, started_at)) if *started_player_uuid == player_uuid => {
+                *started_at
+            }
+            _ => {
+                let now = Instant::now();
+                self.interrupt_turn_started_or = Some((player_uuid.clone(), now));
+                now
+            }
+        };
+        if started_at.elapsed() < deadline || &player_uuid == requesting_player_uuid {
+            return None;
+        }
+
+        // The player may have to respond to the next interrupt too, so their deadline starts over.
+        self.interrupt_turn_started_or = None;
+        game_logic.auto_pass(&player_uuid).ok().map(|_| player_uuid)
+    }
+
+    pub fn undo(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        self.get_game_logic_mut()?.undo(player_uuid)
+    }
+
+    pub fn skip_turn(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        idempotency_key_or: Option<String>,
+    ) -> Result<(), Error> {
+        self.get_game_logic_mut()?.perform_idempotent_action(
+            player_uuid,
+            idempotency_key_or,
+            |game_logic| game_logic.skip_turn(player_uuid),
+        )
+    }
+
+    /// Same as `get_game_view`, but also includes the transient events that the player hasn't
+    /// been sent yet.
+    pub fn take_game_view(
+        &self,
+        player_uuid: PlayerUUID,
+        player_uuids_to_display_names: &HashMap<PlayerUUID, String>,
+        is_player_connected: impl Fn(&PlayerUUID) -> bool,
+    ) -> Result<GameView, Error> {
+        let mut game_view = self.get_game_view(
+            player_uuid.clone(),
+            player_uuids_to_display_names,
+            is_player_connected,
+        )?;
+        if let Some(game_logic) = &self.game_logic_or {
+            game_view.recent_transient_events = game_logic.take_transient_events(&player_uuid);
+        }
+        Ok(game_view)
     }
 
     pub fn get_game_view(
         &self,
         player_uuid: PlayerUUID,
         player_uuids_to_display_names: &HashMap<PlayerUUID, String>,
+        is_player_connected: impl Fn(&PlayerUUID) -> bool,
     ) -> Result<GameView, Error> {
         Ok(GameView {
             game_name: self.display_name.clone(),
+            owner_uuid: self.get_owner().cloned(),
             current_turn_player_uuid: self
                 .game_logic_or
                 .as_ref()
@@ -187,46 +577,259 @@ impl Game {
                 .as_ref()
                 .map(|game_logic| game_logic.get_turn_phase()),
             can_pass: self.player_can_pass(&player_uuid),
+            cannot_pass_reason: self.get_cannot_pass_reason_or(&player_uuid),
+            legal_actions: match &self.game_logic_or {
+                Some(game_logic) => game_logic.legal_actions(&player_uuid),
+                None => Vec::new(),
+            },
+            available_interrupt_responses: match &self.game_logic_or {
+                Some(game_logic) => game_logic.available_interrupt_responses(&player_uuid),
+                None => Vec::new(),
+            },
             hand: match &self.game_logic_or {
                 Some(game_logic) => game_logic.get_game_view_player_hand(&player_uuid),
                 None => Vec::new(),
             },
+            cards_until_reshuffle: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_cards_until_reshuffle_or(&player_uuid),
+                None => None,
+            },
+            stat_change_events: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_game_view_stat_change_events(&player_uuid),
+                None => Vec::new(),
+            },
+            // Filled in by `take_game_view`, since each event is only handed out once.
+            recent_transient_events: Vec::new(),
             self_player_uuid: player_uuid,
             player_data: match &self.game_logic_or {
                 Some(game_logic) => game_logic.get_game_view_player_data_of_all_players(),
                 None => Vec::new(),
             },
-            player_display_names: self
+            player_display_names: self.get_player_display_names(player_uuids_to_display_names),
+            player_readiness: self
                 .players
                 .iter()
-                .cloned()
-                .filter_map(|(player_uuid, _)| {
-                    player_uuids_to_display_names
-                        .get(&player_uuid)
-                        .map(|display_name| (player_uuid, display_name.to_string()))
+                .map(|lobby_player| (lobby_player.player_uuid.clone(), lobby_player.ready))
+                .collect(),
+            player_connection_statuses: self
+                .players
+                .iter()
+                .map(|lobby_player| {
+                    (
+                        lobby_player.player_uuid.clone(),
+                        is_player_connected(&lobby_player.player_uuid),
+                    )
                 })
                 .collect(),
             interrupts: match &self.game_logic_or {
                 Some(game_logic) => game_logic.get_game_view_interrupt_data_or(),
                 None => None,
             },
+            awaiting_interrupt: match &self.game_logic_or {
+                Some(game_logic) => game_logic.interrupt_in_progress(),
+                None => false,
+            },
+            gambling: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_game_view_gambling_data_or(),
+                None => None,
+            },
             drink_event: match &self.game_logic_or {
                 Some(game_logic) => game_logic.get_game_view_drink_event_or(),
                 None => None,
             },
+            seed: match &self.game_logic_or {
+                Some(game_logic) if !game_logic.is_running() => {
+                    Some(game_logic.get_seed().to_string())
+                }
+                _ => None,
+            },
+            phase: self.get_phase(),
             is_running: self.is_running(),
             winner_uuid: match &self.game_logic_or {
                 Some(game_logic) => game_logic.get_winner_or(),
                 None => None,
             },
+            eliminated_player_uuids: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_eliminated_player_uuids(),
+                None => Vec::new(),
+            },
+            standings: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_standings(),
+                None => Vec::new(),
+            },
         })
     }
 
+    /// Returns what anyone watching the game can see, leaving out every player's hand.
+    pub fn get_table_view(
+        &self,
+        player_uuids_to_display_names: &HashMap<PlayerUUID, String>,
+    ) -> TableView {
+        TableView {
+            game_name: self.display_name.clone(),
+            current_turn_player_uuid: self
+                .game_logic_or
+                .as_ref()
+                .map(|game_logic| game_logic.get_turn_info().get_current_player_turn().clone()),
+            current_turn_phase: self
+                .game_logic_or
+                .as_ref()
+                .map(|game_logic| game_logic.get_turn_phase()),
+            player_data: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_game_view_player_data_of_all_players(),
+                None => Vec::new(),
+            },
+            player_display_names: self.get_player_display_names(player_uuids_to_display_names),
+            gambling: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_game_view_gambling_data_or(),
+                None => None,
+            },
+            phase: self.get_phase(),
+            winner_uuid: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_winner_or(),
+                None => None,
+            },
+            standings: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_standings(),
+                None => Vec::new(),
+            },
+        }
+    }
+
+    fn get_player_display_names(
+        &self,
+        player_uuids_to_display_names: &HashMap<PlayerUUID, String>,
+    ) -> HashMap<PlayerUUID, String> {
+        self.players
+            .iter()
+            .cloned()
+            .map(|LobbyPlayer { player_uuid, .. }| {
+                // A player can briefly be missing a display name if they were only partially cleaned up.
+                let display_name = match player_uuids_to_display_names.get(&player_uuid) {
+                    Some(display_name) => display_name.to_string(),
+                    None => UNKNOWN_PLAYER_DISPLAY_NAME.to_string(),
+                };
+                (player_uuid, display_name)
+            })
+            .collect()
+    }
+
+    fn get_phase(&self) -> GamePhase {
+        match &self.game_logic_or {
+            Some(game_logic) if game_logic.is_running() => GamePhase::Running,
+            Some(_) => GamePhase::Finished,
+            None => GamePhase::Lobby,
+        }
+    }
+
+    pub fn get_card_targets(
+        &self,
+        player_uuid: &PlayerUUID,
+        card_index: usize,
+        player_uuids_to_display_names: &HashMap<PlayerUUID, String>,
+    ) -> Result<CardTargets, Error> {
+        let game_logic = match &self.game_logic_or {
+            Some(game_logic) => game_logic,
+            None => return Err(Error::new("Game is not currently running")),
+        };
+
+        Ok(CardTargets {
+            targets: game_logic
+                .get_card_target_uuids(player_uuid, card_index)?
+                .into_iter()
+                .map(|player_uuid| {
+                    let display_name = match player_uuids_to_display_names.get(&player_uuid) {
+                        Some(display_name) => display_name.to_string(),
+                        None => UNKNOWN_PLAYER_DISPLAY_NAME.to_string(),
+                    };
+                    CardTarget {
+                        player_uuid,
+                        display_name,
+                    }
+                })
+                .collect(),
+        })
+    }
+
+    pub fn get_card_playability(
+        &self,
+        player_uuid: &PlayerUUID,
+        card_index: usize,
+    ) -> Result<CardPlayability, Error> {
+        match &self.game_logic_or {
+            Some(game_logic) => game_logic.get_card_playability(player_uuid, card_index),
+            None => Err(Error::new("Game is not currently running")),
+        }
+    }
+
+    pub fn get_player_state_view(&self, player_uuid: PlayerUUID) -> PlayerStateView {
+        PlayerStateView {
+            current_turn_player_uuid: self
+                .game_logic_or
+                .as_ref()
+                .map(|game_logic| game_logic.get_turn_info().get_current_player_turn().clone()),
+            current_turn_phase: self
+                .game_logic_or
+                .as_ref()
+                .map(|game_logic| game_logic.get_turn_phase()),
+            hand: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_game_view_player_hand(&player_uuid),
+                None => Vec::new(),
+            },
+            player_data: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_game_view_player_data_or(&player_uuid),
+                None => None,
+            },
+            cards_until_reshuffle: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_cards_until_reshuffle_or(&player_uuid),
+                None => None,
+            },
+            self_player_uuid: player_uuid,
+        }
+    }
+
+    /// Returns the winner (if anyone survived) and all players of a game that has finished.
+    /// Only returns a result once per game, so that each game is only counted once.
+    pub fn take_finished_game_result(&mut self) -> Option<(Option<PlayerUUID>, Vec<PlayerUUID>)> {
+        let game_logic = self.game_logic_or.as_ref()?;
+        if game_logic.is_running() || self.finished_game_result_taken {
+            return None;
+        }
+        self.finished_game_result_taken = true;
+        Some((
+            game_logic.get_winner_or(),
+            game_logic.clone_uuids_of_all_players(),
+        ))
+    }
+
+    /// Returns every action taken so far in the current (or most recently finished) game.
+    pub fn get_game_log(&self) -> GameLog {
+        GameLog {
+            events: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_game_log().clone(),
+                None => Vec::new(),
+            },
+        }
+    }
+
+    /// The most recent events where the player acted or was targeted, oldest first.
+    pub fn get_player_events(&self, player_uuid: &PlayerUUID, max_event_count: usize) -> GameLog {
+        GameLog {
+            events: match &self.game_logic_or {
+                Some(game_logic) => game_logic.get_player_events(player_uuid, max_event_count),
+                None => Vec::new(),
+            },
+        }
+    }
+
     pub fn get_listed_game_view(&self, game_uuid: GameUUID) -> ListedGameView {
         ListedGameView {
             game_name: self.display_name.clone(),
             game_uuid,
             player_count: self.players.len(),
+            is_running: self.is_running(),
+            is_joinable: !self.is_running() && self.players.len() < MAX_PLAYERS_PER_GAME,
+            requires_password: self.requires_password(),
         }
     }
 
@@ -243,11 +846,13 @@ impl Game {
     }
 
     pub fn player_is_in_game(&self, player_uuid: &PlayerUUID) -> bool {
-        self.players.iter().any(|(uuid, _)| uuid == player_uuid)
+        self.players
+            .iter()
+            .any(|lobby_player| &lobby_player.player_uuid == player_uuid)
     }
 
     fn get_owner(&self) -> Option<&PlayerUUID> {
-        Some(&self.players.first()?.0)
+        Some(&self.players.first()?.player_uuid)
     }
 
     fn is_owner(&self, player_uuid: &PlayerUUID) -> bool {
@@ -265,6 +870,45 @@ impl Game {
     }
 }
 
+/// Lists every card that can appear in any character's deck, sorted by card name.
+/// Cards that show up more than once (or in multiple decks) are only listed once.
+pub fn get_card_catalog() -> CardCatalog {
+    let mut cards_by_name: BTreeMap<String, CardCatalogEntry> = BTreeMap::new();
+    for character in Character::all() {
+        for card in character.create_deck() {
+            cards_by_name
+                .entry(card.get_display_name().to_string())
+                .or_insert_with(|| CardCatalogEntry {
+                    card_name: card.get_display_name().to_string(),
+                    card_description: card.get_display_description().to_string(),
+                    card_type: card.get_card_type_name().to_string(),
+                });
+        }
+    }
+    CardCatalog {
+        cards: cards_by_name.into_values().collect(),
+    }
+}
+
+/// Lists every distinct card in a character's deck, in the order they first appear in the deck.
+pub fn get_character_deck_preview(character: Character) -> CharacterDeckPreview {
+    let mut cards: Vec<CharacterDeckPreviewEntry> = Vec::new();
+    for card in character.create_deck() {
+        match cards
+            .iter_mut()
+            .find(|entry| entry.card_name == card.get_display_name())
+        {
+            Some(entry) => entry.card_count += 1,
+            None => cards.push(CharacterDeckPreviewEntry {
+                card_name: card.get_display_name().to_string(),
+                card_description: card.get_display_description().to_string(),
+                card_count: 1,
+            }),
+        }
+    }
+    CharacterDeckPreview { cards }
+}
+
 #[derive(Clone, Copy)]
 pub enum Character {
     Fiona,
@@ -294,6 +938,23 @@ impl<'a> rocket::request::FromParam<'a> for Character {
 }
 
 impl Character {
+    pub fn all() -> [Self; 4] {
+        [Self::Fiona, Self::Zot, Self::Deirdre, Self::Gerki]
+    }
+
+    /// Counts the cards in the character's deck by card type, keyed by the same type names as the
+    /// card catalog (e.g. `"gambling"` or `"cheating"`). Useful for checking deck balance.
+    #[cfg(test)]
+    pub fn deck_composition(&self) -> HashMap<String, usize> {
+        let mut card_counts_by_type = HashMap::new();
+        for card in self.create_deck() {
+            *card_counts_by_type
+                .entry(card.get_card_type_name().to_string())
+                .or_insert(0) += 1;
+        }
+        card_counts_by_type
+    }
+
     // TODO - Finish implementing entire decks for each character.
     pub fn create_deck(&self) -> Vec<PlayerCard> {
         match self {
@@ -326,12 +987,15 @@ impl Character {
                 ignore_root_card_affecting_fortitude("Luckily for me, I was wearing my armor!")
                     .into(),
                 gain_fortitude_anytime_card("I'm a quick healer.", 2).into(),
+                reshuffle_deck_anytime_card("Let's start this over.").into(),
                 wench_bring_some_drinks_for_my_friends_card().into(),
                 wench_bring_some_drinks_for_my_friends_card().into(),
                 oh_i_guess_the_wench_thought_that_was_her_tip_card().into(),
                 winning_hand_card().into(),
                 winning_hand_card().into(),
                 i_dont_think_so_card().into(),
+                redirect_drink_card("I'll drink to that! Actually, you drink to that.").into(),
+                skip_next_turn_card("Sleep it off!").into(),
             ],
             Self::Zot => vec![
                 gambling_im_in_card().into(),
@@ -355,6 +1019,7 @@ impl Character {
                 change_other_player_fortitude_card("I told you not to distract me!", -2).into(),
                 change_other_player_fortitude_card("Watch out! Don't step on Pooky!", -2).into(),
                 change_other_player_fortitude_card("Down Pooky!", -1).into(),
+                swap_fortitude_card("Hmm... I think I got that spell backwards.").into(),
                 change_all_other_player_fortitude_card(
                     "Oh no! Not again! Pooky's on a drunken rampage!",
                     -1,
@@ -450,6 +1115,9 @@ impl Character {
                 change_other_player_fortitude_card("How did this get stuck in your back?", -2)
                     .into(),
                 ignore_root_card_affecting_fortitude("Hide in shadows").into(),
+                swap_drink_card("I'll just switch these mugs while you're not looking...").into(),
+                remove_other_player_discarded_card("Finders keepers!").into(),
+                steal_gold_card("What's yours is mine!", 2).into(),
                 wench_bring_some_drinks_for_my_friends_card().into(),
                 wench_bring_some_drinks_for_my_friends_card().into(),
                 oh_i_guess_the_wench_thought_that_was_her_tip_card().into(),
@@ -476,6 +1144,8 @@ impl Character {
 
 #[cfg(test)]
 mod tests {
+    use super::game_logic::LegalAction;
+    use super::player_card::change_other_player_fortitude_card;
     use super::*;
 
     #[test]
@@ -496,7 +1166,8 @@ mod tests {
                 game.select_character(&player2_uuid, Character::Gerki),
                 Ok(())
             );
-            assert_eq!(game.start(&player1_uuid), Ok(()));
+            assert_eq!(game.toggle_ready(&player2_uuid), Ok(()));
+            assert_eq!(game.start(&player1_uuid, &HashMap::new()), Ok(()));
 
             pass_until_game_ends_2_player_game(&mut game, &player1_uuid, &player2_uuid);
 
@@ -505,12 +1176,1038 @@ mod tests {
                 game.select_character(&player1_uuid, Character::Deirdre),
                 Ok(())
             );
-            assert_eq!(game.start(&player1_uuid), Ok(()));
+            assert_eq!(game.start(&player1_uuid, &HashMap::new()), Ok(()));
 
             pass_until_game_ends_2_player_game(&mut game, &player1_uuid, &player2_uuid);
         }
     }
 
+    #[test]
+    fn game_view_phase_follows_game_lifecycle() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let get_phase_json = |game: &Game| {
+            let game_view = game
+                .get_game_view(player1_uuid.clone(), &HashMap::new(), |_| true)
+                .unwrap();
+            serde_json::to_value(&game_view).unwrap()["phase"].clone()
+        };
+
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        assert_eq!(get_phase_json(&game), "Lobby");
+
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+        assert_eq!(get_phase_json(&game), "Running");
+
+        pass_until_game_ends_2_player_game(&mut game, &player1_uuid, &player2_uuid);
+        assert_eq!(get_phase_json(&game), "Finished");
+    }
+
+    #[test]
+    fn seed_is_only_revealed_once_game_finishes() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let get_seed_json = |game: &Game| {
+            let game_view = game
+                .get_game_view(player1_uuid.clone(), &HashMap::new(), |_| true)
+                .unwrap();
+            serde_json::to_value(&game_view).unwrap()["seed"].clone()
+        };
+
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+        assert!(get_seed_json(&game).is_null());
+
+        pass_until_game_ends_2_player_game(&mut game, &player1_uuid, &player2_uuid);
+        assert_eq!(
+            get_seed_json(&game),
+            game.game_logic_or.as_ref().unwrap().get_seed().to_string()
+        );
+    }
+
+    #[test]
+    fn cannot_join_full_game() {
+        let mut game = Game::new("Test Game".to_string());
+        let player_uuids: Vec<PlayerUUID> = (0..MAX_PLAYERS_PER_GAME)
+            .map(|_| PlayerUUID::new())
+            .collect();
+        for player_uuid in &player_uuids {
+            assert_eq!(game.join(player_uuid.clone()), Ok(()));
+        }
+
+        assert_eq!(
+            game.join(PlayerUUID::new()),
+            Err(Error::new("Game is full"))
+        );
+        assert!(!game.get_listed_game_view(GameUUID::new()).is_joinable);
+
+        // A full game can still be started.
+        for player_uuid in &player_uuids {
+            assert_eq!(game.select_character(player_uuid, Character::Zot), Ok(()));
+            assert_eq!(game.toggle_ready(player_uuid), Ok(()));
+        }
+        assert_eq!(
+            game.start(player_uuids.first().unwrap(), &HashMap::new()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn listed_game_view_shows_whether_game_can_be_joined() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let game_uuid = GameUUID::new();
+
+        let mut game = Game::new("Test Game".to_string());
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        let listed_game_view = game.get_listed_game_view(game_uuid.clone());
+        assert!(!listed_game_view.is_running);
+        assert!(listed_game_view.is_joinable);
+
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+        let listed_game_view = game.get_listed_game_view(game_uuid);
+        assert!(listed_game_view.is_running);
+        assert!(!listed_game_view.is_joinable);
+    }
+
+    #[test]
+    fn get_game_view_handles_missing_display_names() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+
+        let mut player_uuids_to_display_names = HashMap::new();
+        player_uuids_to_display_names.insert(player1_uuid.clone(), "Tommy".to_string());
+
+        let game_view = game
+            .get_game_view(player1_uuid.clone(), &player_uuids_to_display_names, |_| {
+                true
+            })
+            .unwrap();
+        assert_eq!(
+            game_view.player_display_names.get(&player1_uuid).unwrap(),
+            "Tommy"
+        );
+        assert_eq!(
+            game_view.player_display_names.get(&player2_uuid).unwrap(),
+            UNKNOWN_PLAYER_DISPLAY_NAME
+        );
+    }
+
+    #[test]
+    fn player_state_view_only_contains_own_hand_and_stats() {
+        let mut game = Game::new("Test Game".to_string());
+        let player_uuids = [PlayerUUID::new(), PlayerUUID::new(), PlayerUUID::new()];
+        for (player_uuid, character) in
+            player_uuids
+                .iter()
+                .zip([Character::Deirdre, Character::Gerki, Character::Zot])
+        {
+            game.join(player_uuid.clone()).unwrap();
+            game.select_character(player_uuid, character).unwrap();
+            game.toggle_ready(player_uuid).unwrap();
+        }
+        game.start(&player_uuids[0], &HashMap::new()).unwrap();
+
+        let player_state_view = game.get_player_state_view(player_uuids[1].clone());
+        assert_eq!(
+            player_state_view
+                .hand
+                .iter()
+                .map(|card| card.card_name.clone())
+                .collect::<Vec<String>>(),
+            game.game_logic_or
+                .as_ref()
+                .unwrap()
+                .get_game_view_player_hand(&player_uuids[1])
+                .into_iter()
+                .map(|card| card.card_name)
+                .collect::<Vec<String>>()
+        );
+        assert_eq!(
+            player_state_view.player_data.as_ref().unwrap().player_uuid,
+            player_uuids[1]
+        );
+        assert_eq!(
+            player_state_view.current_turn_player_uuid,
+            Some(player_uuids[0].clone())
+        );
+
+        // The only other player mentioned is the one whose turn it is.
+        let player_state_view_json = serde_json::to_string(&player_state_view).unwrap();
+        let other_player_uuid_json = serde_json::to_string(&player_uuids[2]).unwrap();
+        assert!(!player_state_view_json.contains(&other_player_uuid_json));
+    }
+
+    #[test]
+    fn game_view_never_exposes_other_player_hands() {
+        let mut game = Game::new("Test Game".to_string());
+        let player_uuids = [PlayerUUID::new(), PlayerUUID::new(), PlayerUUID::new()];
+        let mut player_uuids_to_display_names = HashMap::new();
+        for (i, (player_uuid, character)) in player_uuids
+            .iter()
+            .zip([Character::Deirdre, Character::Gerki, Character::Zot])
+            .enumerate()
+        {
+            game.join(player_uuid.clone()).unwrap();
+            game.select_character(player_uuid, character).unwrap();
+            game.toggle_ready(player_uuid).unwrap();
+            player_uuids_to_display_names.insert(player_uuid.clone(), format!("Player {}", i));
+        }
+        game.start(&player_uuids[0], &HashMap::new()).unwrap();
+
+        let game_logic = game.game_logic_or.as_ref().unwrap();
+        let hand_card_names: Vec<Vec<String>> = player_uuids
+            .iter()
+            .map(|player_uuid| {
+                game_logic
+                    .get_game_view_player_hand(player_uuid)
+                    .into_iter()
+                    .map(|card| card.card_name)
+                    .collect()
+            })
+            .collect();
+
+        for (player_uuid, own_hand_card_names) in player_uuids.iter().zip(&hand_card_names) {
+            let game_view = game
+                .get_game_view(player_uuid.clone(), &player_uuids_to_display_names, |_| {
+                    true
+                })
+                .unwrap();
+            let view_hand_card_names: Vec<String> = game_view
+                .hand
+                .iter()
+                .map(|card| card.card_name.clone())
+                .collect();
+            assert_eq!(&view_hand_card_names, own_hand_card_names);
+
+            // Outside of the player's own hand, no hand card should show up anywhere in the view.
+            let mut game_view_json = serde_json::to_value(&game_view).unwrap();
+            game_view_json.as_object_mut().unwrap().remove("hand");
+            let game_view_string = game_view_json.to_string();
+            for card_name in hand_card_names.iter().flatten() {
+                assert!(!game_view_string.contains(card_name.as_str()));
+            }
+        }
+    }
+
+    #[test]
+    fn game_view_shows_gambling_round() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+        let get_gambling_json = |game: &Game| {
+            let game_view = game
+                .get_game_view(player1_uuid.clone(), &HashMap::new(), |_| true)
+                .unwrap();
+            serde_json::to_value(&game_view).unwrap()["gambling"].clone()
+        };
+        assert!(get_gambling_json(&game).is_null());
+
+        game.game_logic_or
+            .as_mut()
+            .unwrap()
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .return_card_to_hand(gambling_im_in_card().into(), 0);
+        game.discard_cards_and_draw_to_full(&player1_uuid, Vec::new(), None)
+            .unwrap();
+        game.play_card(&player1_uuid, &None, 0, None).unwrap();
+        game.pass(&player2_uuid, None).unwrap();
+
+        let gambling_json = get_gambling_json(&game);
+        assert_eq!(gambling_json["potAmount"], 2);
+        assert_eq!(
+            gambling_json["currentPlayerTurn"],
+            serde_json::to_value(&player2_uuid).unwrap()
+        );
+        assert_eq!(
+            gambling_json["activePlayerUuids"],
+            serde_json::to_value(vec![&player1_uuid, &player2_uuid]).unwrap()
+        );
+        assert_eq!(gambling_json["needCheatingCardToTakeNextControl"], false);
+    }
+
+    #[test]
+    fn game_view_json_uses_expected_field_names() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+
+        // Attack player 2 so that there is an interrupt in progress.
+        game.game_logic_or
+            .as_mut()
+            .unwrap()
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .return_card_to_hand(change_other_player_fortitude_card("Punch", -2).into(), 0);
+        game.discard_cards_and_draw_to_full(&player1_uuid, Vec::new(), None)
+            .unwrap();
+        game.play_card(&player1_uuid, &Some(player2_uuid.clone()), 0, None)
+            .unwrap();
+
+        let game_view = game
+            .get_game_view(player1_uuid.clone(), &HashMap::new(), |_| true)
+            .unwrap();
+        let game_view_json = serde_json::to_value(&game_view).unwrap();
+        let field_names = |json: &serde_json::Value| {
+            let mut field_names: Vec<String> = json.as_object().unwrap().keys().cloned().collect();
+            field_names.sort();
+            field_names
+        };
+
+        // The frontend depends on these names, so changing any of them is a breaking change.
+        assert_eq!(
+            field_names(&game_view_json),
+            vec![
+                "availableInterruptResponses",
+                "awaitingInterrupt",
+                "canPass",
+                "cannotPassReason",
+                "cardsUntilReshuffle",
+                "currentTurnPhase",
+                "currentTurnPlayerUuid",
+                "drinkEvent",
+                "eliminatedPlayerUuids",
+                "gambling",
+                "gameName",
+                "hand",
+                "interrupts",
+                "isRunning",
+                "legalActions",
+                "ownerUuid",
+                "phase",
+                "playerConnectionStatuses",
+                "playerData",
+                "playerDisplayNames",
+                "playerReadiness",
+                "recentTransientEvents",
+                "seed",
+                "selfPlayerUuid",
+                "standings",
+                "statChangeEvents",
+                "winnerUuid",
+            ]
+        );
+        assert_eq!(
+            field_names(&game_view_json["playerData"][0]),
+            vec![
+                "alcoholContent",
+                "alcoholContentHistory",
+                "discardPileSize",
+                "drawPileSize",
+                "drinkMePileSize",
+                "fortitude",
+                "fortitudeHistory",
+                "gold",
+                "goldHistory",
+                "isDead",
+                "playerUuid",
+                "removedPileSize",
+            ]
+        );
+        assert_eq!(
+            field_names(&game_view_json["hand"][0]),
+            vec![
+                "cardDescription",
+                "cardName",
+                "isDirected",
+                "isPlayable",
+                "targetStyle",
+            ]
+        );
+        assert_eq!(
+            field_names(&game_view_json["interrupts"]),
+            vec!["currentInterruptTurn", "interrupts"]
+        );
+        assert_eq!(
+            field_names(&game_view_json["interrupts"]["interrupts"][0]),
+            vec!["interruptCardNames", "rootItem"]
+        );
+        assert_eq!(
+            field_names(&game_view_json["interrupts"]["interrupts"][0]["rootItem"]),
+            vec!["drinkPreview", "itemType", "name"]
+        );
+        assert_eq!(
+            game_view_json["selfPlayerUuid"],
+            serde_json::to_value(&player1_uuid).unwrap()
+        );
+        assert_eq!(
+            game_view_json["currentTurnPlayerUuid"],
+            serde_json::to_value(&player1_uuid).unwrap()
+        );
+        assert_eq!(game_view_json["phase"], "Running");
+        assert!(game_view_json["drinkEvent"].is_null());
+    }
+
+    #[test]
+    fn card_targets_only_include_alive_opponents_for_directed_cards() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        game.join(player3_uuid.clone()).unwrap();
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.select_character(&player3_uuid, Character::Fiona)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.toggle_ready(&player3_uuid).unwrap();
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+        game.forfeit(&player3_uuid, None).unwrap();
+
+        let player1 = game
+            .game_logic_or
+            .as_mut()
+            .unwrap()
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap();
+        player1.return_card_to_hand(change_other_player_fortitude_card("Punch", -2).into(), 0);
+        player1.return_card_to_hand(gain_fortitude_anytime_card("Rest", 2).into(), 1);
+
+        let mut player_uuids_to_display_names = HashMap::new();
+        player_uuids_to_display_names.insert(player2_uuid.clone(), "Gerki".to_string());
+
+        // Only the opponent who is still in the game can be targeted.
+        let targets = game
+            .get_card_targets(&player1_uuid, 0, &player_uuids_to_display_names)
+            .unwrap()
+            .targets;
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].player_uuid, player2_uuid);
+        assert_eq!(targets[0].display_name, "Gerki");
+
+        // Cards played on yourself have nobody to pick.
+        assert!(game
+            .get_card_targets(&player1_uuid, 1, &player_uuids_to_display_names)
+            .unwrap()
+            .targets
+            .is_empty());
+
+        assert_eq!(
+            game.get_card_targets(&player1_uuid, 100, &player_uuids_to_display_names)
+                .err(),
+            Some(Error::new("Card does not exist"))
+        );
+    }
+
+    #[test]
+    fn game_view_shows_when_awaiting_interrupt() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player_uuids_to_display_names = HashMap::new();
+        let is_awaiting_interrupt = |game: &Game| {
+            let game_view = game
+                .get_game_view(player1_uuid.clone(), &player_uuids_to_display_names, |_| {
+                    true
+                })
+                .unwrap();
+            let game_view_json = serde_json::to_value(&game_view).unwrap();
+            game_view_json["awaitingInterrupt"].as_bool().unwrap()
+        };
+
+        // Keep dealing new games until player 1 is dealt a card that attacks another player's fortitude.
+        loop {
+            let mut game = Game::new("Test Game".to_string());
+            game.join(player1_uuid.clone()).unwrap();
+            game.join(player2_uuid.clone()).unwrap();
+            game.select_character(&player1_uuid, Character::Deirdre)
+                .unwrap();
+            game.select_character(&player2_uuid, Character::Gerki)
+                .unwrap();
+            game.toggle_ready(&player1_uuid).unwrap();
+            game.toggle_ready(&player2_uuid).unwrap();
+            assert!(!is_awaiting_interrupt(&game));
+            game.start(&player1_uuid, &HashMap::new()).unwrap();
+            game.discard_cards_and_draw_to_full(&player1_uuid, Vec::new(), None)
+                .unwrap();
+            assert!(!is_awaiting_interrupt(&game));
+
+            let card_index_or = game
+                .get_game_view(player1_uuid.clone(), &player_uuids_to_display_names, |_| {
+                    true
+                })
+                .unwrap()
+                .hand
+                .iter()
+                .position(|card| card.is_directed && card.is_playable);
+            let card_index = match card_index_or {
+                Some(card_index) => card_index,
+                None => continue,
+            };
+
+            game.play_card(&player1_uuid, &Some(player2_uuid.clone()), card_index, None)
+                .unwrap();
+            assert!(is_awaiting_interrupt(&game));
+
+            game.pass(&player2_uuid, None).unwrap();
+            assert!(!is_awaiting_interrupt(&game));
+            break;
+        }
+    }
+
+    #[test]
+    fn idle_player_is_auto_passed_once_interrupt_deadline_expires() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player_uuids_to_display_names = HashMap::new();
+        let get_game_view = |game: &Game| {
+            game.get_game_view(player1_uuid.clone(), &player_uuids_to_display_names, |_| {
+                true
+            })
+            .unwrap()
+        };
+        let get_player2_fortitude = |game: &Game| {
+            get_game_view(game)
+                .player_data
+                .iter()
+                .find(|player_data| player_data.player_uuid == player2_uuid)
+                .unwrap()
+                .fortitude
+        };
+
+        let mut game = Game::new("Test Game".to_string());
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+        game.discard_cards_and_draw_to_full(&player1_uuid, Vec::new(), None)
+            .unwrap();
+        assert!(!game.needs_interrupt_deadline_update(&player1_uuid, Duration::ZERO));
+        assert_eq!(
+            game.auto_pass_expired_interrupt(&player1_uuid, Duration::ZERO),
+            None
+        );
+
+        game.game_logic_or
+            .as_mut()
+            .unwrap()
+            .get_player_by_uuid_mut(&player1_uuid)
+            .unwrap()
+            .return_card_to_hand(change_other_player_fortitude_card("Punch", -2).into(), 0);
+        game.play_card(&player1_uuid, &Some(player2_uuid.clone()), 0, None)
+            .unwrap();
+        assert!(get_game_view(&game).awaiting_interrupt);
+        let fortitude_before_attack = get_player2_fortitude(&game);
+
+        // Player 2 still has time to respond.
+        assert_eq!(
+            game.auto_pass_expired_interrupt(&player1_uuid, Duration::from_secs(60)),
+            None
+        );
+        assert!(!game.needs_interrupt_deadline_update(&player1_uuid, Duration::from_secs(60)));
+        assert!(get_game_view(&game).awaiting_interrupt);
+
+        // Player 2's own requests never pass for them, even once the deadline has passed.
+        assert!(!game.needs_interrupt_deadline_update(&player2_uuid, Duration::ZERO));
+        assert_eq!(
+            game.auto_pass_expired_interrupt(&player2_uuid, Duration::ZERO),
+            None
+        );
+        assert!(get_game_view(&game).awaiting_interrupt);
+
+        // Once the deadline passes, the attack resolves and player 1's turn carries on.
+        assert!(game.needs_interrupt_deadline_update(&player1_uuid, Duration::ZERO));
+        assert_eq!(
+            game.auto_pass_expired_interrupt(&player1_uuid, Duration::ZERO),
+            Some(player2_uuid.clone())
+        );
+        let game_view = get_game_view(&game);
+        assert!(!game_view.awaiting_interrupt);
+        assert_eq!(
+            game_view.current_turn_player_uuid,
+            Some(player1_uuid.clone())
+        );
+        assert!(get_player2_fortitude(&game) < fortitude_before_attack);
+
+        // The idle player can't undo the pass that was made for them.
+        assert_eq!(
+            game.undo(&player2_uuid),
+            Err(Error::new("There is no action to undo"))
+        );
+        assert!(get_player2_fortitude(&game) < fortitude_before_attack);
+    }
+
+    #[test]
+    fn replayed_game_matches_original_game() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let players_with_characters = vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ];
+        let mut game = Game::new("Test Game".to_string());
+        for (player_uuid, character) in &players_with_characters {
+            game.join(player_uuid.clone()).unwrap();
+            game.select_character(player_uuid, *character).unwrap();
+            game.toggle_ready(player_uuid).unwrap();
+        }
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+
+        // Play part of a game, cycling through whichever actions are legal at each step.
+        let game_logic = game.game_logic_or.as_mut().unwrap();
+        game_logic
+            .arrange_hand(&player1_uuid, (0..7).rev().collect())
+            .unwrap();
+        for step in 0..200 {
+            let (player_uuid, legal_action) =
+                match players_with_characters.iter().find_map(|(player_uuid, _)| {
+                    let legal_actions = game_logic.legal_actions(player_uuid);
+                    if legal_actions.is_empty() {
+                        None
+                    } else {
+                        Some((
+                            player_uuid.clone(),
+                            legal_actions[step % legal_actions.len()].clone(),
+                        ))
+                    }
+                }) {
+                    Some(player_action) => player_action,
+                    // The game is over.
+                    None => break,
+                };
+            match legal_action {
+                LegalAction::Pass => game_logic.pass(&player_uuid),
+                LegalAction::PlayCard {
+                    card_index,
+                    target_player_uuid,
+                } => game_logic.play_card(&player_uuid, &target_player_uuid, card_index),
+                LegalAction::DiscardCards => {
+                    let hand_size = game_logic.get_game_view_player_hand(&player_uuid).len();
+                    game_logic.discard_cards_and_draw_to_full(
+                        &player_uuid,
+                        (0..hand_size.min(2)).collect(),
+                    )
+                }
+                LegalAction::DiscardSingleCard => game_logic.discard_single_card(&player_uuid, 0),
+                LegalAction::OrderDrink { target_player_uuid } => {
+                    game_logic.order_drink(&player_uuid, &target_player_uuid)
+                }
+            }
+            .unwrap();
+        }
+
+        let mut replayed_game = game.clone();
+        let game_logic = game.game_logic_or.as_ref().unwrap();
+        replayed_game.game_logic_or = Some(
+            GameLogic::replay(
+                game_logic.get_seed(),
+                players_with_characters,
+                game.config.clone(),
+                game_logic.get_game_log(),
+            )
+            .unwrap(),
+        );
+
+        for player_uuid in [&player1_uuid, &player2_uuid] {
+            let game_view = game
+                .get_game_view(player_uuid.clone(), &HashMap::new(), |_| true)
+                .unwrap();
+            let replayed_game_view = replayed_game
+                .get_game_view(player_uuid.clone(), &HashMap::new(), |_| true)
+                .unwrap();
+            assert_eq!(
+                serde_json::to_value(&replayed_game_view).unwrap(),
+                serde_json::to_value(&game_view).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn replay_rejects_illegal_events() {
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let players_with_characters = vec![
+            (player1_uuid.clone(), Character::Deirdre),
+            (player2_uuid.clone(), Character::Gerki),
+        ];
+        let mut game_logic =
+            GameLogic::new_with_config(players_with_characters.clone(), GameConfig::default())
+                .unwrap();
+        game_logic
+            .discard_cards_and_draw_to_full(&player1_uuid, Vec::new())
+            .unwrap();
+        let mut events = game_logic.get_game_log().clone();
+        // Player 1 can't discard twice in a row.
+        events.push(events[0].clone());
+
+        assert!(GameLogic::replay(
+            game_logic.get_seed(),
+            players_with_characters,
+            GameConfig::default(),
+            &events
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn start_error_names_players_without_a_character() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+
+        let mut player_uuids_to_display_names = HashMap::new();
+        player_uuids_to_display_names.insert(player1_uuid.clone(), "Tommy".to_string());
+        player_uuids_to_display_names.insert(player2_uuid, "Sarah".to_string());
+
+        assert_eq!(
+            game.start(&player1_uuid, &player_uuids_to_display_names),
+            Err(Error::new(
+                "Not all players have selected a character: Sarah"
+            ))
+        );
+    }
+
+    #[test]
+    fn cannot_start_game_until_all_players_are_ready() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        game.join(player3_uuid.clone()).unwrap();
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.select_character(&player3_uuid, Character::Zot)
+            .unwrap();
+
+        assert_eq!(
+            game.start(&player1_uuid, &HashMap::new()),
+            Err(Error::new("Not all players are ready"))
+        );
+
+        assert_eq!(game.toggle_ready(&player2_uuid), Ok(()));
+        assert_eq!(
+            game.start(&player1_uuid, &HashMap::new()),
+            Err(Error::new("Not all players are ready"))
+        );
+
+        // Toggling twice un-readies the player.
+        assert_eq!(game.toggle_ready(&player3_uuid), Ok(()));
+        assert_eq!(game.toggle_ready(&player3_uuid), Ok(()));
+        assert_eq!(
+            game.start(&player1_uuid, &HashMap::new()),
+            Err(Error::new("Not all players are ready"))
+        );
+
+        assert_eq!(game.toggle_ready(&player3_uuid), Ok(()));
+        let game_view = game
+            .get_game_view(player1_uuid.clone(), &HashMap::new(), |_| true)
+            .unwrap();
+        assert_eq!(game_view.player_readiness.get(&player1_uuid), Some(&false));
+        assert_eq!(game_view.player_readiness.get(&player2_uuid), Some(&true));
+        assert_eq!(game_view.player_readiness.get(&player3_uuid), Some(&true));
+
+        // The owner doesn't need to be ready to start the game.
+        assert_eq!(game.start(&player1_uuid, &HashMap::new()), Ok(()));
+        assert_eq!(
+            game.toggle_ready(&player2_uuid),
+            Err(Error::new("Cannot change readiness while game is running"))
+        );
+    }
+
+    #[test]
+    fn changing_character_or_config_clears_readiness() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        game.join(player3_uuid.clone()).unwrap();
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.select_character(&player3_uuid, Character::Zot)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.toggle_ready(&player3_uuid).unwrap();
+
+        // Only the player who changed character has to ready up again.
+        game.select_character(&player2_uuid, Character::Fiona)
+            .unwrap();
+        let game_view = game
+            .get_game_view(player1_uuid.clone(), &HashMap::new(), |_| true)
+            .unwrap();
+        assert_eq!(game_view.player_readiness.get(&player2_uuid), Some(&false));
+        assert_eq!(game_view.player_readiness.get(&player3_uuid), Some(&true));
+        assert_eq!(
+            game.start(&player1_uuid, &HashMap::new()),
+            Err(Error::new("Not all players are ready"))
+        );
+
+        // Changing the rules un-readies everyone.
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.set_config(&player1_uuid, GameConfig::default())
+            .unwrap();
+        let game_view = game
+            .get_game_view(player1_uuid.clone(), &HashMap::new(), |_| true)
+            .unwrap();
+        assert_eq!(game_view.player_readiness.get(&player2_uuid), Some(&false));
+        assert_eq!(game_view.player_readiness.get(&player3_uuid), Some(&false));
+        assert_eq!(
+            game.start(&player1_uuid, &HashMap::new()),
+            Err(Error::new("Not all players are ready"))
+        );
+    }
+
+    #[test]
+    fn only_owner_can_set_config_before_game_starts() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        let config = GameConfig {
+            max_discards_per_turn: Some(3),
+            ..GameConfig::default()
+        };
+
+        assert_eq!(
+            game.set_config(&player2_uuid, config.clone()),
+            Err(Error::new("Must be game owner to change game config"))
+        );
+        assert_eq!(game.set_config(&player1_uuid, config.clone()), Ok(()));
+        assert_eq!(game.config, config);
+
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+        assert_eq!(
+            game.set_config(&player1_uuid, GameConfig::default()),
+            Err(Error::new(
+                "Cannot change game config while game is running"
+            ))
+        );
+    }
+
+    #[test]
+    fn only_owner_can_rename_game_before_it_starts() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+
+        assert_eq!(game.rename(&player1_uuid, "New Name".to_string()), Ok(()));
+        assert_eq!(
+            game.get_listed_game_view(GameUUID::new()).game_name,
+            "New Name"
+        );
+
+        assert_eq!(
+            game.rename(&player2_uuid, "Hijacked".to_string()),
+            Err(Error::new("Must be game owner to rename game"))
+        );
+        assert_eq!(game.display_name, "New Name");
+
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+        assert_eq!(
+            game.rename(&player1_uuid, "Too Late".to_string()),
+            Err(Error::new("Cannot rename game while game is running"))
+        );
+        assert_eq!(game.display_name, "New Name");
+    }
+
+    #[test]
+    fn starting_lazy_game_leaves_hands_undrawn() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        game.set_config(
+            &player1_uuid,
+            GameConfig {
+                lazy_starting_hands: true,
+                ..GameConfig::default()
+            },
+        )
+        .unwrap();
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+
+        let has_starting_hand = |game: &Game, player_uuid: &PlayerUUID| {
+            game.get_game_logic()
+                .unwrap()
+                .get_player_by_uuid(player_uuid)
+                .unwrap()
+                .has_starting_hand()
+        };
+        assert!(!has_starting_hand(&game, &player1_uuid));
+        assert!(!has_starting_hand(&game, &player2_uuid));
+
+        // Looking at a hand doesn't draw it.
+        let game_view = game
+            .take_game_view(player1_uuid.clone(), &HashMap::new(), |_| true)
+            .unwrap();
+        assert_eq!(game_view.hand.len(), 7);
+        assert!(!has_starting_hand(&game, &player1_uuid));
+    }
+
+    #[test]
+    fn can_abort_and_restart_game() {
+        let mut game = Game::new("Test Game".to_string());
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game.join(player1_uuid.clone()).unwrap();
+        game.join(player2_uuid.clone()).unwrap();
+        game.select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game.select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game.toggle_ready(&player2_uuid).unwrap();
+
+        assert_eq!(
+            game.abort(&player1_uuid),
+            Err(Error::new("Game is not currently running"))
+        );
+
+        game.start(&player1_uuid, &HashMap::new()).unwrap();
+        assert_eq!(
+            game.abort(&player2_uuid),
+            Err(Error::new("Must be game owner to abort game"))
+        );
+        assert!(game.is_running());
+
+        assert_eq!(game.abort(&player1_uuid), Ok(()));
+        assert!(!game.is_running());
+        assert!(game.get_game_logic().is_none());
+
+        // Players are back in the lobby and can start over.
+        assert_eq!(
+            game.select_character(&player2_uuid, Character::Fiona),
+            Ok(())
+        );
+        game.toggle_ready(&player2_uuid).unwrap();
+        assert_eq!(game.start(&player1_uuid, &HashMap::new()), Ok(()));
+        assert!(game.is_running());
+    }
+
+    #[test]
+    fn card_catalog_contains_every_card_once() {
+        let card_catalog = get_card_catalog();
+
+        let gambling_card = card_catalog
+            .cards
+            .iter()
+            .find(|card| card.card_name == "Gambling? I'm in!")
+            .unwrap();
+        assert_eq!(
+            gambling_card.card_description,
+            "Start a Round of Gambling. (Each player, including you, must ante.)\n- OR -\nTake control of a Round of Gambling."
+        );
+        assert_eq!(gambling_card.card_type, "actionGambling");
+
+        assert_eq!(card_catalog.cards.len(), 45);
+    }
+
+    #[test]
+    fn character_deck_preview_counts_duplicate_cards() {
+        let deck_preview = get_character_deck_preview(Character::Zot);
+        let get_card_count = |card_name: &str| {
+            deck_preview
+                .cards
+                .iter()
+                .find(|card| card.card_name == card_name)
+                .unwrap()
+                .card_count
+        };
+
+        assert_eq!(get_card_count("I don't think so!"), 1);
+        assert_eq!(get_card_count("Gambling? I'm in!"), 6);
+        assert_eq!(get_card_count("I raise!"), 2);
+        assert_eq!(
+            deck_preview
+                .cards
+                .iter()
+                .map(|card| card.card_count)
+                .sum::<usize>(),
+            Character::Zot.create_deck().len()
+        );
+    }
+
+    #[test]
+    fn deck_composition_counts_cards_by_type() {
+        let deck_composition = Character::Zot.deck_composition();
+
+        assert_eq!(deck_composition.get("actionGambling"), Some(&6));
+        assert_eq!(deck_composition.get("gambling"), Some(&2));
+        // Three cheating cards plus two winning hands.
+        assert_eq!(deck_composition.get("cheating"), Some(&5));
+        assert_eq!(
+            deck_composition.values().sum::<usize>(),
+            Character::Zot.create_deck().len()
+        );
+    }
+
     fn pass_until_game_ends_2_player_game(
         game: &mut Game,
         player1_uuid: &PlayerUUID,
@@ -522,11 +2219,11 @@ mod tests {
             }
 
             assert_eq!(
-                game.discard_cards_and_draw_to_full(player1_uuid, Vec::new()),
+                game.discard_cards_and_draw_to_full(player1_uuid, Vec::new(), None),
                 Ok(())
             );
-            assert_eq!(game.pass(player1_uuid), Ok(()));
-            assert_eq!(game.order_drink(player1_uuid, player2_uuid), Ok(()));
+            assert_eq!(game.pass(player1_uuid, None), Ok(()));
+            assert_eq!(game.order_drink(player1_uuid, player2_uuid, None), Ok(()));
 
             while game.get_game_logic().unwrap().is_running()
                 && game
@@ -536,9 +2233,9 @@ mod tests {
                     .is_drink_phase()
             {
                 if game.player_can_pass(player1_uuid) {
-                    game.pass(player1_uuid).unwrap();
+                    game.pass(player1_uuid, None).unwrap();
                 } else if game.player_can_pass(player2_uuid) {
-                    game.pass(player2_uuid).unwrap();
+                    game.pass(player2_uuid, None).unwrap();
                 } else {
                     panic!("Neither player can pass");
                 }
@@ -549,11 +2246,11 @@ mod tests {
             }
 
             assert_eq!(
-                game.discard_cards_and_draw_to_full(player2_uuid, Vec::new()),
+                game.discard_cards_and_draw_to_full(player2_uuid, Vec::new(), None),
                 Ok(())
             );
-            assert_eq!(game.pass(player2_uuid), Ok(()));
-            assert_eq!(game.order_drink(player2_uuid, player1_uuid), Ok(()));
+            assert_eq!(game.pass(player2_uuid, None), Ok(()));
+            assert_eq!(game.order_drink(player2_uuid, player1_uuid, None), Ok(()));
 
             while game.get_game_logic().unwrap().is_running()
                 && game
@@ -563,9 +2260,9 @@ mod tests {
                     .is_drink_phase()
             {
                 if game.player_can_pass(player1_uuid) {
-                    game.pass(player1_uuid).unwrap();
+                    game.pass(player1_uuid, None).unwrap();
                 } else if game.player_can_pass(player2_uuid) {
-                    game.pass(player2_uuid).unwrap();
+                    game.pass(player2_uuid, None).unwrap();
                 } else {
                     panic!("Neither player can pass");
                 }
@@ -0,0 +1,25 @@
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use rand::Rng;
+
+/// Hashes a game's join password with Argon2 and a random salt, so that the password itself
+/// isn't kept around. Returns the hash as a PHC string, which also records the salt and the
+/// hashing parameters.
+pub fn hash_password(password: &str) -> String {
+    let salt_bytes: [u8; 16] = rand::thread_rng().gen();
+    let salt = SaltString::encode_b64(&salt_bytes).unwrap();
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .unwrap()
+        .to_string()
+}
+
+/// Whether `password` is the password that `password_hash` was created from.
+pub fn password_matches(password: &str, password_hash: &str) -> bool {
+    match PasswordHash::new(password_hash) {
+        Ok(password_hash) => Argon2::default()
+            .verify_password(password.as_bytes(), &password_hash)
+            .is_ok(),
+        Err(_) => false,
+    }
+}
@@ -3,10 +3,41 @@ use super::drink::{get_revealed_drink, DrinkCard, DrinkDeck, RevealedDrink};
 use super::gambling_manager::GamblingManager;
 use super::game_logic::TurnInfo;
 use super::interrupt_manager::InterruptManager;
-use super::player_card::{PlayerCard, TargetStyle};
-use super::player_view::{GameViewPlayerCard, GameViewPlayerData};
+use super::player_card::PlayerCard;
+use super::player_view::{GameViewPlayerCard, GameViewPlayerData, GameViewStatChangeEvent};
 use super::uuid::PlayerUUID;
-use super::Character;
+use super::{Character, Error, GameConfig};
+use serde::Serialize;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+/// Maximum number of stat change events that are remembered per player.
+const MAX_STAT_CHANGE_EVENTS: usize = 20;
+
+/// Maximum number of turns that stat snapshots are remembered for per player.
+pub const MAX_STAT_SNAPSHOTS: usize = 10;
+
+/// Fortitude each player starts the game with, unless the game caps it lower.
+const STARTING_FORTITUDE: i32 = 20;
+
+/// Number of cards a player draws up to at the start of their turn.
+pub const HAND_SIZE: usize = 7;
+
+/// Minimum number of cards a character deck must contain. Leaves room for
+/// a full hand plus enough cards to draw from on the following turn.
+const MIN_DECK_SIZE: usize = HAND_SIZE * 2;
+
+/// Checks that a deck is large enough to be played with.
+pub fn validate_deck(deck: &[PlayerCard]) -> Result<(), Error> {
+    if deck.len() < MIN_DECK_SIZE {
+        return Err(Error::new(format!(
+            "Deck must contain at least {} cards but only contains {}",
+            MIN_DECK_SIZE,
+            deck.len()
+        )));
+    }
+    Ok(())
+}
 
 #[derive(Clone, Debug)]
 pub struct Player {
@@ -18,48 +49,113 @@ pub struct Player {
     drink_me_pile: DrinkMePile,
     is_orc: bool,
     is_troll: bool,
+    // Latched the first time the player forfeits, goes broke or passes out, so that later stat
+    // changes can never bring them back into the game.
+    is_eliminated: bool,
+    // False until the player's starting hand has been drawn, which may be after the game starts
+    // if the game uses `lazy_starting_hands`.
+    has_starting_hand: bool,
+    stat_change_events: VecDeque<StatChangeEvent>,
+    // Events that `GameLogic` hasn't passed on to the players' views yet.
+    transient_events: Vec<TransientEvent>,
+    // Snapshots of the player's stats taken at the start of each of their turns, oldest first.
+    stat_snapshots: VecDeque<StatSnapshot>,
+    max_fortitude: i32,
+    max_alcohol_content: i32,
+    // How many of the player's upcoming turns will be passed over.
+    turns_to_skip: usize,
 }
 
 impl Player {
-    pub fn create_from_character(character: Character, gold: i32) -> Self {
+    pub fn create_from_character(
+        character: Character,
+        gold: i32,
+        config: &GameConfig,
+        deck_seed: u64,
+    ) -> Self {
         Self::new(
             gold,
             character.create_deck(),
             character.is_orc(),
             character.is_troll(),
+            config,
+            deck_seed,
         )
     }
 
-    fn new(gold: i32, deck: Vec<PlayerCard>, is_orc: bool, is_troll: bool) -> Self {
+    fn new(
+        gold: i32,
+        deck: Vec<PlayerCard>,
+        is_orc: bool,
+        is_troll: bool,
+        config: &GameConfig,
+        deck_seed: u64,
+    ) -> Self {
         let mut player = Self {
             alcohol_content: 0,
-            fortitude: 20,
+            fortitude: STARTING_FORTITUDE.min(config.max_fortitude),
             gold,
             hand: Vec::new(),
-            deck: AutoShufflingDeck::new(deck),
+            deck: AutoShufflingDeck::new_seeded(deck, deck_seed),
             drink_me_pile: DrinkMePile {
                 drink_cards: Vec::new(),
             },
             is_orc,
             is_troll,
+            is_eliminated: false,
+            has_starting_hand: false,
+            stat_change_events: VecDeque::new(),
+            transient_events: Vec::new(),
+            stat_snapshots: VecDeque::new(),
+            max_fortitude: config.max_fortitude,
+            max_alcohol_content: config.max_alcohol_content,
+            turns_to_skip: 0,
         };
-        player.draw_to_full();
+        if !config.lazy_starting_hands {
+            player.draw_starting_hand_if_needed();
+        }
         player
     }
 
     pub fn to_game_view_player_data(&self, player_uuid: PlayerUUID) -> GameViewPlayerData {
         GameViewPlayerData {
             player_uuid,
-            draw_pile_size: self.deck.draw_pile_size(),
+            draw_pile_size: self.deck.draw_pile_size() - self.get_undrawn_starting_hand_size(),
             discard_pile_size: self.deck.discard_pile_size(),
+            removed_pile_size: self.deck.removed_pile_size(),
             drink_me_pile_size: self.drink_me_pile.drink_cards.len(),
             alcohol_content: self.alcohol_content,
             fortitude: self.fortitude,
             gold: self.gold,
             is_dead: self.is_out_of_game(),
+            alcohol_content_history: self
+                .stat_snapshots
+                .iter()
+                .map(|snapshot| snapshot.alcohol_content)
+                .collect(),
+            fortitude_history: self
+                .stat_snapshots
+                .iter()
+                .map(|snapshot| snapshot.fortitude)
+                .collect(),
+            gold_history: self
+                .stat_snapshots
+                .iter()
+                .map(|snapshot| snapshot.gold)
+                .collect(),
         }
     }
 
+    /// Starting a round of gambling makes the player ante, so a player without any gold can't
+    /// start one.
+    pub fn can_afford_to_play(
+        &self,
+        card: &PlayerCard,
+        gambling_manager: &GamblingManager,
+    ) -> bool {
+        self.gold > 0 || !card.would_start_gambling_round(gambling_manager)
+    }
+
     pub fn get_game_view_hand(
         &self,
         player_uuid: &PlayerUUID,
@@ -67,7 +163,7 @@ impl Player {
         interrupt_manager: &InterruptManager,
         turn_info: &TurnInfo,
     ) -> Vec<GameViewPlayerCard> {
-        self.hand
+        self.get_hand()
             .iter()
             .map(|card| GameViewPlayerCard {
                 card_name: card.get_display_name().to_string(),
@@ -77,20 +173,70 @@ impl Player {
                     gambling_manager,
                     interrupt_manager,
                     turn_info,
-                ),
-                is_directed: match card {
-                    PlayerCard::RootPlayerCard(root_player_card) => {
-                        root_player_card.get_target_style() == TargetStyle::SingleOtherPlayer
-                    }
-                    PlayerCard::InterruptPlayerCard(_) => false,
-                },
+                ) && self.can_afford_to_play(card, gambling_manager),
+                is_directed: card.is_directed(),
+                target_style: card
+                    .get_target_style_name_or()
+                    .map(|target_style_name| target_style_name.to_string()),
+            })
+            .collect()
+    }
+
+    pub fn get_game_view_stat_change_events(&self) -> Vec<GameViewStatChangeEvent> {
+        self.stat_change_events
+            .iter()
+            .map(|event| GameViewStatChangeEvent {
+                amount: event.amount,
+                stat: event.stat,
+                reason: event.reason.clone(),
             })
             .collect()
     }
 
+    #[cfg(test)]
+    pub fn has_starting_hand(&self) -> bool {
+        self.has_starting_hand
+    }
+
+    /// Draws the player's starting hand, unless it has already been drawn.
+    pub fn draw_starting_hand_if_needed(&mut self) {
+        if !self.has_starting_hand {
+            self.has_starting_hand = true;
+            self.draw_to_full();
+        }
+    }
+
     pub fn draw_to_full(&mut self) {
-        while self.hand.len() < 7 {
-            self.hand.push(self.deck.draw_card().unwrap());
+        while self.hand.len() < HAND_SIZE {
+            // The deck only runs dry if there are fewer cards in total than a full hand,
+            // in which case the player keeps a partial hand.
+            match self.deck.draw_card() {
+                Some(card) => self.hand.push(card),
+                None => break,
+            }
+        }
+    }
+
+    /// The player's hand. If their starting hand hasn't been drawn yet, these are the cards that
+    /// it will be drawn from, so the hand looks the same whether or not it has been drawn.
+    pub fn get_hand(&self) -> Cow<'_, [PlayerCard]> {
+        if self.has_starting_hand {
+            Cow::Borrowed(&self.hand)
+        } else {
+            Cow::Owned(
+                (0..self.get_undrawn_starting_hand_size())
+                    .filter_map(|depth| self.deck.peek_card(depth))
+                    .collect(),
+            )
+        }
+    }
+
+    /// How many cards the starting hand will take from the draw pile once it's drawn.
+    fn get_undrawn_starting_hand_size(&self) -> usize {
+        if self.has_starting_hand {
+            0
+        } else {
+            HAND_SIZE.min(self.deck.draw_pile_size())
         }
     }
 
@@ -104,6 +250,13 @@ impl Player {
         }
     }
 
+    /// None of the implemented characters are orcs, so this is the only way to get one.
+    #[cfg(test)]
+    pub fn create_test_orc(config: &GameConfig) -> Self {
+        Self::new(8, Vec::new(), true, false, config, 0)
+    }
+
+    #[cfg(test)]
     pub fn return_card_to_hand(&mut self, card: PlayerCard, mut card_index: usize) {
         if card_index > self.hand.len() {
             card_index = self.hand.len();
@@ -112,10 +265,50 @@ impl Player {
         self.hand.insert(card_index, card);
     }
 
+    /// Replaces the player's hand with one that was saved earlier.
+    pub fn restore_hand(&mut self, hand: Vec<PlayerCard>) {
+        self.hand = hand;
+    }
+
+    /// Reorders the player's hand so that the card at `card_order[i]` ends up at index `i`.
+    /// Returns an error and leaves the hand untouched if `card_order` is not a permutation of the current hand indices.
+    pub fn arrange_hand(&mut self, card_order: Vec<usize>) -> Result<(), Error> {
+        let mut sorted_card_order = card_order.clone();
+        sorted_card_order.sort_unstable();
+        if !sorted_card_order.into_iter().eq(0..self.hand.len()) {
+            return Err(Error::new(
+                "Hand arrangement must contain each card index exactly once",
+            ));
+        }
+
+        let mut cards: Vec<Option<PlayerCard>> = self.hand.drain(..).map(Some).collect();
+        // Will never panic since `card_order` was verified to be a permutation above.
+        self.hand = card_order
+            .into_iter()
+            .map(|card_index| cards[card_index].take().unwrap())
+            .collect();
+        Ok(())
+    }
+
     pub fn discard_card(&mut self, card: PlayerCard) {
         self.deck.discard_card(card);
     }
 
+    /// Number of cards the player can draw before their discard pile is shuffled back into their
+    /// deck.
+    pub fn get_cards_until_reshuffle(&self) -> usize {
+        self.deck.draw_pile_size() - self.get_undrawn_starting_hand_size()
+    }
+
+    pub fn reshuffle_deck(&mut self) {
+        self.deck.reshuffle();
+    }
+
+    /// Takes the player's most recently discarded card out of the game for good.
+    pub fn remove_top_discarded_card_from_game(&mut self) {
+        self.deck.remove_top_discarded_card();
+    }
+
     pub fn is_orc(&self) -> bool {
         self.is_orc
     }
@@ -129,44 +322,89 @@ impl Player {
     }
 
     pub fn reveal_drink_from_drink_pile(&mut self) -> Option<RevealedDrink> {
-        get_revealed_drink(&mut self.drink_me_pile)
+        let revealed_drink_or = get_revealed_drink(&mut self.drink_me_pile);
+        if let Some(revealed_drink) = &revealed_drink_or {
+            let name = match revealed_drink {
+                RevealedDrink::DrinkWithPossibleChasers(drink) => drink.get_display_name(),
+                RevealedDrink::DrinkEvent(drink_event) => {
+                    drink_event.get_display_name().to_string()
+                }
+            };
+            self.record_transient_event(TransientEvent::DrinkRevealed { name });
+        }
+        revealed_drink_or
     }
 
-    pub fn change_alcohol_content(&mut self, amount: i32) {
-        self.alcohol_content += amount;
-        if self.alcohol_content > 20 {
-            self.alcohol_content = 20;
-        } else if self.alcohol_content < 0 {
-            self.alcohol_content = 0;
-        }
+    pub fn change_alcohol_content(&mut self, amount: i32, reason: impl ToString) {
+        let alcohol_content_before_change = self.alcohol_content;
+        self.alcohol_content = add_to_stat(self.alcohol_content, amount, self.max_alcohol_content);
+        self.record_stat_change_event(
+            self.alcohol_content - alcohol_content_before_change,
+            Stat::AlcoholContent,
+            reason,
+        );
+        self.latch_elimination();
     }
 
     pub fn get_fortitude(&self) -> i32 {
         self.fortitude
     }
 
-    pub fn change_fortitude(&mut self, amount: i32) {
-        self.fortitude += amount;
-        if self.fortitude > 20 {
-            self.fortitude = 20;
-        } else if self.fortitude < 0 {
-            self.fortitude = 0;
+    pub fn change_fortitude(&mut self, amount: i32, reason: impl ToString) {
+        let fortitude_before_change = self.fortitude;
+        self.fortitude = add_to_stat(self.fortitude, amount, self.max_fortitude);
+        self.record_stat_change_event(
+            self.fortitude - fortitude_before_change,
+            Stat::Fortitude,
+            reason,
+        );
+        if self.fortitude != fortitude_before_change {
+            self.record_transient_event(TransientEvent::FortitudeChanged {
+                amount: self.fortitude - fortitude_before_change,
+            });
         }
+        self.latch_elimination();
     }
 
     pub fn get_gold(&self) -> i32 {
         self.gold
     }
 
-    pub fn change_gold(&mut self, amount: i32) {
-        self.gold += amount;
-        if self.gold < 0 {
-            self.gold = 0;
-        }
+    pub fn change_gold(&mut self, amount: i32, reason: impl ToString) {
+        let gold_before_change = self.gold;
+        // Gold has no cap.
+        self.gold = add_to_stat(self.gold, amount, i32::MAX);
+        self.record_stat_change_event(self.gold - gold_before_change, Stat::Gold, reason);
+        self.latch_elimination();
+    }
+
+    pub fn forfeit(&mut self) {
+        self.is_eliminated = true;
     }
 
+    /// Once a player is out of the game they stay out, even if their stats change afterwards.
     pub fn is_out_of_game(&self) -> bool {
-        self.is_broke() || self.is_passed_out()
+        self.is_eliminated
+    }
+
+    /// Makes the player miss their next turn. Stacks if they are already due to miss a turn.
+    pub fn skip_next_turn(&mut self) {
+        self.turns_to_skip += 1;
+    }
+
+    /// Uses up one of the player's skipped turns. Returns false if they aren't due to miss a turn.
+    pub fn take_skipped_turn(&mut self) -> bool {
+        if self.turns_to_skip == 0 {
+            return false;
+        }
+        self.turns_to_skip -= 1;
+        true
+    }
+
+    fn latch_elimination(&mut self) {
+        if self.is_broke() || self.is_passed_out() {
+            self.is_eliminated = true;
+        }
     }
 
     fn is_broke(&self) -> bool {
@@ -176,6 +414,81 @@ impl Player {
     fn is_passed_out(&self) -> bool {
         self.alcohol_content >= self.get_fortitude()
     }
+
+    /// Remembers the player's current stats. Should be called at the start of each of their turns.
+    pub fn record_stat_snapshot(&mut self) {
+        if self.stat_snapshots.len() >= MAX_STAT_SNAPSHOTS {
+            self.stat_snapshots.pop_front();
+        }
+        self.stat_snapshots.push_back(StatSnapshot {
+            alcohol_content: self.alcohol_content,
+            fortitude: self.fortitude,
+            gold: self.gold,
+        });
+    }
+
+    pub fn record_transient_event(&mut self, event: TransientEvent) {
+        self.transient_events.push(event);
+    }
+
+    /// Returns the transient events recorded since this was last called, oldest first.
+    pub fn take_transient_events(&mut self) -> Vec<TransientEvent> {
+        std::mem::take(&mut self.transient_events)
+    }
+
+    /// Records how much a stat actually changed by, after clamping. Changes that were clamped
+    /// away entirely aren't recorded.
+    fn record_stat_change_event(&mut self, amount: i32, stat: Stat, reason: impl ToString) {
+        if amount == 0 {
+            return;
+        }
+        if self.stat_change_events.len() >= MAX_STAT_CHANGE_EVENTS {
+            self.stat_change_events.pop_front();
+        }
+        self.stat_change_events.push_back(StatChangeEvent {
+            amount,
+            stat,
+            reason: reason.to_string(),
+        });
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Stat {
+    AlcoholContent,
+    Fortitude,
+    Gold,
+}
+
+/// Something that happened to a player which the client may want to animate.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+#[serde(tag = "eventType", rename_all = "camelCase")]
+pub enum TransientEvent {
+    DrinkRevealed { name: String },
+    DrinkDrunk { name: String },
+    FortitudeChanged { amount: i32 },
+    PotAwarded { amount: i32 },
+}
+
+#[derive(Clone, Debug)]
+struct StatChangeEvent {
+    amount: i32,
+    stat: Stat,
+    reason: String,
+}
+
+#[derive(Clone, Debug)]
+struct StatSnapshot {
+    alcohol_content: i32,
+    fortitude: i32,
+    gold: i32,
+}
+
+/// Adds `amount` to a stat, keeping the result between 0 and `max`. Saturates rather than
+/// overflowing, however large `amount` is.
+fn add_to_stat(stat: i32, amount: i32, max: i32) -> i32 {
+    stat.saturating_add(amount).clamp(0, max)
 }
 
 #[derive(Clone, Debug)]
@@ -188,3 +501,181 @@ impl DrinkDeck for DrinkMePile {
         self.drink_cards.pop()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::player_card::{
+        change_other_player_fortitude_card, gambling_im_in_card, i_dont_think_so_card,
+    };
+    use super::*;
+
+    #[test]
+    fn extreme_stat_changes_saturate_instead_of_overflowing() {
+        let mut player =
+            Player::create_from_character(Character::Fiona, 8, &GameConfig::default(), 0);
+
+        player.change_gold(i32::MAX, "Test");
+        player.change_gold(i32::MAX, "Test");
+        assert_eq!(player.get_gold(), i32::MAX);
+        player.change_alcohol_content(i32::MAX, "Test");
+        assert_eq!(player.alcohol_content, 20);
+        player.change_fortitude(i32::MAX, "Test");
+        assert_eq!(player.get_fortitude(), 20);
+
+        player.change_gold(i32::MIN, "Test");
+        assert_eq!(player.get_gold(), 0);
+        player.change_alcohol_content(i32::MIN, "Test");
+        assert_eq!(player.alcohol_content, 0);
+        player.change_fortitude(i32::MIN, "Test");
+        assert_eq!(player.get_fortitude(), 0);
+    }
+
+    #[test]
+    fn stat_change_events_record_clamped_amount() {
+        let mut player =
+            Player::create_from_character(Character::Fiona, 8, &GameConfig::default(), 0);
+        player.change_fortitude(-18, "Test");
+        player.stat_change_events.clear();
+
+        player.change_fortitude(-5, "Punch in the face");
+        player.change_fortitude(-5, "Punch in the face");
+        player.change_gold(-100, "Robbed");
+
+        let stat_change_events = player.get_game_view_stat_change_events();
+        assert_eq!(stat_change_events.len(), 2);
+        assert_eq!(stat_change_events[0].amount, -2);
+        assert_eq!(stat_change_events[0].stat, Stat::Fortitude);
+        assert_eq!(stat_change_events[1].amount, -8);
+        assert_eq!(stat_change_events[1].stat, Stat::Gold);
+        assert_eq!(
+            serde_json::to_value(&stat_change_events[1]).unwrap()["stat"],
+            "gold"
+        );
+        assert_eq!(
+            serde_json::to_value(Stat::AlcoholContent).unwrap(),
+            "alcoholContent"
+        );
+    }
+
+    #[test]
+    fn stat_caps_come_from_config() {
+        let config = GameConfig {
+            max_fortitude: 30,
+            max_alcohol_content: 30,
+            ..GameConfig::default()
+        };
+        let mut player = Player::new(8, Vec::new(), false, false, &config, 0);
+        assert_eq!(player.get_fortitude(), 20);
+
+        // Fortitude can exceed the standard cap of 20.
+        player.change_fortitude(15, "Test");
+        assert_eq!(player.get_fortitude(), 30);
+
+        // Alcohol content above 20 isn't enough to pass out when fortitude is higher.
+        player.change_alcohol_content(25, "Test");
+        assert_eq!(player.alcohol_content, 25);
+        assert!(!player.is_out_of_game());
+
+        player.change_alcohol_content(10, "Test");
+        assert_eq!(player.alcohol_content, 30);
+        assert!(player.is_out_of_game());
+    }
+
+    #[test]
+    fn eliminated_player_stays_out_of_game() {
+        let mut player = Player::new(8, Vec::new(), false, false, &GameConfig::default(), 0);
+        player.change_alcohol_content(20, "Test");
+        assert!(player.is_out_of_game());
+
+        // Sobering up doesn't bring the player back.
+        player.change_alcohol_content(-20, "Test");
+        assert_eq!(player.alcohol_content, 0);
+        assert!(player.is_out_of_game());
+
+        // Neither does winning gold back after going broke.
+        let mut player = Player::new(8, Vec::new(), false, false, &GameConfig::default(), 0);
+        player.change_gold(-8, "Test");
+        player.change_gold(5, "Test");
+        assert_eq!(player.get_gold(), 5);
+        assert!(player.is_out_of_game());
+    }
+
+    #[test]
+    fn stats_are_capped_at_20_by_default() {
+        let mut player = Player::new(8, Vec::new(), false, false, &GameConfig::default(), 0);
+        player.change_fortitude(15, "Test");
+        assert_eq!(player.get_fortitude(), 20);
+        player.change_alcohol_content(25, "Test");
+        assert_eq!(player.alcohol_content, 20);
+        assert!(player.is_out_of_game());
+    }
+
+    #[test]
+    fn draw_to_full_stops_when_deck_is_exhausted() {
+        let deck: Vec<PlayerCard> = (0..3).map(|_| gambling_im_in_card().into()).collect();
+        let mut player = Player::new(8, deck, false, false, &GameConfig::default(), 0);
+        assert_eq!(player.hand.len(), 3);
+        assert_eq!(player.deck.draw_pile_size(), 0);
+        assert_eq!(player.deck.discard_pile_size(), 0);
+
+        // Drawing again with an empty deck should be a no-op.
+        player.draw_to_full();
+        assert_eq!(player.hand.len(), 3);
+    }
+
+    #[test]
+    fn cards_until_reshuffle_counts_down_to_reshuffle() {
+        let deck: Vec<PlayerCard> = (0..10).map(|_| gambling_im_in_card().into()).collect();
+        let mut player = Player::new(8, deck, false, false, &GameConfig::default(), 0);
+        assert_eq!(player.get_cards_until_reshuffle(), 3);
+
+        let discard_and_draw = |player: &mut Player| {
+            let card = player.pop_card_from_hand(0).unwrap();
+            player.discard_card(card);
+            player.draw_to_full();
+        };
+        for cards_until_reshuffle in [2, 1, 0] {
+            discard_and_draw(&mut player);
+            assert_eq!(player.get_cards_until_reshuffle(), cards_until_reshuffle);
+        }
+
+        // The next draw shuffles the 4 discarded cards back in and draws one of them.
+        discard_and_draw(&mut player);
+        assert_eq!(player.get_cards_until_reshuffle(), 3);
+        assert_eq!(player.deck.discard_pile_size(), 0);
+    }
+
+    #[test]
+    fn draw_to_full_with_empty_deck() {
+        let player = Player::new(8, Vec::new(), false, false, &GameConfig::default(), 0);
+        assert!(player.hand.is_empty());
+    }
+
+    #[test]
+    fn game_view_hand_includes_target_style() {
+        let mut player = Player::new(8, Vec::new(), false, false, &GameConfig::default(), 0);
+        player.return_card_to_hand(gambling_im_in_card().into(), 0);
+        player.return_card_to_hand(change_other_player_fortitude_card("Punch", -2).into(), 1);
+        player.return_card_to_hand(i_dont_think_so_card().into(), 2);
+        let player_uuid = PlayerUUID::new();
+
+        let game_view_hand = player.get_game_view_hand(
+            &player_uuid,
+            &GamblingManager::new(),
+            &InterruptManager::new(),
+            &TurnInfo::new_test(player_uuid.clone()),
+        );
+        let target_styles: Vec<serde_json::Value> = game_view_hand
+            .iter()
+            .map(|card| serde_json::to_value(card).unwrap()["targetStyle"].clone())
+            .collect();
+        assert_eq!(
+            target_styles,
+            vec![
+                serde_json::json!("allOtherPlayers"),
+                serde_json::json!("singleOtherPlayer"),
+                serde_json::Value::Null
+            ]
+        );
+    }
+}
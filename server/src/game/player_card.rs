@@ -31,6 +31,48 @@ impl PlayerCard {
         }
     }
 
+    /// Whether the card must be directed at another player when played.
+    pub fn is_directed(&self) -> bool {
+        match &self {
+            Self::RootPlayerCard(root_player_card) => {
+                root_player_card.get_target_style() == TargetStyle::SingleOtherPlayer
+            }
+            Self::InterruptPlayerCard(_) => false,
+        }
+    }
+
+    /// Who the card affects, for clients to decide whether to prompt for a target.
+    /// Is `None` for interrupt cards, which always respond to the current interrupt.
+    pub fn get_target_style_name_or(&self) -> Option<&'static str> {
+        match &self {
+            Self::RootPlayerCard(root_player_card) => {
+                Some(match root_player_card.get_target_style() {
+                    TargetStyle::SelfPlayer => "self",
+                    TargetStyle::SingleOtherPlayer => "singleOtherPlayer",
+                    TargetStyle::AllOtherPlayers => "allOtherPlayers",
+                    TargetStyle::AllGamblingPlayersIncludingSelf => {
+                        "allGamblingPlayersIncludingSelf"
+                    }
+                })
+            }
+            Self::InterruptPlayerCard(_) => None,
+        }
+    }
+
+    pub fn get_card_type_name(&self) -> &'static str {
+        match &self {
+            Self::RootPlayerCard(root_player_card) => match root_player_card.card_type {
+                RootPlayerCardType::Action => "action",
+                RootPlayerCardType::ActionGambling => "actionGambling",
+                RootPlayerCardType::Anytime => "anytime",
+                RootPlayerCardType::Gambling => "gambling",
+                RootPlayerCardType::Cheating => "cheating",
+                RootPlayerCardType::Sometimes => "sometimes",
+            },
+            Self::InterruptPlayerCard(_) => "interrupt",
+        }
+    }
+
     pub fn can_play(
         &self,
         player_uuid: &PlayerUUID,
@@ -46,23 +88,17 @@ impl PlayerCard {
                 turn_info,
             ),
             Self::InterruptPlayerCard(interrupt_player_card) => {
-                let current_interrupt = match interrupt_manager.get_current_interrupt() {
-                    Some(current_interrupt) => current_interrupt,
-                    None => return false,
-                };
-
-                if let GameInterruptType::SometimesCardPlayed(player_card_info) = current_interrupt
-                {
-                    if player_card_info.is_i_dont_think_so_card
-                        && !interrupt_player_card.is_i_dont_think_so_card
-                    {
-                        return false;
-                    }
-                }
+                interrupt_player_card.can_play(player_uuid, interrupt_manager)
+            }
+        }
+    }
 
-                interrupt_player_card.can_interrupt(current_interrupt)
-                    && interrupt_manager.is_turn_to_interrupt(player_uuid)
+    pub fn would_start_gambling_round(&self, gambling_manager: &GamblingManager) -> bool {
+        match &self {
+            Self::RootPlayerCard(root_player_card) => {
+                root_player_card.would_start_gambling_round(gambling_manager)
             }
+            Self::InterruptPlayerCard(_) => false,
         }
     }
 }
@@ -149,6 +185,17 @@ impl RootPlayerCard {
         }
     }
 
+    pub fn is_cheating_card(&self) -> bool {
+        self.card_type == RootPlayerCardType::Cheating
+    }
+
+    /// Whether playing this card right now would start a new round of gambling, rather than
+    /// taking control of the current one.
+    pub fn would_start_gambling_round(&self, gambling_manager: &GamblingManager) -> bool {
+        self.card_type == RootPlayerCardType::ActionGambling
+            && !gambling_manager.round_in_progress()
+    }
+
     pub fn can_play(
         &self,
         player_uuid: &PlayerUUID,
@@ -280,6 +327,22 @@ impl InterruptPlayerCard {
         (self.can_interrupt_fn)(current_interrupt)
     }
 
+    /// Whether the player can respond to the current interrupt with this card right now.
+    pub fn can_play(&self, player_uuid: &PlayerUUID, interrupt_manager: &InterruptManager) -> bool {
+        let current_interrupt = match interrupt_manager.get_current_interrupt() {
+            Some(current_interrupt) => current_interrupt,
+            None => return false,
+        };
+
+        if let GameInterruptType::SometimesCardPlayed(player_card_info) = current_interrupt {
+            if player_card_info.is_i_dont_think_so_card && !self.is_i_dont_think_so_card {
+                return false;
+            }
+        }
+
+        self.can_interrupt(current_interrupt) && interrupt_manager.is_turn_to_interrupt(player_uuid)
+    }
+
     pub fn get_interrupt_type_output(&self) -> GameInterruptType {
         self.interrupt_type_output
     }
@@ -297,6 +360,10 @@ impl InterruptPlayerCard {
 pub enum ShouldCancelPreviousCard {
     Negate,
     Ignore,
+    // Doesn't cancel the previous card, but passes the drink being resolved to the next player.
+    RedirectDrink,
+    // Doesn't cancel the previous card, but replaces the drink being resolved with the top card of the drink deck.
+    SwapDrink,
     No,
 }
 
@@ -472,8 +539,10 @@ pub fn change_other_player_fortitude_card(
     display_name: impl ToString,
     amount: i32,
 ) -> RootPlayerCard {
+    let display_name = display_name.to_string();
+    let reason = display_name.clone();
     RootPlayerCard {
-        display_name: display_name.to_string(),
+        display_name,
         display_description: get_change_other_player_fortitude_card_description(amount),
         card_type: RootPlayerCardType::Action,
         target_style: TargetStyle::SingleOtherPlayer,
@@ -493,7 +562,170 @@ pub fn change_other_player_fortitude_card(
                 if let Some(targeted_player) =
                     player_manager.get_player_by_uuid_mut(targeted_player_uuid)
                 {
-                    targeted_player.change_fortitude(amount);
+                    targeted_player.change_fortitude(amount, &reason);
+                }
+            },
+        ),
+        interrupt_data_or: Some(RootPlayerCardInterruptData {
+            interrupt_type_output: GameInterruptType::DirectedActionCardPlayed(PlayerCardInfo {
+                affects_fortitude: true,
+                is_i_dont_think_so_card: false,
+            }),
+            post_interrupt_play_fn_or: None,
+        }),
+    }
+}
+
+// TODO - I only added this card to Gerki's deck when I implemented this function.
+pub fn steal_gold_card(display_name: impl ToString, amount: i32) -> RootPlayerCard {
+    let display_name = display_name.to_string();
+    let reason = display_name.clone();
+    RootPlayerCard {
+        display_name,
+        display_description: format!("Take {} Gold from another player.", amount),
+        card_type: RootPlayerCardType::Action,
+        target_style: TargetStyle::SingleOtherPlayer,
+        can_play_fn: |player_uuid: &PlayerUUID,
+                      gambling_manager: &GamblingManager,
+                      _interrupt_manager: &InterruptManager,
+                      turn_info: &TurnInfo|
+         -> bool {
+            turn_info.can_play_action_card(player_uuid, gambling_manager)
+        },
+        pre_interrupt_play_fn_or: None,
+        interrupt_play_fn: Arc::from(
+            move |player_uuid: &PlayerUUID,
+                  targeted_player_uuid: &PlayerUUID,
+                  player_manager: &mut PlayerManager,
+                  _gambling_manager: &mut GamblingManager| {
+                player_manager.steal_gold(player_uuid, targeted_player_uuid, amount, &reason);
+            },
+        ),
+        interrupt_data_or: Some(RootPlayerCardInterruptData {
+            interrupt_type_output: GameInterruptType::DirectedActionCardPlayed(PlayerCardInfo {
+                affects_fortitude: false,
+                is_i_dont_think_so_card: false,
+            }),
+            post_interrupt_play_fn_or: None,
+        }),
+    }
+}
+
+// TODO - I only added this card to Fiona's deck when I implemented this function.
+pub fn skip_next_turn_card(display_name: impl ToString) -> RootPlayerCard {
+    RootPlayerCard {
+        display_name: display_name.to_string(),
+        display_description: String::from("Pick another player. They skip their next turn."),
+        card_type: RootPlayerCardType::Action,
+        target_style: TargetStyle::SingleOtherPlayer,
+        can_play_fn: |player_uuid: &PlayerUUID,
+                      gambling_manager: &GamblingManager,
+                      _interrupt_manager: &InterruptManager,
+                      turn_info: &TurnInfo|
+         -> bool {
+            turn_info.can_play_action_card(player_uuid, gambling_manager)
+        },
+        pre_interrupt_play_fn_or: None,
+        interrupt_play_fn: Arc::from(
+            |_player_uuid: &PlayerUUID,
+             targeted_player_uuid: &PlayerUUID,
+             player_manager: &mut PlayerManager,
+             _gambling_manager: &mut GamblingManager| {
+                if let Some(targeted_player) =
+                    player_manager.get_player_by_uuid_mut(targeted_player_uuid)
+                {
+                    targeted_player.skip_next_turn();
+                }
+            },
+        ),
+        interrupt_data_or: Some(RootPlayerCardInterruptData {
+            interrupt_type_output: GameInterruptType::DirectedActionCardPlayed(PlayerCardInfo {
+                affects_fortitude: false,
+                is_i_dont_think_so_card: false,
+            }),
+            post_interrupt_play_fn_or: None,
+        }),
+    }
+}
+
+// TODO - I only added this card to Gerki's deck when I implemented this function.
+pub fn remove_other_player_discarded_card(display_name: impl ToString) -> RootPlayerCard {
+    RootPlayerCard {
+        display_name: display_name.to_string(),
+        display_description: String::from(
+            "Pick another player. The top card of their discard pile is removed from the game.",
+        ),
+        card_type: RootPlayerCardType::Action,
+        target_style: TargetStyle::SingleOtherPlayer,
+        can_play_fn: |player_uuid: &PlayerUUID,
+                      gambling_manager: &GamblingManager,
+                      _interrupt_manager: &InterruptManager,
+                      turn_info: &TurnInfo|
+         -> bool {
+            turn_info.can_play_action_card(player_uuid, gambling_manager)
+        },
+        pre_interrupt_play_fn_or: None,
+        interrupt_play_fn: Arc::from(
+            |_player_uuid: &PlayerUUID,
+             targeted_player_uuid: &PlayerUUID,
+             player_manager: &mut PlayerManager,
+             _gambling_manager: &mut GamblingManager| {
+                if let Some(targeted_player) =
+                    player_manager.get_player_by_uuid_mut(targeted_player_uuid)
+                {
+                    targeted_player.remove_top_discarded_card_from_game();
+                }
+            },
+        ),
+        interrupt_data_or: Some(RootPlayerCardInterruptData {
+            interrupt_type_output: GameInterruptType::DirectedActionCardPlayed(PlayerCardInfo {
+                affects_fortitude: false,
+                is_i_dont_think_so_card: false,
+            }),
+            post_interrupt_play_fn_or: None,
+        }),
+    }
+}
+
+// TODO - I only added this card to Zot's deck when I implemented this function.
+pub fn swap_fortitude_card(display_name: impl ToString) -> RootPlayerCard {
+    let display_name = display_name.to_string();
+    let reason = display_name.clone();
+    RootPlayerCard {
+        display_name,
+        display_description: String::from("Pick another player. Swap Fortitude with them."),
+        card_type: RootPlayerCardType::Action,
+        target_style: TargetStyle::SingleOtherPlayer,
+        can_play_fn: |player_uuid: &PlayerUUID,
+                      gambling_manager: &GamblingManager,
+                      _interrupt_manager: &InterruptManager,
+                      turn_info: &TurnInfo|
+         -> bool {
+            turn_info.can_play_action_card(player_uuid, gambling_manager)
+        },
+        pre_interrupt_play_fn_or: None,
+        interrupt_play_fn: Arc::from(
+            move |player_uuid: &PlayerUUID,
+                  targeted_player_uuid: &PlayerUUID,
+                  player_manager: &mut PlayerManager,
+                  _gambling_manager: &mut GamblingManager| {
+                let (player_fortitude, targeted_player_fortitude) = match (
+                    player_manager.get_player_by_uuid(player_uuid),
+                    player_manager.get_player_by_uuid(targeted_player_uuid),
+                ) {
+                    (Some(player), Some(targeted_player)) => {
+                        (player.get_fortitude(), targeted_player.get_fortitude())
+                    }
+                    _ => return,
+                };
+                let difference = targeted_player_fortitude - player_fortitude;
+                if let Some(player) = player_manager.get_player_by_uuid_mut(player_uuid) {
+                    player.change_fortitude(difference, &reason);
+                }
+                if let Some(targeted_player) =
+                    player_manager.get_player_by_uuid_mut(targeted_player_uuid)
+                {
+                    targeted_player.change_fortitude(-difference, &reason);
                 }
             },
         ),
@@ -522,8 +754,10 @@ pub fn change_all_other_player_fortitude_card(
     display_name: impl ToString,
     amount: i32,
 ) -> RootPlayerCard {
+    let display_name = display_name.to_string();
+    let reason = display_name.clone();
     RootPlayerCard {
-        display_name: display_name.to_string(),
+        display_name,
         display_description: get_change_all_other_player_fortitude_card_description(amount),
         card_type: RootPlayerCardType::Action,
         target_style: TargetStyle::AllOtherPlayers,
@@ -543,7 +777,7 @@ pub fn change_all_other_player_fortitude_card(
                 if let Some(targeted_player) =
                     player_manager.get_player_by_uuid_mut(targeted_player_uuid)
                 {
-                    targeted_player.change_fortitude(amount);
+                    targeted_player.change_fortitude(amount, &reason);
                 }
             },
         ),
@@ -586,8 +820,10 @@ pub fn ignore_root_card_affecting_fortitude(display_name: impl ToString) -> Inte
 }
 
 pub fn gain_fortitude_anytime_card(display_name: impl ToString, amount: i32) -> RootPlayerCard {
+    let display_name = display_name.to_string();
+    let reason = display_name.clone();
     RootPlayerCard {
-        display_name: display_name.to_string(),
+        display_name,
         display_description: format!("Gain {} Fortitude.", amount),
         card_type: RootPlayerCardType::Anytime,
         target_style: TargetStyle::SelfPlayer,
@@ -602,7 +838,40 @@ pub fn gain_fortitude_anytime_card(display_name: impl ToString, amount: i32) ->
                   _gambling_manager: &mut GamblingManager,
                   _turn_info: &mut TurnInfo| {
                 if let Some(player) = player_manager.get_player_by_uuid_mut(player_uuid) {
-                    player.change_fortitude(amount)
+                    player.change_fortitude(amount, &reason)
+                }
+                ShouldInterrupt::No
+            },
+        )),
+        interrupt_play_fn: Arc::from(
+            |_player_uuid: &PlayerUUID,
+             _targeted_player_uuid: &PlayerUUID,
+             _player_manager: &mut PlayerManager,
+             _gambling_manager: &mut GamblingManager| {},
+        ),
+        interrupt_data_or: None,
+    }
+}
+
+// TODO - I only added this card to Fiona's deck when I implemented this function.
+pub fn reshuffle_deck_anytime_card(display_name: impl ToString) -> RootPlayerCard {
+    RootPlayerCard {
+        display_name: display_name.to_string(),
+        display_description: String::from("Shuffle your discard pile back into your deck."),
+        card_type: RootPlayerCardType::Anytime,
+        target_style: TargetStyle::SelfPlayer,
+        can_play_fn: |_player_uuid: &PlayerUUID,
+                      _gambling_manager: &GamblingManager,
+                      _interrupt_manager: &InterruptManager,
+                      _turn_info: &TurnInfo|
+         -> bool { true },
+        pre_interrupt_play_fn_or: Some(Arc::from(
+            |player_uuid: &PlayerUUID,
+             player_manager: &mut PlayerManager,
+             _gambling_manager: &mut GamblingManager,
+             _turn_info: &mut TurnInfo| {
+                if let Some(player) = player_manager.get_player_by_uuid_mut(player_uuid) {
+                    player.reshuffle_deck();
                 }
                 ShouldInterrupt::No
             },
@@ -666,7 +935,11 @@ pub fn oh_i_guess_the_wench_thought_that_was_her_tip_card() -> RootPlayerCard {
                       interrupt_manager: &InterruptManager,
                       _turn_info: &TurnInfo|
          -> bool {
-            gambling_manager.round_in_progress() && !interrupt_manager.interrupt_in_progress()
+            gambling_manager.round_in_progress()
+                // Can't be played in response to a card that makes players ante.
+                && !interrupt_manager.is_resolving_ante()
+                // Nor while any other card is resolving, since the Round would end underneath it.
+                && !interrupt_manager.interrupt_in_progress()
         },
         pre_interrupt_play_fn_or: Some(Arc::from(
             move |_player_uuid: &PlayerUUID,
@@ -736,6 +1009,54 @@ pub fn ignore_drink_card(display_name: impl ToString) -> InterruptPlayerCard {
     }
 }
 
+// TODO - Add this card for all characters other than Fiona. I only added the card to Fiona's deck when I implemented this function.
+pub fn redirect_drink_card(display_name: impl ToString) -> InterruptPlayerCard {
+    InterruptPlayerCard {
+        display_name: display_name.to_string(),
+        display_description: String::from(
+            "Pass a Drink to the player on your left.\n(Reveal the Drink first!)",
+        ),
+        can_interrupt_fn: Arc::from(|current_interrupt| {
+            matches!(current_interrupt, GameInterruptType::AboutToDrink)
+        }),
+        interrupt_type_output: GameInterruptType::SometimesCardPlayed(PlayerCardInfo {
+            affects_fortitude: false,
+            is_i_dont_think_so_card: false,
+        }),
+        interrupt_fn: Arc::from(
+            |_player_uuid: &PlayerUUID,
+             _interrupt_manager: &InterruptManager,
+             _gambling_manager: &mut GamblingManager|
+             -> ShouldCancelPreviousCard { ShouldCancelPreviousCard::RedirectDrink },
+        ),
+        is_i_dont_think_so_card: false,
+    }
+}
+
+// TODO - I only added this card to Gerki's deck when I implemented this function.
+pub fn swap_drink_card(display_name: impl ToString) -> InterruptPlayerCard {
+    InterruptPlayerCard {
+        display_name: display_name.to_string(),
+        display_description: String::from(
+            "Swap a Drink for the top card of the Drink deck.\n(Reveal the Drink first!)",
+        ),
+        can_interrupt_fn: Arc::from(|current_interrupt| {
+            matches!(current_interrupt, GameInterruptType::AboutToDrink)
+        }),
+        interrupt_type_output: GameInterruptType::SometimesCardPlayed(PlayerCardInfo {
+            affects_fortitude: false,
+            is_i_dont_think_so_card: false,
+        }),
+        interrupt_fn: Arc::from(
+            |_player_uuid: &PlayerUUID,
+             _interrupt_manager: &InterruptManager,
+             _gambling_manager: &mut GamblingManager|
+             -> ShouldCancelPreviousCard { ShouldCancelPreviousCard::SwapDrink },
+        ),
+        is_i_dont_think_so_card: false,
+    }
+}
+
 pub fn leave_gambling_round_instead_of_anteing_card(
     display_name: impl ToString,
 ) -> InterruptPlayerCard {
@@ -2,15 +2,34 @@ use super::player::Player;
 use super::player_card::PlayerCard;
 use super::player_view::GameViewPlayerData;
 use super::uuid::PlayerUUID;
-use super::Character;
+use super::{Character, GameConfig};
+use rand::Rng;
 
 #[derive(Clone, Debug)]
 pub struct PlayerManager {
     players: Vec<(PlayerUUID, Player)>,
+    // Players who are out of the game, grouped by when they went out. Players in the same group
+    // were eliminated at the same time.
+    eliminations: Vec<Vec<PlayerUUID>>,
+    gold_to_win: Option<i32>,
 }
 
 impl PlayerManager {
+    #[cfg(test)]
     pub fn new(players_with_characters: Vec<(PlayerUUID, Character)>) -> Self {
+        Self::new_with_config(
+            players_with_characters,
+            &GameConfig::default(),
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Each player's deck is shuffled with a seed drawn from `rng`.
+    pub fn new_with_config(
+        players_with_characters: Vec<(PlayerUUID, Character)>,
+        config: &GameConfig,
+        rng: &mut impl Rng,
+    ) -> Self {
         let player_count = players_with_characters.len();
 
         PlayerManager {
@@ -22,13 +41,72 @@ impl PlayerManager {
                         Player::create_from_character(
                             character,
                             Self::get_starting_gold_amount_for_player_count(player_count),
+                            config,
+                            rng.gen(),
                         ),
                     )
                 })
                 .collect(),
+            eliminations: Vec::new(),
+            gold_to_win: config.gold_to_win,
         }
     }
 
+    /// Checks for players that are newly out of the game and records them as eliminated.
+    /// Should be called whenever a player's stats may have changed.
+    pub fn record_eliminations(&mut self) {
+        let eliminated_player_uuids = self.get_eliminated_player_uuids();
+        let newly_eliminated_player_uuids: Vec<PlayerUUID> = self
+            .players
+            .iter()
+            .filter(|(player_uuid, player)| {
+                player.is_out_of_game() && !eliminated_player_uuids.contains(player_uuid)
+            })
+            .map(|(player_uuid, _)| player_uuid.clone())
+            .collect();
+        if !newly_eliminated_player_uuids.is_empty() {
+            self.eliminations.push(newly_eliminated_player_uuids);
+        }
+    }
+
+    pub fn get_eliminated_player_uuids(&self) -> Vec<PlayerUUID> {
+        self.eliminations.iter().flatten().cloned().collect()
+    }
+
+    /// Players grouped by their final placing, best first. Players in the same group are tied,
+    /// which includes everyone who was still in the game if they all went out at once.
+    /// Empty while the game is running.
+    pub fn get_standings(&self) -> Vec<Vec<PlayerUUID>> {
+        let mut standings = match self.get_running_state() {
+            GameRunningState::Running => return Vec::new(),
+            GameRunningState::Finished(Some(winner_uuid)) => vec![vec![winner_uuid]],
+            GameRunningState::Finished(None) => Vec::new(),
+        };
+        // Players can still be standing if someone won by reaching the gold threshold.
+        let other_remaining_player_uuids: Vec<PlayerUUID> = self
+            .players
+            .iter()
+            .filter(|(player_uuid, player)| {
+                !player.is_out_of_game()
+                    && !standings.iter().flatten().any(|uuid| uuid == player_uuid)
+            })
+            .map(|(player_uuid, _)| player_uuid.clone())
+            .collect();
+        if !other_remaining_player_uuids.is_empty() {
+            standings.push(other_remaining_player_uuids);
+        }
+        standings.extend(self.eliminations.iter().rev().cloned());
+        standings
+    }
+
+    pub fn clone_uuids_of_all_players(&self) -> Vec<PlayerUUID> {
+        self.players
+            .iter()
+            .map(|(player_uuid, _)| player_uuid)
+            .cloned()
+            .collect()
+    }
+
     pub fn clone_uuids_of_all_alive_players(&self) -> Vec<PlayerUUID> {
         self.players
             .iter()
@@ -67,6 +145,34 @@ impl PlayerManager {
         }
     }
 
+    /// Moves up to `amount` gold from one player to another. Players can't lose more gold than
+    /// they have, so only what the victim actually had is moved. Returns the amount moved.
+    pub fn steal_gold(
+        &mut self,
+        thief_uuid: &PlayerUUID,
+        victim_uuid: &PlayerUUID,
+        amount: i32,
+        reason: &str,
+    ) -> i32 {
+        if self.get_player_by_uuid(thief_uuid).is_none() {
+            return 0;
+        }
+
+        let stolen_amount = match self.get_player_by_uuid_mut(victim_uuid) {
+            Some(victim) => {
+                let gold_before_theft = victim.get_gold();
+                victim.change_gold(-amount.max(0), reason);
+                gold_before_theft - victim.get_gold()
+            }
+            None => return 0,
+        };
+
+        if let Some(thief) = self.get_player_by_uuid_mut(thief_uuid) {
+            thief.change_gold(stolen_amount, reason);
+        }
+        stolen_amount
+    }
+
     pub fn get_next_alive_player_uuid<'a>(
         &'a self,
         player_uuid: &PlayerUUID,
@@ -107,6 +213,17 @@ impl PlayerManager {
     }
 
     pub fn get_running_state(&self) -> GameRunningState {
+        if let Some(gold_to_win) = self.gold_to_win {
+            if let Some((player_uuid, _)) = self
+                .players
+                .iter()
+                .filter(|(_, player)| !player.is_out_of_game() && player.get_gold() >= gold_to_win)
+                .max_by_key(|(_, player)| player.get_gold())
+            {
+                return GameRunningState::Finished(Some(player_uuid.clone()));
+            }
+        }
+
         let mut remaining_player_uuids = Vec::new();
         for (player_uuid, player) in self.players.iter() {
             if !player.is_out_of_game() {
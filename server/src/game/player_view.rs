@@ -1,4 +1,8 @@
-use super::{game_logic::TurnPhase, GameUUID, PlayerUUID};
+use super::{
+    game_logic::{GameEvent, LegalAction, TurnPhase},
+    player::{Stat, TransientEvent},
+    GameUUID, PlayerUUID,
+};
 use serde::Serialize;
 use std::cmp::{Ord, Ordering, PartialOrd};
 use std::collections::HashMap;
@@ -10,6 +14,44 @@ pub struct GameViewPlayerCard {
     pub card_description: String,
     pub is_playable: bool,
     pub is_directed: bool,
+    pub target_style: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardCatalogEntry {
+    pub card_name: String,
+    pub card_description: String,
+    pub card_type: String,
+}
+
+pub struct CardCatalog {
+    pub cards: Vec<CardCatalogEntry>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CharacterDeckPreviewEntry {
+    pub card_name: String,
+    pub card_description: String,
+    pub card_count: usize,
+}
+
+pub struct CharacterDeckPreview {
+    pub cards: Vec<CharacterDeckPreviewEntry>,
+}
+
+pub struct GameLog {
+    pub events: Vec<GameEvent>,
+}
+
+/// Results of all finished games played under a single display name.
+#[derive(Clone, Copy, Default, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerStats {
+    pub games_played: usize,
+    pub wins: usize,
+    pub losses: usize,
 }
 
 #[derive(Serialize)]
@@ -18,11 +60,33 @@ pub struct GameViewPlayerData {
     pub player_uuid: PlayerUUID,
     pub draw_pile_size: usize,
     pub discard_pile_size: usize,
+    pub removed_pile_size: usize,
     pub drink_me_pile_size: usize,
     pub alcohol_content: i32,
     pub fortitude: i32,
     pub gold: i32,
     pub is_dead: bool,
+    // Stats at the start of each of the player's most recent turns, oldest first.
+    pub alcohol_content_history: Vec<i32>,
+    pub fortitude_history: Vec<i32>,
+    pub gold_history: Vec<i32>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameViewStatChangeEvent {
+    pub amount: i32,
+    pub stat: Stat,
+    pub reason: String,
+}
+
+/// A transient event, along with the player it happened to.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameViewTransientEvent {
+    pub player_uuid: PlayerUUID,
+    #[serde(flatten)]
+    pub event: TransientEvent,
 }
 
 #[derive(Serialize)]
@@ -32,6 +96,17 @@ pub struct GameViewDrinkEvent {
     pub drinking_contest_remaining_player_uuids: Option<Vec<PlayerUUID>>,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameViewGamblingData {
+    pub pot_amount: i32,
+    pub active_player_uuids: Vec<PlayerUUID>,
+    pub current_player_turn: PlayerUUID,
+    // The player who currently controls the round, and who wins the pot if nobody takes control.
+    pub winning_player_uuid: PlayerUUID,
+    pub need_cheating_card_to_take_next_control: bool,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GameViewInterruptData {
@@ -51,6 +126,18 @@ pub struct GameViewInterruptStack {
 pub struct GameViewInterruptStackRootItem {
     pub name: String,
     pub item_type: String,
+    // Is `None` unless the root item is a drink.
+    pub drink_preview: Option<GameViewDrinkPreview>,
+}
+
+/// What a drink will do to the player who is about to drink it. Drinks such as Orcish Rotgut
+/// affect some characters differently, so this is worked out on the server.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameViewDrinkPreview {
+    pub player_uuid: PlayerUUID,
+    pub alcohol_content_change: i32,
+    pub fortitude_change: i32,
 }
 
 #[derive(Serialize)]
@@ -58,16 +145,107 @@ pub struct GameViewInterruptStackRootItem {
 pub struct GameView {
     pub game_name: String,
     pub self_player_uuid: PlayerUUID,
+    // The player who can configure and start the game. If they leave before the game starts, the
+    // next player to have joined becomes the owner.
+    pub owner_uuid: Option<PlayerUUID>,
     pub current_turn_player_uuid: Option<PlayerUUID>,
     pub current_turn_phase: Option<TurnPhase>,
     pub can_pass: bool,
+    /// Why the player can't pass right now. Only set when `can_pass` is false.
+    pub cannot_pass_reason: Option<String>,
+    pub legal_actions: Vec<LegalAction>,
+    pub available_interrupt_responses: Vec<String>,
     pub hand: Vec<GameViewPlayerCard>,
+    // How many more cards this player can draw before their discard pile is reshuffled.
+    pub cards_until_reshuffle: Option<usize>,
     pub player_data: Vec<GameViewPlayerData>,
     pub player_display_names: HashMap<PlayerUUID, String>,
+    pub player_readiness: HashMap<PlayerUUID, bool>,
+    pub player_connection_statuses: HashMap<PlayerUUID, bool>,
+    pub stat_change_events: Vec<GameViewStatChangeEvent>,
+    // Events since this player last fetched their view, oldest first, so that the client can
+    // animate them. Each event is only sent once.
+    pub recent_transient_events: Vec<GameViewTransientEvent>,
     pub interrupts: Option<GameViewInterruptData>,
+    pub awaiting_interrupt: bool,
+    // Is `None` unless a gambling round is in progress.
+    pub gambling: Option<GameViewGamblingData>,
     pub drink_event: Option<GameViewDrinkEvent>,
+    pub phase: GamePhase,
     pub is_running: bool,
     pub winner_uuid: Option<PlayerUUID>,
+    pub eliminated_player_uuids: Vec<PlayerUUID>,
+    /// Players grouped by final placing, best first. Players in the same group are tied.
+    pub standings: Vec<Vec<PlayerUUID>>,
+    /// The seed that decided every shuffle, so that players can verify the game was fair. Only
+    /// revealed once the game has finished, since it could be used to predict draws. Sent as a
+    /// string because JavaScript numbers can't hold every `u64`.
+    pub seed: Option<String>,
+}
+
+/// Where a game is in its lifecycle, from the perspective of its players.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub enum GamePhase {
+    /// Players are choosing characters and readying up.
+    Lobby,
+    Running,
+    /// The game has ended and its final standings can be shown.
+    Finished,
+}
+
+/// A smaller alternative to `GameView` for clients that poll often. Only contains what the
+/// player needs to see for themselves.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerStateView {
+    pub self_player_uuid: PlayerUUID,
+    pub current_turn_player_uuid: Option<PlayerUUID>,
+    pub current_turn_phase: Option<TurnPhase>,
+    pub hand: Vec<GameViewPlayerCard>,
+    pub player_data: Option<GameViewPlayerData>,
+    // How many more cards the player can draw before their discard pile is reshuffled.
+    pub cards_until_reshuffle: Option<usize>,
+}
+
+/// The parts of a game that anyone watching it can see. Unlike `GameView`, no player's hand is
+/// included.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableView {
+    pub game_name: String,
+    pub current_turn_player_uuid: Option<PlayerUUID>,
+    pub current_turn_phase: Option<TurnPhase>,
+    pub player_data: Vec<GameViewPlayerData>,
+    pub player_display_names: HashMap<PlayerUUID, String>,
+    pub gambling: Option<GameViewGamblingData>,
+    pub phase: GamePhase,
+    pub winner_uuid: Option<PlayerUUID>,
+    pub standings: Vec<Vec<PlayerUUID>>,
+}
+
+/// Table views of several games, keyed by game uuid. Games that don't exist are left out.
+pub struct TableViewCollection {
+    pub table_views: HashMap<GameUUID, TableView>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardTarget {
+    pub player_uuid: PlayerUUID,
+    pub display_name: String,
+}
+
+pub struct CardTargets {
+    pub targets: Vec<CardTarget>,
+}
+
+/// Whether a card in the player's hand can be played right now.
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CardPlayability {
+    pub playable: bool,
+    /// Why the card can't be played. Only set when `playable` is false.
+    pub reason: Option<String>,
 }
 
 #[derive(Serialize, PartialEq, Eq)]
@@ -76,21 +254,50 @@ pub struct ListedGameView {
     pub game_name: String,
     pub game_uuid: GameUUID,
     pub player_count: usize,
+    pub is_running: bool,
+    /// False once the game is running or full.
+    pub is_joinable: bool,
+    pub requires_password: bool,
 }
 
 pub struct ListedGameViewCollection {
     pub listed_game_views: Vec<ListedGameView>,
 }
 
+/// Live counts for monitoring.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerMetrics {
+    pub game_count: usize,
+    pub running_game_count: usize,
+    pub player_count: usize,
+    pub uptime_seconds: u64,
+}
+
+/// Server-wide counts for operators. Not shown to players.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminOverview {
+    /// Every signed-in player, including those who aren't in a game.
+    pub player_count: usize,
+    pub players_not_in_game_count: usize,
+    pub game_count: usize,
+    pub running_game_count: usize,
+    pub games: Vec<ListedGameView>,
+}
+
 impl PartialOrd for ListedGameView {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.game_name.partial_cmp(&other.game_name)
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for ListedGameView {
+    // Games with the same name are ordered by uuid so that they don't swap places between listings.
     fn cmp(&self, other: &Self) -> Ordering {
-        self.game_name.cmp(&other.game_name)
+        self.game_name
+            .cmp(&other.game_name)
+            .then_with(|| self.game_uuid.cmp(&other.game_uuid))
     }
 }
 
@@ -116,3 +323,75 @@ impl_to_json_string_responder!(
     |collection: ListedGameViewCollection| collection.listed_game_views
 );
 impl_to_json_string_responder!(GameView, |game_view: GameView| game_view);
+impl_to_json_string_responder!(PlayerStateView, |player_state_view: PlayerStateView| {
+    player_state_view
+});
+impl_to_json_string_responder!(TableViewCollection, |collection: TableViewCollection| {
+    collection.table_views
+});
+impl_to_json_string_responder!(CardTargets, |card_targets: CardTargets| card_targets
+    .targets);
+impl_to_json_string_responder!(CardPlayability, |card_playability: CardPlayability| {
+    card_playability
+});
+impl_to_json_string_responder!(CardCatalog, |card_catalog: CardCatalog| card_catalog.cards);
+impl_to_json_string_responder!(
+    CharacterDeckPreview,
+    |character_deck_preview: CharacterDeckPreview| character_deck_preview.cards
+);
+impl_to_json_string_responder!(GameLog, |game_log: GameLog| game_log.events);
+impl_to_json_string_responder!(PlayerStats, |player_stats: PlayerStats| player_stats);
+impl_to_json_string_responder!(ServerMetrics, |server_metrics: ServerMetrics| {
+    server_metrics
+});
+impl_to_json_string_responder!(AdminOverview, |admin_overview: AdminOverview| {
+    admin_overview
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn listed_games_with_the_same_name_are_ordered_by_uuid() {
+        let game_uuids: Vec<GameUUID> = (0..5).map(|_| GameUUID::new()).collect();
+        let mut sorted_game_uuids = game_uuids.clone();
+        sorted_game_uuids.sort();
+
+        let listed_game_view = |game_name: &str, game_uuid: &GameUUID| ListedGameView {
+            game_name: game_name.to_string(),
+            game_uuid: game_uuid.clone(),
+            player_count: 1,
+            is_running: false,
+            is_joinable: true,
+            requires_password: false,
+        };
+        let mut listed_game_views: Vec<ListedGameView> = game_uuids
+            .iter()
+            .map(|game_uuid| listed_game_view("Same Name", game_uuid))
+            .collect();
+        listed_game_views.push(listed_game_view("Another Name", &game_uuids[0]));
+        listed_game_views.sort();
+
+        assert_eq!(listed_game_views[0].game_name, "Another Name");
+        assert_eq!(
+            listed_game_views[1..]
+                .iter()
+                .map(|listed_game_view| listed_game_view.game_uuid.clone())
+                .collect::<Vec<GameUUID>>(),
+            sorted_game_uuids
+        );
+
+        // Sorting in a different starting order gives the same result.
+        listed_game_views.reverse();
+        listed_game_views.sort();
+        assert_eq!(listed_game_views[0].game_name, "Another Name");
+        assert_eq!(
+            listed_game_views[1..]
+                .iter()
+                .map(|listed_game_view| listed_game_view.game_uuid.clone())
+                .collect::<Vec<GameUUID>>(),
+            sorted_game_uuids
+        );
+    }
+}
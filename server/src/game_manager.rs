@@ -1,13 +1,54 @@
-use super::game::player_view::{GameView, ListedGameView, ListedGameViewCollection};
-use super::game::{Error, Game, GameUUID, PlayerUUID};
+use super::game::player_view::{
+    AdminOverview, CardPlayability, CardTargets, GameLog, GameView, ListedGameView,
+    ListedGameViewCollection, PlayerStateView, PlayerStats, ServerMetrics, TableViewCollection,
+};
+use super::game::{Error, Game, GameConfig, GameUUID, PlayerUUID};
 use super::Character;
+use log::{info, warn};
 use std::collections::HashMap;
 use std::sync::RwLock;
+use std::time::{Duration, Instant};
 
+/// Maximum number of signed-in players that aren't in a game.
+/// Keeps repeated sign-ins from growing the player map without bound.
+const DEFAULT_MAX_PLAYERS_NOT_IN_GAME: usize = 10000;
+/// How long a player must be out of a game before they can be evicted to make room for new players.
+const DEFAULT_PLAYER_IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+/// Maximum number of games that can exist at once across the whole server.
+/// Keeps players from creating games faster than empty ones are cleaned up.
+const DEFAULT_MAX_GAMES: usize = 1000;
+/// How long a player has to respond to an interrupt before they automatically pass.
+const DEFAULT_INTERRUPT_RESPONSE_TIMEOUT: Duration = Duration::from_secs(60);
+/// How long a finished game can still be viewed after its last player leaves.
+const DEFAULT_FINISHED_GAME_TTL: Duration = Duration::from_secs(10 * 60);
+/// How recently a player must have made a request to be shown as connected.
+const PLAYER_CONNECTED_TIMEOUT: Duration = Duration::from_secs(30);
+/// Longest display name that a player can sign in with, in characters.
+const MAX_DISPLAY_NAME_LENGTH: usize = 32;
+/// Longest name that a game can be renamed to, in characters.
+const MAX_GAME_NAME_LENGTH: usize = 48;
+
+/// Each game has its own lock, and every in-game action only locks the game that it affects.
+/// So the manager itself only needs to be write-locked when players sign in or out, or when
+/// they create, join or leave a game.
 pub struct GameManager {
     games_by_game_id: HashMap<GameUUID, RwLock<Game>>,
+    // Finished games that everyone has left, along with when the last player left. Kept for
+    // `finished_game_ttl` so that the final standings can still be reviewed.
+    finished_games: HashMap<GameUUID, (Game, Instant)>,
+    finished_game_ttl: Duration,
     player_uuids_to_game_id: HashMap<PlayerUUID, GameUUID>,
     player_uuids_to_display_names: HashMap<PlayerUUID, String>,
+    // When each player last made a request, signed in, or left a game.
+    // Behind its own lock so that requests can be recorded without write-locking the whole manager.
+    player_uuids_to_last_seen_times: RwLock<HashMap<PlayerUUID, Instant>>,
+    max_players_not_in_game: usize,
+    player_idle_timeout: Duration,
+    max_games: usize,
+    interrupt_response_timeout: Duration,
+    // Keyed by display name so that stats carry over between sessions.
+    display_names_to_player_stats: RwLock<HashMap<String, PlayerStats>>,
+    created_at: Instant,
 }
 
 impl GameManager {
@@ -15,7 +56,16 @@ impl GameManager {
         Self {
             player_uuids_to_display_names: HashMap::new(),
             games_by_game_id: HashMap::new(),
+            finished_games: HashMap::new(),
+            finished_game_ttl: DEFAULT_FINISHED_GAME_TTL,
             player_uuids_to_game_id: HashMap::new(),
+            player_uuids_to_last_seen_times: RwLock::new(HashMap::new()),
+            max_players_not_in_game: DEFAULT_MAX_PLAYERS_NOT_IN_GAME,
+            player_idle_timeout: DEFAULT_PLAYER_IDLE_TIMEOUT,
+            max_games: DEFAULT_MAX_GAMES,
+            interrupt_response_timeout: DEFAULT_INTERRUPT_RESPONSE_TIMEOUT,
+            display_names_to_player_stats: RwLock::new(HashMap::new()),
+            created_at: Instant::now(),
         }
     }
 
@@ -23,6 +73,16 @@ impl GameManager {
         &mut self,
         player_uuid: PlayerUUID,
         display_name: String,
+    ) -> Result<(), Error> {
+        let result = self.add_player_unlogged(player_uuid.clone(), display_name);
+        log_action("add_player", None, &player_uuid, &result);
+        result
+    }
+
+    fn add_player_unlogged(
+        &mut self,
+        player_uuid: PlayerUUID,
+        display_name: String,
     ) -> Result<(), Error> {
         if self
             .player_uuids_to_display_names
@@ -30,45 +90,205 @@ impl GameManager {
         {
             return Err(Error::new("Player already exists"));
         }
+        let display_name = validate_display_name(&display_name)?;
+        if self.get_player_count_not_in_game() >= self.max_players_not_in_game {
+            self.evict_longest_idle_player()?;
+        }
+        self.player_uuids_to_last_seen_times
+            .get_mut()
+            .unwrap()
+            .insert(player_uuid.clone(), Instant::now());
         self.player_uuids_to_display_names
             .insert(player_uuid, display_name);
         Ok(())
     }
 
     pub fn remove_player(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        let result = self.remove_player_unlogged(player_uuid);
+        log_action("remove_player", None, player_uuid, &result);
+        result
+    }
+
+    fn remove_player_unlogged(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
         self.assert_player_exists(player_uuid)?;
         if self.player_is_in_game(player_uuid) {
             self.leave_game(player_uuid)?;
         }
         self.player_uuids_to_display_names.remove(player_uuid);
+        self.player_uuids_to_last_seen_times
+            .get_mut()
+            .unwrap()
+            .remove(player_uuid);
         Ok(())
     }
 
+    /// Records that the player just made a request. Does nothing if the player isn't signed in.
+    pub fn touch_player(&self, player_uuid: &PlayerUUID) {
+        if self.player_uuids_to_display_names.contains_key(player_uuid) {
+            self.player_uuids_to_last_seen_times
+                .write()
+                .unwrap()
+                .insert(player_uuid.clone(), Instant::now());
+        }
+    }
+
+    /// Passes for whoever is holding up an interrupt in the player's game if they have taken
+    /// longer than `interrupt_response_timeout` to respond. Does nothing if the player isn't in a
+    /// game, or if they are the one holding up the interrupt.
+    pub fn auto_pass_expired_interrupt(&self, player_uuid: &PlayerUUID) {
+        let game = match self.get_game_of_player(player_uuid) {
+            Ok(game) => game,
+            Err(_) => return,
+        };
+        // Most requests have nothing to do here, so the game is only locked for writing when a
+        // deadline needs to be started or has expired.
+        if !game
+            .read()
+            .unwrap()
+            .needs_interrupt_deadline_update(player_uuid, self.interrupt_response_timeout)
+        {
+            return;
+        }
+        let mut unlocked_game = game.write().unwrap();
+        if let Some(idle_player_uuid) =
+            unlocked_game.auto_pass_expired_interrupt(player_uuid, self.interrupt_response_timeout)
+        {
+            log_action::<()>(
+                "auto_pass_interrupt",
+                self.player_uuids_to_game_id.get(player_uuid),
+                &idle_player_uuid,
+                &Ok(()),
+            );
+            self.record_finished_game(&mut unlocked_game);
+        }
+    }
+
+    fn player_is_connected_at(&self, player_uuid: &PlayerUUID, now: Instant) -> bool {
+        match self
+            .player_uuids_to_last_seen_times
+            .read()
+            .unwrap()
+            .get(player_uuid)
+        {
+            Some(last_seen_time) => {
+                now.saturating_duration_since(*last_seen_time) < PLAYER_CONNECTED_TIMEOUT
+            }
+            None => false,
+        }
+    }
+
+    fn get_player_count_not_in_game(&self) -> usize {
+        self.player_uuids_to_display_names
+            .keys()
+            .filter(|player_uuid| !self.player_is_in_game(player_uuid))
+            .count()
+    }
+
+    /// Removes the player that has been out of a game the longest, as long as they've been idle
+    /// for at least `player_idle_timeout`. Players in a game are never evicted.
+    fn evict_longest_idle_player(&mut self) -> Result<(), Error> {
+        let player_uuids_to_last_seen_times =
+            self.player_uuids_to_last_seen_times.get_mut().unwrap();
+        let longest_idle_player_uuid_or = self
+            .player_uuids_to_display_names
+            .keys()
+            .filter(|player_uuid| !self.player_uuids_to_game_id.contains_key(player_uuid))
+            .filter(|player_uuid| {
+                match player_uuids_to_last_seen_times.get(player_uuid) {
+                    Some(last_seen_time) => last_seen_time.elapsed() >= self.player_idle_timeout,
+                    // Should never happen, but a player without a last seen time can't be active.
+                    None => true,
+                }
+            })
+            .min_by_key(|player_uuid| player_uuids_to_last_seen_times.get(player_uuid))
+            .cloned();
+
+        match longest_idle_player_uuid_or {
+            Some(player_uuid) => self.remove_player(&player_uuid),
+            None => Err(Error::new("Too many players are signed in")),
+        }
+    }
+
+    pub fn get_metrics(&self) -> ServerMetrics {
+        let admin_overview = self.admin_overview();
+        ServerMetrics {
+            game_count: admin_overview.game_count,
+            running_game_count: admin_overview.running_game_count,
+            player_count: admin_overview.player_count,
+            uptime_seconds: self.created_at.elapsed().as_secs(),
+        }
+    }
+
     pub fn get_player_display_name(&self, player_uuid: &PlayerUUID) -> Option<&String> {
         self.player_uuids_to_display_names.get(player_uuid)
     }
 
     pub fn list_games(&self) -> ListedGameViewCollection {
+        ListedGameViewCollection {
+            listed_game_views: self.get_sorted_listed_game_views(),
+        }
+    }
+
+    fn get_sorted_listed_game_views(&self) -> Vec<ListedGameView> {
         let mut listed_game_views: Vec<ListedGameView> = self
             .games_by_game_id
             .iter()
             .map(|(game_uuid, game)| game.read().unwrap().get_listed_game_view(game_uuid.clone()))
             .collect();
         listed_game_views.sort();
-        ListedGameViewCollection { listed_game_views }
+        listed_game_views
+    }
+
+    pub fn admin_overview(&self) -> AdminOverview {
+        let games = self.get_sorted_listed_game_views();
+        AdminOverview {
+            player_count: self.player_uuids_to_display_names.len(),
+            players_not_in_game_count: self.get_player_count_not_in_game(),
+            game_count: games.len(),
+            running_game_count: games
+                .iter()
+                .filter(|listed_game_view| listed_game_view.is_running)
+                .count(),
+            games,
+        }
     }
 
+    /// Creates a game with the player in it. If a password is given, other players need it to join.
     pub fn create_game(
         &mut self,
         player_uuid: PlayerUUID,
         game_name: String,
+        password_or: Option<String>,
+    ) -> Result<GameUUID, Error> {
+        let result = self.create_game_unlogged(player_uuid.clone(), game_name, password_or);
+        log_action("create_game", result.as_ref().ok(), &player_uuid, &result);
+        result
+    }
+
+    fn create_game_unlogged(
+        &mut self,
+        player_uuid: PlayerUUID,
+        game_name: String,
+        password_or: Option<String>,
     ) -> Result<GameUUID, Error> {
+        self.remove_expired_finished_games();
+        self.remove_stale_game_mapping(&player_uuid);
         if self.player_uuids_to_game_id.contains_key(&player_uuid) {
             return Err(Error::new("Player is already in a game"));
         }
         self.assert_player_exists(&player_uuid)?;
+        let game_name = validate_game_name(&game_name)?;
+        if self.games_by_game_id.len() >= self.max_games {
+            return Err(Error::new(
+                "Too many games are in progress, please try again later",
+            ));
+        }
         let game_id = GameUUID::new();
         let mut game = Game::new(game_name);
+        // An empty password comes from leaving the password field blank, so the game stays public.
+        if let Some(password) = password_or.filter(|password| !password.is_empty()) {
+            game.set_password(&password);
+        }
         game.join(player_uuid.clone())?;
         self.games_by_game_id
             .insert(game_id.clone(), RwLock::from(game));
@@ -77,8 +297,25 @@ impl GameManager {
         Ok(game_id)
     }
 
-    pub fn join_game(&mut self, player_uuid: PlayerUUID, game_id: GameUUID) -> Result<(), Error> {
+    pub fn join_game(
+        &mut self,
+        player_uuid: PlayerUUID,
+        game_id: GameUUID,
+        password_or: Option<&str>,
+    ) -> Result<(), Error> {
+        let result = self.join_game_unlogged(player_uuid.clone(), game_id.clone(), password_or);
+        log_action("join_game", Some(&game_id), &player_uuid, &result);
+        result
+    }
+
+    fn join_game_unlogged(
+        &mut self,
+        player_uuid: PlayerUUID,
+        game_id: GameUUID,
+        password_or: Option<&str>,
+    ) -> Result<(), Error> {
         self.assert_player_exists(&player_uuid)?;
+        self.remove_stale_game_mapping(&player_uuid);
         if self.player_uuids_to_game_id.contains_key(&player_uuid) {
             return Err(Error::new("Player is already in a game"));
         }
@@ -86,7 +323,10 @@ impl GameManager {
             Some(game) => game,
             None => return Err(Error::new("Game does not exist")),
         };
-        game.write().unwrap().join(player_uuid.clone())?;
+        let mut unlocked_game = game.write().unwrap();
+        unlocked_game.check_password(password_or)?;
+        unlocked_game.join(player_uuid.clone())?;
+        drop(unlocked_game);
         self.player_uuids_to_game_id.insert(player_uuid, game_id);
         Ok(())
     }
@@ -95,8 +335,34 @@ impl GameManager {
         self.player_uuids_to_game_id.contains_key(player_uuid)
     }
 
+    /// Drops the player's game mapping if it points at a game that no longer exists.
+    /// Returns true if a stale mapping was removed.
+    fn remove_stale_game_mapping(&mut self, player_uuid: &PlayerUUID) -> bool {
+        let is_stale = match self.player_uuids_to_game_id.get(player_uuid) {
+            Some(game_id) => !self.games_by_game_id.contains_key(game_id),
+            None => false,
+        };
+        if is_stale {
+            self.player_uuids_to_game_id.remove(player_uuid);
+        }
+        is_stale
+    }
+
     pub fn leave_game(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        // The player's game mapping is gone after leaving, so grab the game ID beforehand.
+        let game_id_or = self.player_uuids_to_game_id.get(player_uuid).cloned();
+        let result = self.leave_game_unlogged(player_uuid);
+        log_action("leave_game", game_id_or.as_ref(), player_uuid, &result);
+        result
+    }
+
+    fn leave_game_unlogged(&mut self, player_uuid: &PlayerUUID) -> Result<(), Error> {
         self.assert_player_exists(player_uuid)?;
+        self.remove_expired_finished_games();
+        if self.remove_stale_game_mapping(player_uuid) {
+            // The game is already gone, so there's nothing left to leave.
+            return Ok(());
+        }
         let game_id = match self.player_uuids_to_game_id.get(player_uuid) {
             Some(game_id) => game_id,
             None => return Err(Error::new("Player is not in a game")),
@@ -111,18 +377,88 @@ impl GameManager {
             unlocked_game.is_empty()
         };
         if game_is_empty {
-            self.games_by_game_id.remove(game_id);
+            if let Some(game) = self.games_by_game_id.remove(game_id) {
+                let game = game.into_inner().unwrap();
+                // Empty games that never finished have nothing worth reviewing, so they're dropped right away.
+                if game.is_finished() {
+                    self.finished_games
+                        .insert(game_id.clone(), (game, Instant::now()));
+                }
+            }
         }
         self.player_uuids_to_game_id.remove(player_uuid);
+        self.player_uuids_to_last_seen_times
+            .get_mut()
+            .unwrap()
+            .insert(player_uuid.clone(), Instant::now());
+        Ok(())
+    }
+
+    fn remove_expired_finished_games(&mut self) {
+        let finished_game_ttl = self.finished_game_ttl;
+        self.finished_games
+            .retain(|_, (_, emptied_time)| emptied_time.elapsed() < finished_game_ttl);
+    }
+
+    pub fn kick_player(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        kicked_player_uuid: &PlayerUUID,
+    ) -> Result<(), Error> {
+        let game_id_or = self.player_uuids_to_game_id.get(player_uuid).cloned();
+        let result = self.kick_player_unlogged(player_uuid, kicked_player_uuid);
+        log_action("kick_player", game_id_or.as_ref(), player_uuid, &result);
+        result
+    }
+
+    fn kick_player_unlogged(
+        &mut self,
+        player_uuid: &PlayerUUID,
+        kicked_player_uuid: &PlayerUUID,
+    ) -> Result<(), Error> {
+        self.get_game_of_player(player_uuid)?
+            .write()
+            .unwrap()
+            .kick_player(player_uuid, kicked_player_uuid)?;
+        self.player_uuids_to_game_id.remove(kicked_player_uuid);
+        self.player_uuids_to_last_seen_times
+            .get_mut()
+            .unwrap()
+            .insert(kicked_player_uuid.clone(), Instant::now());
         Ok(())
     }
 
     pub fn start_game(&self, player_uuid: &PlayerUUID) -> Result<(), Error> {
-        let game = match self.get_game_of_player(player_uuid) {
-            Ok(game) => game,
-            Err(error) => return Err(error),
-        };
-        game.write().unwrap().start(player_uuid)
+        self.with_game_of_player("start_game", player_uuid, |game| {
+            game.start(player_uuid, &self.player_uuids_to_display_names)
+        })
+    }
+
+    pub fn abort_game(&self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        self.with_game_of_player("abort_game", player_uuid, |game| game.abort(player_uuid))
+    }
+
+    pub fn set_game_config(
+        &self,
+        player_uuid: &PlayerUUID,
+        config: GameConfig,
+    ) -> Result<(), Error> {
+        self.with_game_of_player("set_game_config", player_uuid, |game| {
+            game.set_config(player_uuid, config)
+        })
+    }
+
+    pub fn rename_game(&self, player_uuid: &PlayerUUID, game_name: &str) -> Result<(), Error> {
+        let game_name_or = validate_game_name(game_name);
+        self.with_game_of_player("rename_game", player_uuid, |game| {
+            game.rename(player_uuid, game_name_or?)
+        })
+    }
+
+    pub fn toggle_ready(&self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        self.with_game_of_player("toggle_ready", player_uuid, |game| {
+            game.toggle_ready(player_uuid)
+        })
     }
 
     pub fn select_character(
@@ -130,13 +466,9 @@ impl GameManager {
         player_uuid: &PlayerUUID,
         character: Character,
     ) -> Result<(), Error> {
-        let game = match self.get_game_of_player(player_uuid) {
-            Ok(game) => game,
-            Err(error) => return Err(error),
-        };
-        game.write()
-            .unwrap()
-            .select_character(player_uuid, character)
+        self.with_game_of_player("select_character", player_uuid, |game| {
+            game.select_character(player_uuid, character)
+        })
     }
 
     fn assert_player_exists(&self, player_uuid: &PlayerUUID) -> Result<(), Error> {
@@ -151,63 +483,272 @@ impl GameManager {
         player_uuid: &PlayerUUID,
         other_player_uuid_or: &Option<PlayerUUID>,
         card_index: usize,
+        idempotency_key_or: Option<String>,
     ) -> Result<(), Error> {
-        let game = match self.get_game_of_player(player_uuid) {
-            Ok(game) => game,
-            Err(error) => return Err(error),
-        };
-        let mut unlocked_game = game.write().unwrap();
-        if let Some(other_player_uuid) = other_player_uuid_or {
-            if !unlocked_game.player_is_in_game(other_player_uuid) {
-                return Err(Error::new(
-                    "Other player is not in the same game or does not exist",
-                ));
+        self.with_game_of_player("play_card", player_uuid, |game| {
+            if let Some(other_player_uuid) = other_player_uuid_or {
+                if !game.player_is_in_game(other_player_uuid) {
+                    return Err(Error::new(
+                        "Other player is not in the same game or does not exist",
+                    ));
+                }
             }
-        }
-        unlocked_game.play_card(player_uuid, other_player_uuid_or, card_index)
+            game.play_card(
+                player_uuid,
+                other_player_uuid_or,
+                card_index,
+                idempotency_key_or,
+            )
+        })
+    }
+
+    pub fn arrange_hand(
+        &self,
+        player_uuid: &PlayerUUID,
+        card_order: Vec<usize>,
+    ) -> Result<(), Error> {
+        self.with_game_of_player("arrange_hand", player_uuid, |game| {
+            game.arrange_hand(player_uuid, card_order)
+        })
     }
 
     pub fn discard_cards_and_draw_to_full(
         &self,
         player_uuid: &PlayerUUID,
         card_indices: Vec<usize>,
+        idempotency_key_or: Option<String>,
     ) -> Result<(), Error> {
-        let game = match self.get_game_of_player(player_uuid) {
-            Ok(game) => game,
-            Err(error) => return Err(error),
-        };
-        game.write()
-            .unwrap()
-            .discard_cards_and_draw_to_full(player_uuid, card_indices)
+        self.with_game_of_player("discard_cards_and_draw_to_full", player_uuid, |game| {
+            game.discard_cards_and_draw_to_full(player_uuid, card_indices, idempotency_key_or)
+        })
+    }
+
+    pub fn discard_single_card(
+        &self,
+        player_uuid: &PlayerUUID,
+        card_index: usize,
+        idempotency_key_or: Option<String>,
+    ) -> Result<(), Error> {
+        self.with_game_of_player("discard_single_card", player_uuid, |game| {
+            game.discard_single_card(player_uuid, card_index, idempotency_key_or)
+        })
     }
 
     pub fn order_drink(
         &self,
         player_uuid: &PlayerUUID,
         other_player_uuid: &PlayerUUID,
+        idempotency_key_or: Option<String>,
     ) -> Result<(), Error> {
-        let game = match self.get_game_of_player(player_uuid) {
-            Ok(game) => game,
-            Err(error) => return Err(error),
+        self.with_game_of_player("order_drink", player_uuid, |game| {
+            game.order_drink(player_uuid, other_player_uuid, idempotency_key_or)
+        })
+    }
+
+    pub fn order_drinks(
+        &self,
+        player_uuid: &PlayerUUID,
+        other_player_uuids: Vec<PlayerUUID>,
+        idempotency_key_or: Option<String>,
+    ) -> Result<(), Error> {
+        self.with_game_of_player("order_drinks", player_uuid, |game| {
+            game.order_drinks(player_uuid, other_player_uuids, idempotency_key_or)
+        })
+    }
+
+    pub fn pass(
+        &self,
+        player_uuid: &PlayerUUID,
+        idempotency_key_or: Option<String>,
+    ) -> Result<(), Error> {
+        self.with_game_of_player("pass", player_uuid, |game| {
+            game.pass(player_uuid, idempotency_key_or)
+        })
+    }
+
+    pub fn forfeit(
+        &self,
+        player_uuid: &PlayerUUID,
+        idempotency_key_or: Option<String>,
+    ) -> Result<(), Error> {
+        self.with_game_of_player("forfeit", player_uuid, |game| {
+            game.forfeit(player_uuid, idempotency_key_or)
+        })
+    }
+
+    pub fn undo(&self, player_uuid: &PlayerUUID) -> Result<(), Error> {
+        self.with_game_of_player("undo", player_uuid, |game| game.undo(player_uuid))
+    }
+
+    pub fn skip_turn(
+        &self,
+        player_uuid: &PlayerUUID,
+        idempotency_key_or: Option<String>,
+    ) -> Result<(), Error> {
+        self.with_game_of_player("skip_turn", player_uuid, |game| {
+            game.skip_turn(player_uuid, idempotency_key_or)
+        })
+    }
+
+    /// Runs an action against the game that the player is in, then logs the outcome and
+    /// records the game's result if the action ended it.
+    fn with_game_of_player<T>(
+        &self,
+        action: &str,
+        player_uuid: &PlayerUUID,
+        f: impl FnOnce(&mut Game) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let result = match self.get_game_of_player(player_uuid) {
+            Ok(game) => {
+                let mut unlocked_game = game.write().unwrap();
+                let result = f(&mut unlocked_game);
+                self.record_finished_game(&mut unlocked_game);
+                result
+            }
+            Err(error) => Err(error),
         };
-        game.write()
-            .unwrap()
-            .order_drink(player_uuid, other_player_uuid)
+        log_action(
+            action,
+            self.player_uuids_to_game_id.get(player_uuid),
+            player_uuid,
+            &result,
+        );
+        result
     }
 
-    pub fn pass(&self, player_uuid: &PlayerUUID) -> Result<(), Error> {
-        let game = match self.get_game_of_player(player_uuid) {
-            Ok(game) => game,
-            Err(error) => return Err(error),
+    /// Adds the result of the game to each player's stats if the game just finished.
+    /// Should be called after any action that could end a game.
+    fn record_finished_game(&self, game: &mut Game) {
+        let (winner_uuid_or, player_uuids) = match game.take_finished_game_result() {
+            Some(finished_game_result) => finished_game_result,
+            None => return,
         };
-        game.write().unwrap().pass(player_uuid)
+        let mut display_names_to_player_stats = self.display_names_to_player_stats.write().unwrap();
+        for player_uuid in player_uuids {
+            // Players who have since signed out can't be credited since their display name is gone.
+            let display_name = match self.player_uuids_to_display_names.get(&player_uuid) {
+                Some(display_name) => display_name,
+                None => continue,
+            };
+            let player_stats = display_names_to_player_stats
+                .entry(display_name.clone())
+                .or_default();
+            player_stats.games_played += 1;
+            if winner_uuid_or.as_ref() == Some(&player_uuid) {
+                player_stats.wins += 1;
+            } else {
+                player_stats.losses += 1;
+            }
+        }
+    }
+
+    pub fn get_player_stats(&self, display_name: &str) -> PlayerStats {
+        self.display_names_to_player_stats
+            .read()
+            .unwrap()
+            .get(display_name)
+            .cloned()
+            .unwrap_or_default()
     }
 
     pub fn get_game_view(&self, player_uuid: PlayerUUID) -> Result<GameView, Error> {
+        self.get_game_view_at(player_uuid, Instant::now())
+    }
+
+    fn get_game_view_at(&self, player_uuid: PlayerUUID, now: Instant) -> Result<GameView, Error> {
         let game = self.get_game_of_player(&player_uuid)?;
+        game.read().unwrap().take_game_view(
+            player_uuid,
+            &self.player_uuids_to_display_names,
+            |player_uuid| self.player_is_connected_at(player_uuid, now),
+        )
+    }
+
+    /// Returns the table view of each of the given games. Games that don't exist or are private
+    /// are skipped, so that one finished game doesn't break the whole batch. Finished games that
+    /// everyone has left are included until `finished_game_ttl` has passed.
+    pub fn get_table_views(&self, game_uuids: Vec<GameUUID>) -> TableViewCollection {
+        self.get_table_views_at(game_uuids, Instant::now())
+    }
+
+    fn get_table_views_at(&self, game_uuids: Vec<GameUUID>, now: Instant) -> TableViewCollection {
+        TableViewCollection {
+            table_views: game_uuids
+                .into_iter()
+                .filter_map(|game_uuid| {
+                    let table_view = match self.games_by_game_id.get(&game_uuid) {
+                        Some(game) => {
+                            let game = game.read().unwrap();
+                            // Private games are only visible to the players in them.
+                            if game.requires_password() {
+                                return None;
+                            }
+                            game.get_table_view(&self.player_uuids_to_display_names)
+                        }
+                        None => {
+                            let (game, emptied_time) = self.finished_games.get(&game_uuid)?;
+                            if game.requires_password()
+                                || now.saturating_duration_since(*emptied_time)
+                                    >= self.finished_game_ttl
+                            {
+                                return None;
+                            }
+                            game.get_table_view(&self.player_uuids_to_display_names)
+                        }
+                    };
+                    Some((game_uuid, table_view))
+                })
+                .collect(),
+        }
+    }
+
+    pub fn get_card_targets(
+        &self,
+        player_uuid: &PlayerUUID,
+        card_index: usize,
+    ) -> Result<CardTargets, Error> {
+        let game = self.get_game_of_player(player_uuid)?;
+        game.read().unwrap().get_card_targets(
+            player_uuid,
+            card_index,
+            &self.player_uuids_to_display_names,
+        )
+    }
+
+    pub fn get_card_playability(
+        &self,
+        player_uuid: &PlayerUUID,
+        card_index: usize,
+    ) -> Result<CardPlayability, Error> {
+        let game = self.get_game_of_player(player_uuid)?;
         game.read()
             .unwrap()
-            .get_game_view(player_uuid, &self.player_uuids_to_display_names)
+            .get_card_playability(player_uuid, card_index)
+    }
+
+    pub fn get_player_state_view(&self, player_uuid: PlayerUUID) -> Result<PlayerStateView, Error> {
+        let game = self.get_game_of_player(&player_uuid)?;
+        Ok(game.read().unwrap().get_player_state_view(player_uuid))
+    }
+
+    pub fn get_game_log(&self, player_uuid: &PlayerUUID) -> Result<GameLog, Error> {
+        Ok(self
+            .get_game_of_player(player_uuid)?
+            .read()
+            .unwrap()
+            .get_game_log())
+    }
+
+    pub fn get_player_events(
+        &self,
+        player_uuid: &PlayerUUID,
+        max_event_count: usize,
+    ) -> Result<GameLog, Error> {
+        Ok(self
+            .get_game_of_player(player_uuid)?
+            .read()
+            .unwrap()
+            .get_player_events(player_uuid, max_event_count))
     }
 
     fn get_game_of_player(&self, player_uuid: &PlayerUUID) -> Result<&RwLock<Game>, Error> {
@@ -224,9 +765,70 @@ impl GameManager {
     }
 }
 
+/// Logs the outcome of an action that changes a game or the set of signed-in players.
+fn log_action<T>(
+    action: &str,
+    game_uuid_or: Option<&GameUUID>,
+    player_uuid: &PlayerUUID,
+    result: &Result<T, Error>,
+) {
+    let game_uuid = match game_uuid_or {
+        Some(game_uuid) => game_uuid.to_string(),
+        None => "none".to_string(),
+    };
+    match result {
+        Ok(_) => info!(
+            "action={} game_uuid={} player_uuid={} outcome=ok",
+            action,
+            game_uuid,
+            player_uuid.to_string()
+        ),
+        Err(error) => warn!(
+            "action={} game_uuid={} player_uuid={} outcome=err error={:?}",
+            action,
+            game_uuid,
+            player_uuid.to_string(),
+            error
+        ),
+    }
+}
+
+fn validate_display_name(display_name: &str) -> Result<String, Error> {
+    validate_name(display_name, "Display name", MAX_DISPLAY_NAME_LENGTH)
+}
+
+fn validate_game_name(game_name: &str) -> Result<String, Error> {
+    validate_name(game_name, "Game name", MAX_GAME_NAME_LENGTH)
+}
+
+/// Trims surrounding whitespace from a name, and rejects names that would be
+/// empty, too long, or contain control characters such as newlines.
+/// `name_kind` is used at the start of error messages, e.g. "Display name".
+fn validate_name(name: &str, name_kind: &str, max_length: usize) -> Result<String, Error> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Error::new(format!("{} cannot be empty", name_kind)));
+    }
+    if name.chars().count() > max_length {
+        return Err(Error::new(format!(
+            "{} cannot be longer than {} characters",
+            name_kind, max_length
+        )));
+    }
+    if name.chars().any(char::is_control) {
+        return Err(Error::new(format!(
+            "{} cannot contain control characters",
+            name_kind
+        )));
+    }
+    Ok(name.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::player_view::GamePhase;
+    use crate::test_logger::get_logged_messages;
 
     #[test]
     fn can_add_and_remove_player_without_error() {
@@ -240,6 +842,47 @@ mod tests {
         assert!(game_manager.remove_player(&player_uuid).is_ok());
     }
 
+    #[test]
+    fn table_views_skip_games_that_do_not_exist() {
+        let mut game_manager = GameManager::new();
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Jimmy"))
+            .unwrap();
+        let game1_uuid = game_manager
+            .create_game(player1_uuid, String::from("Table 1"), None)
+            .unwrap();
+        let game2_uuid = game_manager
+            .create_game(player2_uuid, String::from("Table 2"), None)
+            .unwrap();
+
+        let table_views = game_manager
+            .get_table_views(vec![
+                game1_uuid.clone(),
+                game2_uuid.clone(),
+                GameUUID::new(),
+            ])
+            .table_views;
+        assert_eq!(table_views.len(), 2);
+        assert_eq!(table_views.get(&game1_uuid).unwrap().game_name, "Table 1");
+        assert_eq!(table_views.get(&game2_uuid).unwrap().game_name, "Table 2");
+
+        let table_views_json = serde_json::to_value(&table_views).unwrap();
+        assert_eq!(
+            table_views_json[serde_json::to_value(&game1_uuid).unwrap().as_str().unwrap()]
+                ["playerDisplayNames"]
+                .as_object()
+                .unwrap()
+                .values()
+                .collect::<Vec<_>>(),
+            vec!["Tommy"]
+        );
+    }
+
     #[test]
     fn cannot_add_player_twice() {
         let mut game_manager = GameManager::new();
@@ -258,66 +901,1037 @@ mod tests {
     }
 
     #[test]
-    fn cannot_remove_player_that_does_not_exist() {
+    fn display_name_is_trimmed() {
         let mut game_manager = GameManager::new();
-
         let player_uuid = PlayerUUID::new();
 
+        game_manager
+            .add_player(player_uuid.clone(), String::from("  Tommy \t"))
+            .unwrap();
         assert_eq!(
-            game_manager.remove_player(&player_uuid).unwrap_err(),
-            Error::new("Player does not exist")
+            game_manager.get_player_display_name(&player_uuid),
+            Some(&String::from("Tommy"))
         );
+    }
 
-        game_manager
-            .add_player(player_uuid.clone(), String::from("Tommy"))
-            .unwrap();
-        game_manager.remove_player(&player_uuid).unwrap();
+    #[test]
+    fn invalid_display_names_are_rejected() {
+        let mut game_manager = GameManager::new();
+
+        for (display_name, error) in [
+            (
+                String::from(" \t "),
+                Error::new("Display name cannot be empty"),
+            ),
+            (
+                "a".repeat(MAX_DISPLAY_NAME_LENGTH + 1),
+                Error::new("Display name cannot be longer than 32 characters"),
+            ),
+            (
+                String::from("Tommy\nBilly"),
+                Error::new("Display name cannot contain control characters"),
+            ),
+        ] {
+            let player_uuid = PlayerUUID::new();
+            assert_eq!(
+                game_manager.add_player(player_uuid.clone(), display_name),
+                Err(error)
+            );
+            assert_eq!(game_manager.get_player_display_name(&player_uuid), None);
+        }
 
+        // The longest allowed name is accepted.
         assert_eq!(
-            game_manager.remove_player(&player_uuid).unwrap_err(),
-            Error::new("Player does not exist")
+            game_manager.add_player(PlayerUUID::new(), "a".repeat(MAX_DISPLAY_NAME_LENGTH)),
+            Ok(())
         );
     }
 
     #[test]
-    fn empty_games_are_removed() {
+    fn renamed_game_name_is_validated_and_trimmed() {
         let mut game_manager = GameManager::new();
-
         let player_uuid = PlayerUUID::new();
-
         game_manager
             .add_player(player_uuid.clone(), String::from("Tommy"))
             .unwrap();
         game_manager
-            .create_game(player_uuid.clone(), "Game 1".to_string())
+            .create_game(player_uuid.clone(), String::from("Table 1"), None)
             .unwrap();
 
-        assert_eq!(game_manager.games_by_game_id.len(), 1);
-        assert_eq!(game_manager.leave_game(&player_uuid), Ok(()));
-        assert_eq!(game_manager.games_by_game_id.len(), 0);
         assert_eq!(
-            game_manager.leave_game(&player_uuid),
-            Err(Error::new("Player is not in a game"))
+            game_manager.rename_game(&player_uuid, " \t "),
+            Err(Error::new("Game name cannot be empty"))
+        );
+        assert_eq!(
+            game_manager.rename_game(&player_uuid, &"a".repeat(MAX_GAME_NAME_LENGTH + 1)),
+            Err(Error::new("Game name cannot be longer than 48 characters"))
+        );
+        assert_eq!(game_manager.rename_game(&player_uuid, "  Table 2 "), Ok(()));
+        assert_eq!(
+            game_manager.list_games().listed_game_views[0].game_name,
+            "Table 2"
         );
     }
 
     #[test]
-    fn cannot_create_game_when_you_are_already_in_one() {
+    fn created_game_name_is_validated_and_trimmed() {
         let mut game_manager = GameManager::new();
-
         let player_uuid = PlayerUUID::new();
-
         game_manager
             .add_player(player_uuid.clone(), String::from("Tommy"))
             .unwrap();
-        game_manager
-            .create_game(player_uuid.clone(), "Game 1".to_string())
-            .unwrap();
+
         assert_eq!(
-            game_manager.create_game(player_uuid, "Game 1".to_string()),
+            game_manager.create_game(player_uuid.clone(), String::from(" \t "), None),
+            Err(Error::new("Game name cannot be empty"))
+        );
+        assert_eq!(
+            game_manager.create_game(
+                player_uuid.clone(),
+                "a".repeat(MAX_GAME_NAME_LENGTH + 1),
+                None
+            ),
+            Err(Error::new("Game name cannot be longer than 48 characters"))
+        );
+        assert_eq!(
+            game_manager.create_game(player_uuid.clone(), String::from("Table\n1"), None),
+            Err(Error::new("Game name cannot contain control characters"))
+        );
+        assert!(!game_manager.player_is_in_game(&player_uuid));
+
+        game_manager
+            .create_game(player_uuid, String::from("  Table 1 "), None)
+            .unwrap();
+        assert_eq!(
+            game_manager.list_games().listed_game_views[0].game_name,
+            "Table 1"
+        );
+    }
+
+    #[test]
+    fn admin_overview_counts_players_and_games() {
+        let mut game_manager = GameManager::new();
+        let overview = game_manager.admin_overview();
+        assert_eq!(overview.player_count, 0);
+        assert_eq!(overview.game_count, 0);
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        game_manager
+            .add_player(player3_uuid, String::from("Jimmy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player1_uuid.clone(), String::from("Table 1"), None)
+            .unwrap();
+        game_manager
+            .join_game(player2_uuid.clone(), game_uuid.clone(), None)
+            .unwrap();
+
+        let overview = game_manager.admin_overview();
+        assert_eq!(overview.player_count, 3);
+        assert_eq!(overview.players_not_in_game_count, 1);
+        assert_eq!(overview.game_count, 1);
+        assert_eq!(overview.running_game_count, 0);
+        assert_eq!(overview.games[0].game_uuid, game_uuid);
+        assert_eq!(overview.games[0].player_count, 2);
+        assert!(!overview.games[0].is_running);
+
+        game_manager
+            .select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game_manager
+            .select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game_manager.toggle_ready(&player2_uuid).unwrap();
+        game_manager.start_game(&player1_uuid).unwrap();
+
+        let overview = game_manager.admin_overview();
+        assert_eq!(overview.running_game_count, 1);
+        assert!(overview.games[0].is_running);
+    }
+
+    #[test]
+    fn metrics_count_games_and_players() {
+        let mut game_manager = GameManager::new();
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid, String::from("Billy"))
+            .unwrap();
+        game_manager
+            .create_game(player1_uuid, String::from("Table 1"), None)
+            .unwrap();
+
+        let metrics_json = serde_json::to_value(game_manager.get_metrics()).unwrap();
+        assert_eq!(metrics_json["gameCount"], 1);
+        assert_eq!(metrics_json["runningGameCount"], 0);
+        assert_eq!(metrics_json["playerCount"], 2);
+        assert!(metrics_json["uptimeSeconds"].is_u64());
+    }
+
+    #[test]
+    fn cannot_remove_player_that_does_not_exist() {
+        let mut game_manager = GameManager::new();
+
+        let player_uuid = PlayerUUID::new();
+
+        assert_eq!(
+            game_manager.remove_player(&player_uuid).unwrap_err(),
+            Error::new("Player does not exist")
+        );
+
+        game_manager
+            .add_player(player_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager.remove_player(&player_uuid).unwrap();
+
+        assert_eq!(
+            game_manager.remove_player(&player_uuid).unwrap_err(),
+            Error::new("Player does not exist")
+        );
+    }
+
+    #[test]
+    fn empty_games_are_removed() {
+        let mut game_manager = GameManager::new();
+
+        let player_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .create_game(player_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+
+        assert_eq!(game_manager.games_by_game_id.len(), 1);
+        assert_eq!(game_manager.leave_game(&player_uuid), Ok(()));
+        assert_eq!(game_manager.games_by_game_id.len(), 0);
+        assert_eq!(
+            game_manager.leave_game(&player_uuid),
+            Err(Error::new("Player is not in a game"))
+        );
+    }
+
+    #[test]
+    fn cannot_create_game_when_you_are_already_in_one() {
+        let mut game_manager = GameManager::new();
+
+        let player_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .create_game(player_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        assert_eq!(
+            game_manager.create_game(player_uuid, "Game 1".to_string(), None),
             Err(Error::new("Player is already in a game"))
         );
 
         assert_eq!(game_manager.games_by_game_id.len(), 1);
     }
+
+    #[test]
+    fn owner_can_kick_player() {
+        let mut game_manager = GameManager::new();
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .join_game(player2_uuid.clone(), game_uuid, None)
+            .unwrap();
+
+        assert_eq!(
+            game_manager.kick_player(&player1_uuid, &player2_uuid),
+            Ok(())
+        );
+        assert!(!game_manager.player_is_in_game(&player2_uuid));
+        assert_eq!(
+            game_manager.list_games().listed_game_views[0].player_count,
+            1
+        );
+    }
+
+    #[test]
+    fn non_owner_cannot_kick_player() {
+        let mut game_manager = GameManager::new();
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .join_game(player2_uuid.clone(), game_uuid, None)
+            .unwrap();
+
+        assert_eq!(
+            game_manager.kick_player(&player2_uuid, &player1_uuid),
+            Err(Error::new("Must be game owner to kick players"))
+        );
+        assert!(game_manager.player_is_in_game(&player1_uuid));
+    }
+
+    #[test]
+    fn cannot_select_character_when_not_in_a_game() {
+        let mut game_manager = GameManager::new();
+
+        let player_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(player_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+
+        assert_eq!(
+            game_manager.select_character(&player_uuid, Character::Deirdre),
+            Err(Error::new("Player is not in a game"))
+        );
+
+        // Same error once the player has left the game they were in.
+        game_manager
+            .create_game(player_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager.leave_game(&player_uuid).unwrap();
+        assert_eq!(
+            game_manager.select_character(&player_uuid, Character::Deirdre),
+            Err(Error::new("Player is not in a game"))
+        );
+    }
+
+    #[test]
+    fn cannot_select_character_after_game_starts() {
+        let mut game_manager = GameManager::new();
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .join_game(player2_uuid.clone(), game_uuid, None)
+            .unwrap();
+        game_manager
+            .select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game_manager
+            .select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game_manager.toggle_ready(&player2_uuid).unwrap();
+        game_manager.start_game(&player1_uuid).unwrap();
+
+        assert_eq!(
+            game_manager.select_character(&player2_uuid, Character::Fiona),
+            Err(Error::new("Cannot change characters while game is running"))
+        );
+    }
+
+    #[test]
+    fn player_state_of_lazy_game_includes_starting_hand() {
+        let mut game_manager = GameManager::new();
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .join_game(player2_uuid.clone(), game_uuid, None)
+            .unwrap();
+        game_manager
+            .set_game_config(
+                &player1_uuid,
+                GameConfig {
+                    lazy_starting_hands: true,
+                    ..GameConfig::default()
+                },
+            )
+            .unwrap();
+        game_manager
+            .select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game_manager
+            .select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game_manager.toggle_ready(&player2_uuid).unwrap();
+        game_manager.start_game(&player1_uuid).unwrap();
+
+        // Neither player has acted yet, so their hands haven't been drawn.
+        assert_eq!(
+            game_manager
+                .get_player_state_view(player2_uuid)
+                .unwrap()
+                .hand
+                .len(),
+            7
+        );
+        assert_eq!(
+            game_manager
+                .get_player_state_view(player1_uuid)
+                .unwrap()
+                .hand
+                .len(),
+            7
+        );
+    }
+
+    #[test]
+    fn cannot_kick_player_while_game_is_running() {
+        let mut game_manager = GameManager::new();
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .join_game(player2_uuid.clone(), game_uuid, None)
+            .unwrap();
+        game_manager
+            .select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game_manager
+            .select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game_manager.toggle_ready(&player2_uuid).unwrap();
+        game_manager.start_game(&player1_uuid).unwrap();
+
+        assert_eq!(
+            game_manager.kick_player(&player1_uuid, &player2_uuid),
+            Err(Error::new("Cannot kick players while game is running"))
+        );
+        assert!(game_manager.player_is_in_game(&player2_uuid));
+    }
+
+    #[test]
+    fn stale_game_mapping_is_removed_on_join() {
+        let mut game_manager = GameManager::new();
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+
+        // Simulate player 1 being mapped to a game that was removed.
+        game_manager
+            .player_uuids_to_game_id
+            .insert(player1_uuid.clone(), GameUUID::new());
+
+        let game_uuid = game_manager
+            .create_game(player2_uuid, "Game 1".to_string(), None)
+            .unwrap();
+        assert_eq!(
+            game_manager.join_game(player1_uuid.clone(), game_uuid.clone(), None),
+            Ok(())
+        );
+        assert_eq!(
+            game_manager.player_uuids_to_game_id.get(&player1_uuid),
+            Some(&game_uuid)
+        );
+    }
+
+    #[test]
+    fn stale_game_mapping_is_removed_on_leave() {
+        let mut game_manager = GameManager::new();
+
+        let player_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(player_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+
+        // Simulate the player being mapped to a game that was removed.
+        game_manager
+            .player_uuids_to_game_id
+            .insert(player_uuid.clone(), GameUUID::new());
+
+        assert_eq!(game_manager.leave_game(&player_uuid), Ok(()));
+        assert!(!game_manager.player_is_in_game(&player_uuid));
+        assert!(game_manager
+            .create_game(player_uuid, "Game 1".to_string(), None)
+            .is_ok());
+    }
+
+    #[test]
+    fn creating_game_beyond_cap_is_rejected_until_a_game_is_removed() {
+        let mut game_manager = GameManager::new();
+        game_manager.max_games = 2;
+
+        let player_uuids: Vec<PlayerUUID> = (0..3).map(|_| PlayerUUID::new()).collect();
+        for (i, player_uuid) in player_uuids.iter().enumerate() {
+            game_manager
+                .add_player(player_uuid.clone(), format!("Player {}", i + 1))
+                .unwrap();
+        }
+
+        game_manager
+            .create_game(player_uuids[0].clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .create_game(player_uuids[1].clone(), "Game 2".to_string(), None)
+            .unwrap();
+        assert_eq!(
+            game_manager.create_game(player_uuids[2].clone(), "Game 3".to_string(), None),
+            Err(Error::new(
+                "Too many games are in progress, please try again later"
+            ))
+        );
+
+        // Leaving an otherwise empty game removes it, which frees up a slot.
+        game_manager.leave_game(&player_uuids[0]).unwrap();
+        assert!(game_manager
+            .create_game(player_uuids[2].clone(), "Game 3".to_string(), None)
+            .is_ok());
+    }
+
+    #[test]
+    fn adding_player_beyond_cap_evicts_longest_idle_player() {
+        let mut game_manager = GameManager::new();
+        game_manager.max_players_not_in_game = 2;
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+
+        // Neither player has been idle long enough to be evicted.
+        assert_eq!(
+            game_manager.add_player(player3_uuid.clone(), String::from("Jimmy")),
+            Err(Error::new("Too many players are signed in"))
+        );
+
+        // Player 2 has been seen more recently than player 1.
+        let now = Instant::now();
+        game_manager
+            .player_uuids_to_last_seen_times
+            .get_mut()
+            .unwrap()
+            .insert(player1_uuid.clone(), now);
+        game_manager
+            .player_uuids_to_last_seen_times
+            .get_mut()
+            .unwrap()
+            .insert(player2_uuid.clone(), now + Duration::from_secs(1));
+        game_manager.player_idle_timeout = Duration::ZERO;
+
+        assert_eq!(
+            game_manager.add_player(player3_uuid.clone(), String::from("Jimmy")),
+            Ok(())
+        );
+        assert!(game_manager
+            .get_player_display_name(&player1_uuid)
+            .is_none());
+        assert!(game_manager
+            .get_player_display_name(&player2_uuid)
+            .is_some());
+        assert!(game_manager
+            .get_player_display_name(&player3_uuid)
+            .is_some());
+    }
+
+    #[test]
+    fn player_is_shown_as_disconnected_after_timeout() {
+        let mut game_manager = GameManager::new();
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .join_game(player2_uuid.clone(), game_uuid, None)
+            .unwrap();
+
+        let now = Instant::now();
+        game_manager.touch_player(&player1_uuid);
+        game_manager.touch_player(&player2_uuid);
+        let game_view = game_manager
+            .get_game_view_at(player1_uuid.clone(), now)
+            .unwrap();
+        assert_eq!(
+            game_view.player_connection_statuses.get(&player1_uuid),
+            Some(&true)
+        );
+        assert_eq!(
+            game_view.player_connection_statuses.get(&player2_uuid),
+            Some(&true)
+        );
+
+        // Only player 1 makes a request later on.
+        let later = now + PLAYER_CONNECTED_TIMEOUT + Duration::from_secs(1);
+        game_manager
+            .player_uuids_to_last_seen_times
+            .get_mut()
+            .unwrap()
+            .insert(player1_uuid.clone(), later);
+        let game_view = game_manager
+            .get_game_view_at(player1_uuid.clone(), later)
+            .unwrap();
+        assert_eq!(
+            game_view.player_connection_statuses.get(&player1_uuid),
+            Some(&true)
+        );
+        assert_eq!(
+            game_view.player_connection_statuses.get(&player2_uuid),
+            Some(&false)
+        );
+    }
+
+    #[test]
+    fn finished_game_is_recorded_in_player_stats_once() {
+        let mut game_manager = GameManager::new();
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .join_game(player2_uuid.clone(), game_uuid, None)
+            .unwrap();
+        game_manager
+            .select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game_manager
+            .select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game_manager.toggle_ready(&player2_uuid).unwrap();
+        game_manager.start_game(&player1_uuid).unwrap();
+
+        assert_eq!(
+            game_manager.get_player_stats("Tommy"),
+            PlayerStats::default()
+        );
+
+        game_manager.forfeit(&player2_uuid, None).unwrap();
+        // Further actions after the game has ended shouldn't count it again.
+        assert!(game_manager.pass(&player1_uuid, None).is_err());
+        assert!(game_manager.forfeit(&player1_uuid, None).is_err());
+
+        assert_eq!(
+            game_manager.get_player_stats("Tommy"),
+            PlayerStats {
+                games_played: 1,
+                wins: 1,
+                losses: 0
+            }
+        );
+        assert_eq!(
+            game_manager.get_player_stats("Billy"),
+            PlayerStats {
+                games_played: 1,
+                wins: 0,
+                losses: 1
+            }
+        );
+    }
+
+    #[test]
+    fn finished_game_can_be_viewed_until_ttl_after_everyone_leaves() {
+        let mut game_manager = GameManager::new();
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        game_manager
+            .add_player(player3_uuid.clone(), String::from("Jimmy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .join_game(player2_uuid.clone(), game_uuid.clone(), None)
+            .unwrap();
+        game_manager
+            .select_character(&player1_uuid, Character::Deirdre)
+            .unwrap();
+        game_manager
+            .select_character(&player2_uuid, Character::Gerki)
+            .unwrap();
+        game_manager.toggle_ready(&player2_uuid).unwrap();
+        game_manager.start_game(&player1_uuid).unwrap();
+        game_manager.forfeit(&player2_uuid, None).unwrap();
+
+        game_manager.leave_game(&player1_uuid).unwrap();
+        game_manager.leave_game(&player2_uuid).unwrap();
+        let emptied_time = Instant::now();
+
+        // The game is no longer listed or joinable, but its final state can still be viewed.
+        assert!(game_manager.list_games().listed_game_views.is_empty());
+        assert_eq!(
+            game_manager.join_game(player3_uuid, game_uuid.clone(), None),
+            Err(Error::new("Game does not exist"))
+        );
+        let table_views = game_manager
+            .get_table_views(vec![game_uuid.clone()])
+            .table_views;
+        let table_view = table_views.get(&game_uuid).unwrap();
+        assert_eq!(table_view.phase, GamePhase::Finished);
+        assert_eq!(table_view.winner_uuid, Some(player1_uuid.clone()));
+        assert_eq!(
+            table_view.standings,
+            vec![vec![player1_uuid.clone()], vec![player2_uuid]]
+        );
+
+        // Once the TTL has passed, the game is gone.
+        let later = emptied_time + DEFAULT_FINISHED_GAME_TTL;
+        assert!(game_manager
+            .get_table_views_at(vec![game_uuid.clone()], later)
+            .table_views
+            .is_empty());
+        game_manager.finished_game_ttl = Duration::ZERO;
+        game_manager
+            .create_game(player1_uuid, "Game 2".to_string(), None)
+            .unwrap();
+        assert!(game_manager.finished_games.is_empty());
+    }
+
+    #[test]
+    fn empty_unstarted_game_is_removed_immediately() {
+        let mut game_manager = GameManager::new();
+        let player_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(player_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+
+        game_manager.leave_game(&player_uuid).unwrap();
+        assert!(game_manager.finished_games.is_empty());
+        assert!(game_manager
+            .get_table_views(vec![game_uuid])
+            .table_views
+            .is_empty());
+    }
+
+    #[test]
+    fn owner_leaving_lobby_passes_ownership_to_next_player() {
+        let mut game_manager = GameManager::new();
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        game_manager
+            .add_player(player3_uuid.clone(), String::from("Jimmy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .join_game(player2_uuid.clone(), game_uuid.clone(), None)
+            .unwrap();
+        game_manager
+            .join_game(player3_uuid.clone(), game_uuid, None)
+            .unwrap();
+        assert_eq!(
+            game_manager
+                .get_game_view(player2_uuid.clone())
+                .unwrap()
+                .owner_uuid,
+            Some(player1_uuid.clone())
+        );
+
+        game_manager.leave_game(&player1_uuid).unwrap();
+        assert_eq!(
+            game_manager
+                .get_game_view(player3_uuid.clone())
+                .unwrap()
+                .owner_uuid,
+            Some(player2_uuid.clone())
+        );
+        assert_eq!(
+            game_manager.list_games().listed_game_views[0].player_count,
+            2
+        );
+
+        // Only the new owner can do owner-only actions.
+        assert_eq!(
+            game_manager.rename_game(&player3_uuid, "Game 2"),
+            Err(Error::new("Must be game owner to rename game"))
+        );
+        assert_eq!(game_manager.rename_game(&player2_uuid, "Game 2"), Ok(()));
+    }
+
+    #[test]
+    fn owner_leaving_solo_lobby_removes_game() {
+        let mut game_manager = GameManager::new();
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+
+        game_manager.leave_game(&player1_uuid).unwrap();
+        assert!(!game_manager.player_is_in_game(&player1_uuid));
+        assert!(game_manager.list_games().listed_game_views.is_empty());
+        assert!(game_manager
+            .join_game(player2_uuid, game_uuid, None)
+            .is_err());
+    }
+
+    #[test]
+    fn different_games_can_be_changed_concurrently() {
+        let mut game_manager = GameManager::new();
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .create_game(player2_uuid.clone(), "Game 2".to_string(), None)
+            .unwrap();
+        let game_manager = RwLock::new(game_manager);
+
+        // Lock player 1's game for the duration of the test, as if an action were in progress.
+        let unlocked_game_manager = game_manager.read().unwrap();
+        let game1_guard = unlocked_game_manager
+            .get_game_of_player(&player1_uuid)
+            .unwrap()
+            .write()
+            .unwrap();
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                let unlocked_game_manager = game_manager.read().unwrap();
+                unlocked_game_manager.touch_player(&player2_uuid);
+                sender
+                    .send(unlocked_game_manager.select_character(&player2_uuid, Character::Zot))
+                    .unwrap();
+            });
+            let result = receiver.recv_timeout(Duration::from_secs(5));
+            // Unlock player 1's game before asserting, so that a failure can't leave the other thread blocked.
+            drop(game1_guard);
+            assert_eq!(result, Ok(Ok(())));
+        });
+    }
+
+    #[test]
+    fn play_card_error_is_logged() {
+        // Installs the logger before anything is logged.
+        get_logged_messages();
+        let mut game_manager = GameManager::new();
+
+        let player_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(player_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(player_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+
+        assert!(game_manager
+            .play_card(&player_uuid, &None, 0, None)
+            .is_err());
+
+        let expected_message = format!(
+            "WARN action=play_card game_uuid={} player_uuid={} outcome=err error=Error(\"Game is not currently running\")",
+            game_uuid.to_string(),
+            player_uuid.to_string()
+        );
+        assert!(get_logged_messages().contains(&expected_message));
+    }
+
+    #[test]
+    fn players_in_game_are_never_evicted() {
+        let mut game_manager = GameManager::new();
+        game_manager.max_players_not_in_game = 1;
+        game_manager.player_idle_timeout = Duration::ZERO;
+
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        let player3_uuid = PlayerUUID::new();
+
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .create_game(player1_uuid.clone(), "Game 1".to_string(), None)
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Billy"))
+            .unwrap();
+
+        assert_eq!(
+            game_manager.add_player(player3_uuid.clone(), String::from("Jimmy")),
+            Ok(())
+        );
+        assert!(game_manager
+            .get_player_display_name(&player1_uuid)
+            .is_some());
+        assert!(game_manager.player_is_in_game(&player1_uuid));
+        assert!(game_manager
+            .get_player_display_name(&player2_uuid)
+            .is_none());
+    }
+
+    #[test]
+    fn private_game_can_only_be_joined_with_correct_password() {
+        let mut game_manager = GameManager::new();
+        let owner_uuid = PlayerUUID::new();
+        let player_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(owner_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        let game_uuid = game_manager
+            .create_game(
+                owner_uuid,
+                "Game 1".to_string(),
+                Some("hunter2".to_string()),
+            )
+            .unwrap();
+        assert!(game_manager.list_games().listed_game_views[0].requires_password);
+        assert!(game_manager
+            .get_table_views(vec![game_uuid.clone()])
+            .table_views
+            .is_empty());
+
+        assert_eq!(
+            game_manager.join_game(player_uuid.clone(), game_uuid.clone(), None),
+            Err(Error::new("This game requires a password to join"))
+        );
+        assert_eq!(
+            game_manager.join_game(player_uuid.clone(), game_uuid.clone(), Some("hunter3")),
+            Err(Error::new("Incorrect game password"))
+        );
+        assert!(!game_manager.player_is_in_game(&player_uuid));
+
+        assert_eq!(
+            game_manager.join_game(player_uuid.clone(), game_uuid, Some("hunter2")),
+            Ok(())
+        );
+        assert!(game_manager.player_is_in_game(&player_uuid));
+    }
+
+    #[test]
+    fn public_game_ignores_join_password() {
+        let mut game_manager = GameManager::new();
+        let owner_uuid = PlayerUUID::new();
+        let player1_uuid = PlayerUUID::new();
+        let player2_uuid = PlayerUUID::new();
+        game_manager
+            .add_player(owner_uuid.clone(), String::from("Tommy"))
+            .unwrap();
+        game_manager
+            .add_player(player1_uuid.clone(), String::from("Billy"))
+            .unwrap();
+        game_manager
+            .add_player(player2_uuid.clone(), String::from("Jimmy"))
+            .unwrap();
+        // A blank password is what the client sends when the password field is left empty.
+        let game_uuid = game_manager
+            .create_game(owner_uuid, "Game 1".to_string(), Some(String::new()))
+            .unwrap();
+        assert!(!game_manager.list_games().listed_game_views[0].requires_password);
+
+        assert_eq!(
+            game_manager.join_game(player1_uuid, game_uuid.clone(), None),
+            Ok(())
+        );
+        assert_eq!(
+            game_manager.join_game(player2_uuid, game_uuid, Some("anything")),
+            Ok(())
+        );
+    }
 }
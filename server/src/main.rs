@@ -2,18 +2,28 @@
 extern crate rocket;
 
 mod auth;
+mod cors;
 mod game;
 mod game_manager;
+#[cfg(test)]
+mod test_logger;
 
-use auth::SESSION_COOKIE_NAME;
+use auth::{Admin, AdminTokenConfig, GamePassword, SESSION_COOKIE_NAME};
 use game::{
-    player_view::{GameView, ListedGameViewCollection},
-    Character, Error, GameUUID, PlayerUUID,
+    get_card_catalog, get_character_deck_preview,
+    player_view::{
+        AdminOverview, CardCatalog, CardPlayability, CardTargets, CharacterDeckPreview, GameLog,
+        GameView, ListedGameViewCollection, PlayerStateView, PlayerStats, ServerMetrics,
+        TableViewCollection,
+    },
+    Character, Error, GameConfig, GameUUID, PlayerUUID,
 };
 use game_manager::GameManager;
+use std::str::FromStr;
 use std::sync::RwLock;
 
 use rocket::{
+    fairing::AdHoc,
     http::{Cookie, CookieJar},
     response::{content, status},
     Request, State,
@@ -23,6 +33,9 @@ const FAVICON_BYTES: &[u8] = include_bytes!("../../client/out/favicon.ico");
 const HTML_BYTES: &[u8] = include_bytes!("../../client/out/index.html");
 const JS_BUNDLE_BYTES: &[u8] = include_bytes!("../../client/out/bundle.js");
 
+/// How many events `/api/myEvents` returns when no limit is given.
+const DEFAULT_MY_EVENTS_LIMIT: usize = 20;
+
 // TODO - Use JWT to sign cookies. Currently they are completely unsecure.
 
 enum NotFoundResponse {
@@ -88,6 +101,11 @@ async fn healthz_handler() -> content::Html<String> {
     content::Html("<html><body><h1>200 OK</h1>Service ready.</body></html>".to_string())
 }
 
+#[get("/api/metrics")]
+async fn metrics_handler(game_manager: &State<RwLock<GameManager>>) -> ServerMetrics {
+    game_manager.read().unwrap().get_metrics()
+}
+
 #[get("/api/signin?<display_name>")]
 async fn signin_handler(
     game_manager: &State<RwLock<GameManager>>,
@@ -141,15 +159,26 @@ async fn list_games_handler(game_manager: &State<RwLock<GameManager>>) -> Listed
     game_manager.read().unwrap().list_games()
 }
 
+#[get("/api/cardCatalog")]
+async fn card_catalog_handler() -> CardCatalog {
+    get_card_catalog()
+}
+
+#[get("/api/characterDeck/<character>")]
+async fn character_deck_handler(character: Character) -> CharacterDeckPreview {
+    get_character_deck_preview(character)
+}
+
 #[get("/api/createGame/<game_name>")]
 async fn create_game_handler(
     game_manager: &State<RwLock<GameManager>>,
     cookie_jar: &CookieJar<'_>,
     game_name: String,
+    game_password: GamePassword,
 ) -> Result<GameView, Error> {
     let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
     let mut unlocked_game_manager = game_manager.write().unwrap();
-    unlocked_game_manager.create_game(player_uuid.clone(), game_name)?;
+    unlocked_game_manager.create_game(player_uuid.clone(), game_name, game_password.0)?;
     unlocked_game_manager.get_game_view(player_uuid)
 }
 
@@ -158,10 +187,11 @@ async fn join_game_handler(
     game_manager: &State<RwLock<GameManager>>,
     cookie_jar: &CookieJar<'_>,
     game_uuid: GameUUID,
+    game_password: GamePassword,
 ) -> Result<GameView, Error> {
     let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
     let mut unlocked_game_manager = game_manager.write().unwrap();
-    unlocked_game_manager.join_game(player_uuid.clone(), game_uuid)?;
+    unlocked_game_manager.join_game(player_uuid.clone(), game_uuid, game_password.0.as_deref())?;
     unlocked_game_manager.get_game_view(player_uuid)
 }
 
@@ -175,6 +205,18 @@ async fn leave_game_handler(
     unlocked_game_manager.leave_game(&player_uuid)
 }
 
+#[get("/api/kickPlayer/<kicked_player_uuid>")]
+async fn kick_player_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+    kicked_player_uuid: PlayerUUID,
+) -> Result<GameView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    let mut unlocked_game_manager = game_manager.write().unwrap();
+    unlocked_game_manager.kick_player(&player_uuid, &kicked_player_uuid)?;
+    unlocked_game_manager.get_game_view(player_uuid)
+}
+
 #[get("/api/startGame")]
 async fn start_game_handler(
     game_manager: &State<RwLock<GameManager>>,
@@ -186,6 +228,74 @@ async fn start_game_handler(
     unlocked_game_manager.get_game_view(player_uuid)
 }
 
+#[get("/api/abortGame")]
+async fn abort_game_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+) -> Result<GameView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    let unlocked_game_manager = game_manager.read().unwrap();
+    unlocked_game_manager.abort_game(&player_uuid)?;
+    unlocked_game_manager.get_game_view(player_uuid)
+}
+
+#[get(
+    "/api/setGameConfig?<max_discards_per_turn>&<max_fortitude>&<max_alcohol_content>&<gambling_ante>&<first_player_seed>&<gold_to_win>&<lazy_starting_hands>"
+)]
+// Each rule variant is its own query parameter.
+#[allow(clippy::too_many_arguments)]
+async fn set_game_config_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+    max_discards_per_turn: Option<usize>,
+    max_fortitude: Option<i32>,
+    max_alcohol_content: Option<i32>,
+    gambling_ante: Option<i32>,
+    first_player_seed: Option<u64>,
+    gold_to_win: Option<i32>,
+    lazy_starting_hands: Option<bool>,
+) -> Result<GameView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    let unlocked_game_manager = game_manager.read().unwrap();
+    let default_config = GameConfig::default();
+    unlocked_game_manager.set_game_config(
+        &player_uuid,
+        GameConfig {
+            max_discards_per_turn,
+            max_fortitude: max_fortitude.unwrap_or(default_config.max_fortitude),
+            max_alcohol_content: max_alcohol_content.unwrap_or(default_config.max_alcohol_content),
+            gambling_ante: gambling_ante.unwrap_or(default_config.gambling_ante),
+            first_player_seed,
+            gold_to_win,
+            lazy_starting_hands: lazy_starting_hands.unwrap_or(default_config.lazy_starting_hands),
+        },
+    )?;
+    unlocked_game_manager.get_game_view(player_uuid)
+}
+
+#[get("/api/renameGame?<name>")]
+async fn rename_game_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+    name: String,
+) -> Result<GameView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    let unlocked_game_manager = game_manager.read().unwrap();
+    unlocked_game_manager.rename_game(&player_uuid, &name)?;
+    unlocked_game_manager.get_game_view(player_uuid)
+}
+
+#[get("/api/toggleReady")]
+async fn toggle_ready_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+) -> Result<GameView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    let unlocked_game_manager = game_manager.read().unwrap();
+    unlocked_game_manager.toggle_ready(&player_uuid)?;
+    unlocked_game_manager.get_game_view(player_uuid)
+}
+
 #[get("/api/selectCharacter/<character>")]
 async fn select_character_handler(
     game_manager: &State<RwLock<GameManager>>,
@@ -198,52 +308,137 @@ async fn select_character_handler(
     unlocked_game_manager.get_game_view(player_uuid)
 }
 
-#[get("/api/playCard?<other_player_uuid>&<card_index>")]
+#[get("/api/playCard?<other_player_uuid>&<card_index>&<idempotency_key>")]
 async fn play_card_handler(
     game_manager: &State<RwLock<GameManager>>,
     cookie_jar: &CookieJar<'_>,
     other_player_uuid: Option<PlayerUUID>,
     card_index: usize,
+    idempotency_key: Option<String>,
 ) -> Result<GameView, Error> {
     let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
     let unlocked_game_manager = game_manager.read().unwrap();
-    unlocked_game_manager.play_card(&player_uuid, &other_player_uuid, card_index)?;
+    unlocked_game_manager.play_card(
+        &player_uuid,
+        &other_player_uuid,
+        card_index,
+        idempotency_key,
+    )?;
     unlocked_game_manager.get_game_view(player_uuid)
 }
 
-#[get("/api/discardCards?<card_indices_string>")]
+#[get("/api/arrangeHand?<order>")]
+async fn arrange_hand_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+    order: Option<String>,
+) -> Result<GameView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    let unlocked_game_manager = game_manager.read().unwrap();
+    unlocked_game_manager.arrange_hand(&player_uuid, parse_vec(order)?)?;
+    unlocked_game_manager.get_game_view(player_uuid)
+}
+
+#[get("/api/discardCards?<card_indices_string>&<idempotency_key>")]
 async fn discard_cards_handler(
     game_manager: &State<RwLock<GameManager>>,
     cookie_jar: &CookieJar<'_>,
     card_indices_string: Option<String>,
+    idempotency_key: Option<String>,
+) -> Result<GameView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    let unlocked_game_manager = game_manager.read().unwrap();
+    unlocked_game_manager.discard_cards_and_draw_to_full(
+        &player_uuid,
+        parse_vec(card_indices_string)?,
+        idempotency_key,
+    )?;
+    unlocked_game_manager.get_game_view(player_uuid)
+}
+
+#[get("/api/discardCard/<card_index>?<idempotency_key>")]
+async fn discard_card_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+    card_index: usize,
+    idempotency_key: Option<String>,
 ) -> Result<GameView, Error> {
     let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
     let unlocked_game_manager = game_manager.read().unwrap();
-    unlocked_game_manager
-        .discard_cards_and_draw_to_full(&player_uuid, parse_usize_vec(card_indices_string)?)?;
+    unlocked_game_manager.discard_single_card(&player_uuid, card_index, idempotency_key)?;
     unlocked_game_manager.get_game_view(player_uuid)
 }
 
-#[get("/api/orderDrink/<other_player_uuid>")]
+#[get("/api/orderDrink/<other_player_uuid>?<idempotency_key>")]
 async fn order_drink_handler(
     game_manager: &State<RwLock<GameManager>>,
     cookie_jar: &CookieJar<'_>,
     other_player_uuid: PlayerUUID,
+    idempotency_key: Option<String>,
 ) -> Result<GameView, Error> {
     let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
     let unlocked_game_manager = game_manager.read().unwrap();
-    unlocked_game_manager.order_drink(&player_uuid, &other_player_uuid)?;
+    unlocked_game_manager.order_drink(&player_uuid, &other_player_uuid, idempotency_key)?;
     unlocked_game_manager.get_game_view(player_uuid)
 }
 
-#[get("/api/pass")]
+#[get("/api/orderDrinks?<targets>&<idempotency_key>")]
+async fn order_drinks_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+    targets: Option<String>,
+    idempotency_key: Option<String>,
+) -> Result<GameView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    let unlocked_game_manager = game_manager.read().unwrap();
+    unlocked_game_manager.order_drinks(&player_uuid, parse_vec(targets)?, idempotency_key)?;
+    unlocked_game_manager.get_game_view(player_uuid)
+}
+
+#[get("/api/pass?<idempotency_key>")]
 async fn pass_handler(
     game_manager: &State<RwLock<GameManager>>,
     cookie_jar: &CookieJar<'_>,
+    idempotency_key: Option<String>,
 ) -> Result<GameView, Error> {
     let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
     let unlocked_game_manager = game_manager.read().unwrap();
-    unlocked_game_manager.pass(&player_uuid)?;
+    unlocked_game_manager.pass(&player_uuid, idempotency_key)?;
+    unlocked_game_manager.get_game_view(player_uuid)
+}
+
+#[get("/api/forfeit?<idempotency_key>")]
+async fn forfeit_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+    idempotency_key: Option<String>,
+) -> Result<GameView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    let unlocked_game_manager = game_manager.read().unwrap();
+    unlocked_game_manager.forfeit(&player_uuid, idempotency_key)?;
+    unlocked_game_manager.get_game_view(player_uuid)
+}
+
+#[get("/api/undo")]
+async fn undo_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+) -> Result<GameView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    let unlocked_game_manager = game_manager.read().unwrap();
+    unlocked_game_manager.undo(&player_uuid)?;
+    unlocked_game_manager.get_game_view(player_uuid)
+}
+
+#[get("/api/skipTurn?<idempotency_key>")]
+async fn skip_turn_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+    idempotency_key: Option<String>,
+) -> Result<GameView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    let unlocked_game_manager = game_manager.read().unwrap();
+    unlocked_game_manager.skip_turn(&player_uuid, idempotency_key)?;
     unlocked_game_manager.get_game_view(player_uuid)
 }
 
@@ -256,12 +451,97 @@ async fn get_game_view_handler(
     game_manager.read().unwrap().get_game_view(player_uuid)
 }
 
-fn parse_usize_vec(items_string_or: Option<String>) -> Result<Vec<usize>, Error> {
+#[get("/api/cardTargets?<card_index>")]
+async fn card_targets_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+    card_index: usize,
+) -> Result<CardTargets, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    game_manager
+        .read()
+        .unwrap()
+        .get_card_targets(&player_uuid, card_index)
+}
+
+#[get("/api/cardPlayable?<card_index>")]
+async fn card_playable_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+    card_index: usize,
+) -> Result<CardPlayability, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    game_manager
+        .read()
+        .unwrap()
+        .get_card_playability(&player_uuid, card_index)
+}
+
+#[get("/api/tableViews?<game_uuids>")]
+async fn table_views_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    game_uuids: Option<String>,
+) -> Result<TableViewCollection, Error> {
+    let game_uuids: Vec<GameUUID> = parse_vec(game_uuids)?;
+    Ok(game_manager.read().unwrap().get_table_views(game_uuids))
+}
+
+#[get("/api/myState")]
+async fn my_state_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+) -> Result<PlayerStateView, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    game_manager
+        .read()
+        .unwrap()
+        .get_player_state_view(player_uuid)
+}
+
+#[get("/api/gameLog")]
+async fn get_game_log_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+) -> Result<GameLog, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    game_manager.read().unwrap().get_game_log(&player_uuid)
+}
+
+#[get("/api/myEvents?<limit>")]
+async fn my_events_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    cookie_jar: &CookieJar<'_>,
+    limit: Option<usize>,
+) -> Result<GameLog, Error> {
+    let player_uuid = PlayerUUID::from_cookie_jar(cookie_jar)?;
+    game_manager
+        .read()
+        .unwrap()
+        .get_player_events(&player_uuid, limit.unwrap_or(DEFAULT_MY_EVENTS_LIMIT))
+}
+
+#[get("/api/playerStats/<display_name>")]
+async fn player_stats_handler(
+    game_manager: &State<RwLock<GameManager>>,
+    display_name: String,
+) -> PlayerStats {
+    game_manager.read().unwrap().get_player_stats(&display_name)
+}
+
+#[get("/api/admin/overview")]
+async fn admin_overview_handler(
+    _admin: Admin,
+    game_manager: &State<RwLock<GameManager>>,
+) -> AdminOverview {
+    game_manager.read().unwrap().admin_overview()
+}
+
+fn parse_vec<T: FromStr>(items_string_or: Option<String>) -> Result<Vec<T>, Error> {
     match items_string_or {
         Some(items_string) => {
-            let mut items: Vec<usize> = Vec::new();
+            let mut items: Vec<T> = Vec::new();
             for item_string in items_string.split(',') {
-                match item_string.parse::<usize>() {
+                match item_string.parse::<T>() {
                     Ok(item) => items.push(item),
                     Err(_) => return Err(Error::new("Unable to parse items")),
                 };
@@ -276,25 +556,63 @@ fn parse_usize_vec(items_string_or: Option<String>) -> Result<Vec<usize>, Error>
 async fn rocket() -> _ {
     rocket::build()
         .manage(RwLock::from(GameManager::new()))
+        .manage(AdminTokenConfig::from_env())
+        .attach(cors::Cors::from_env())
+        .attach(AdHoc::on_request("Player presence", |request, _| {
+            Box::pin(async move {
+                // Any request from a signed-in player counts as a heartbeat.
+                if let (Some(game_manager), Ok(player_uuid)) = (
+                    request.rocket().state::<RwLock<GameManager>>(),
+                    PlayerUUID::from_cookie_jar(request.cookies()),
+                ) {
+                    let unlocked_game_manager = game_manager.read().unwrap();
+                    unlocked_game_manager.touch_player(&player_uuid);
+                    // Clients poll regularly, so this is also when interrupt deadlines are checked.
+                    unlocked_game_manager.auto_pass_expired_interrupt(&player_uuid);
+                }
+            })
+        }))
         .register("/", catchers![not_found_handler])
         .mount(
             "/",
             routes![
                 healthz_handler,
+                metrics_handler,
                 signin_handler,
                 signout_handler,
                 me_handler,
                 list_games_handler,
+                card_catalog_handler,
+                character_deck_handler,
                 create_game_handler,
                 join_game_handler,
                 leave_game_handler,
+                kick_player_handler,
                 start_game_handler,
+                abort_game_handler,
                 select_character_handler,
+                toggle_ready_handler,
+                set_game_config_handler,
+                rename_game_handler,
                 play_card_handler,
                 discard_cards_handler,
+                discard_card_handler,
+                arrange_hand_handler,
                 order_drink_handler,
+                order_drinks_handler,
                 pass_handler,
-                get_game_view_handler
+                forfeit_handler,
+                skip_turn_handler,
+                undo_handler,
+                get_game_view_handler,
+                my_state_handler,
+                card_targets_handler,
+                card_playable_handler,
+                table_views_handler,
+                get_game_log_handler,
+                my_events_handler,
+                player_stats_handler,
+                admin_overview_handler
             ],
         )
 }
@@ -0,0 +1,40 @@
+use std::sync::{Mutex, Once};
+
+/// Collects every log message so that tests can check what was logged.
+struct TestLogger {
+    messages: Mutex<Vec<String>>,
+}
+
+impl log::Log for TestLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        self.messages
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", record.level(), record.args()));
+    }
+
+    fn flush(&self) {}
+}
+
+static TEST_LOGGER: TestLogger = TestLogger {
+    messages: Mutex::new(Vec::new()),
+};
+
+/// Installs the test logger. Only one logger can be set per process, so this must run before
+/// anything else (such as Rocket) tries to install its own.
+pub fn init() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        log::set_logger(&TEST_LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Info);
+    });
+}
+
+pub fn get_logged_messages() -> Vec<String> {
+    init();
+    TEST_LOGGER.messages.lock().unwrap().clone()
+}